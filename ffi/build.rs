@@ -0,0 +1,142 @@
+//! writes calecalc.h to OUT_DIR from the constants, #[repr(C)] structs and extern "C" functions
+//! in src/lib.rs, tests/header.rs checks the committed include/calecalc.h is the same
+//!
+//! the parsing is line based so signatures stay on one line and struct fields get a line each
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+fn c_type(rust: &str) -> String {
+    let rust = rust.trim();
+    if let Some(pointee) = rust.strip_prefix("*mut ") {
+        format!("{} *", c_type(pointee))
+    } else if let Some(pointee) = rust.strip_prefix("*const ") {
+        format!("const {} *", c_type(pointee))
+    } else {
+        match rust {
+            "i32" => "int32_t",
+            "bool" => "bool",
+            "usize" => "size_t",
+            "c_char" => "char",
+            other => other
+        }.to_string()
+    }
+}
+
+/// a declaration of a variable, arrays like `[i32; 2]` put their length after the name
+fn declaration(name: &str, rust: &str) -> String {
+    let rust = rust.trim();
+    if rust.starts_with('[') {
+        let inner = &rust[1..rust.len() - 1];
+        let mut parts = inner.split(';');
+        let element = parts.next().unwrap_or("");
+        let length = parts.next().unwrap_or("").trim();
+        return format!("{} {}[{}]", c_type(element), name, length);
+    }
+    let c = c_type(rust);
+    if c.ends_with('*') { format!("{}{}", c, name) } else { format!("{} {}", c, name) }
+}
+
+/// splits `name: type` at the first colon
+fn name_and_type(text: &str) -> (&str, &str) {
+    let colon = text.find(':').expect("Fields and arguments are written as name: type");
+    (text[..colon].trim(), text[colon + 1..].trim())
+}
+
+fn header(source: &str) -> String {
+    let mut out = String::new();
+    out.push_str("/* generated by build.rs from src/lib.rs, do not edit */\n");
+    for line in source.lines().take_while(|line| line.starts_with("//!")) {
+        out.push_str(&format!("//{}\n", &line[3..]).replace("// \n", "//\n"));
+    }
+    out.push_str("\n#ifndef CALECALC_H\n#define CALECALC_H\n\n");
+    out.push_str("#include <stdbool.h>\n#include <stddef.h>\n#include <stdint.h>\n\n");
+    out.push_str("#ifdef __cplusplus\nextern \"C\" {\n#endif\n");
+
+    let mut docs: Vec<String> = Vec::new();
+    let mut repr_c = false;
+    // blank lines in the source separate the declarations in the header too
+    let mut blank = true;
+    let start = |out: &mut String, docs: &[String], blank: bool| {
+        if blank {
+            out.push('\n');
+        }
+        for doc in docs {
+            out.push_str(&format!("{}\n", doc));
+        }
+    };
+    let mut lines = source.lines();
+    while let Some(line) = lines.next() {
+        let line = line.trim();
+        if line.is_empty() {
+            blank = true;
+            continue;
+        }
+        if let Some(doc) = line.strip_prefix("///") {
+            docs.push(format!("//{}", doc));
+            continue;
+        }
+        if line == "#[repr(C)]" {
+            repr_c = true;
+            continue;
+        }
+        if line.starts_with("#[") {
+            continue;
+        }
+
+        if let Some(constant) = line.strip_prefix("pub const ") {
+            let (name, rest) = name_and_type(constant);
+            let value = rest.split('=').nth(1).expect("Constants have a value").trim().trim_end_matches(';');
+            start(&mut out, &docs, blank);
+            out.push_str(&format!("#define {} {}\n", name, value));
+        } else if let (true, Some(rest)) = (repr_c, line.strip_prefix("pub struct ")) {
+            let name = rest.trim_end_matches('{').trim();
+            start(&mut out, &docs, blank);
+            out.push_str(&format!("typedef struct {} {{\n", name));
+            for field in lines.by_ref().map(str::trim).take_while(|&field| field != "}") {
+                if let Some(doc) = field.strip_prefix("///") {
+                    out.push_str(&format!("    //{}\n", doc));
+                } else if let Some(field) = field.strip_prefix("pub ") {
+                    let (field, rust) = name_and_type(field.trim_end_matches(','));
+                    out.push_str(&format!("    {};\n", declaration(field, rust)));
+                }
+            }
+            out.push_str(&format!("}} {};\n", name));
+        } else if line.starts_with("pub ") && line.contains("extern \"C\" fn ") {
+            let signature = &line[line.find("fn ").expect("Functions have fn") + 3..];
+            let open = signature.find('(').expect("Functions have arguments");
+            let close = signature.rfind(')').expect("Functions have arguments");
+            let name = &signature[..open];
+            let arguments = signature[open + 1..close].split(',')
+                .filter(|argument| !argument.trim().is_empty())
+                .map(|argument| {
+                    let (argument, rust) = name_and_type(argument);
+                    declaration(argument, rust)
+                })
+                .collect::<Vec<_>>();
+            let returns = signature[close + 1..].trim().trim_end_matches('{').trim();
+            let returns = returns.strip_prefix("->").map_or("void".to_string(), c_type);
+            start(&mut out, &docs, blank);
+            let arguments = if arguments.is_empty() { "void".to_string() } else { arguments.join(", ") };
+            let separator = if returns.ends_with('*') { "" } else { " " };
+            out.push_str(&format!("{}{}{}({});\n", returns, separator, name, arguments));
+        }
+        if !line.starts_with("//") {
+            docs.clear();
+            repr_c = false;
+            blank = false;
+        }
+    }
+
+    out.push_str("\n#ifdef __cplusplus\n}\n#endif\n\n#endif\n");
+    out
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    let directory = env::var("CARGO_MANIFEST_DIR").expect("Cargo sets the manifest directory");
+    let source = fs::read_to_string(Path::new(&directory).join("src/lib.rs")).expect("src/lib.rs can be read");
+    let out = env::var("OUT_DIR").expect("Cargo sets the output directory");
+    fs::write(Path::new(&out).join("calecalc.h"), header(&source)).expect("calecalc.h can be written to OUT_DIR");
+}
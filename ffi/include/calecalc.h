@@ -0,0 +1,256 @@
+/* generated by build.rs from src/lib.rs, do not edit */
+// calecalc for C and C++, the header include/calecalc.h is generated from this file by build.rs
+//
+// conversions to R.D. dates check the date first and give back one of the CALECALC_ error codes,
+// the result is written through the pointer which may be null only when an error is returned.
+// every pointer has to be null or point to memory the function can write to
+
+#ifndef CALECALC_H
+#define CALECALC_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define CALECALC_OK 0
+#define CALECALC_INVALID_MONTH 1
+#define CALECALC_INVALID_DAY 2
+// a field other than the month or day is out of range
+#define CALECALC_INVALID_FIELD 3
+#define CALECALC_NULL_POINTER 4
+// there is no such date, like birkath hachama in most years
+#define CALECALC_NO_DATE 5
+
+// the roman events
+#define CALECALC_KALENDS 1
+#define CALECALC_NONES 2
+#define CALECALC_IDES 3
+
+typedef struct CalecalcGregorian {
+    int32_t year;
+    int32_t month;
+    int32_t day;
+} CalecalcGregorian;
+
+typedef struct CalecalcJulian {
+    int32_t year;
+    int32_t month;
+    int32_t day;
+} CalecalcJulian;
+
+typedef struct CalecalcRoman {
+    int32_t year;
+    int32_t month;
+    // one of CALECALC_KALENDS, CALECALC_NONES or CALECALC_IDES
+    int32_t event;
+    int32_t count;
+    bool leap;
+} CalecalcRoman;
+
+// both the coptic and the ethiopic calendar
+typedef struct CalecalcCopticEthiopic {
+    int32_t year;
+    int32_t month;
+    int32_t day;
+} CalecalcCopticEthiopic;
+
+typedef struct CalecalcIso {
+    int32_t year;
+    int32_t week;
+    int32_t day;
+} CalecalcIso;
+
+typedef struct CalecalcIslamic {
+    int32_t year;
+    int32_t month;
+    int32_t day;
+} CalecalcIslamic;
+
+// months are counted from nisan, tishrei is 7 and adar ii 13
+typedef struct CalecalcHebrew {
+    int32_t year;
+    int32_t month;
+    int32_t day;
+} CalecalcHebrew;
+
+typedef struct CalecalcHinduSolar {
+    int32_t year;
+    int32_t month;
+    int32_t day;
+} CalecalcHinduSolar;
+
+typedef struct CalecalcHinduLunisolar {
+    int32_t year;
+    int32_t month;
+    bool leap_month;
+    int32_t day;
+} CalecalcHinduLunisolar;
+
+typedef struct CalecalcLongCount {
+    int32_t baktun;
+    int32_t katun;
+    int32_t tun;
+    int32_t uinal;
+    int32_t kin;
+} CalecalcLongCount;
+
+typedef struct CalecalcHaab {
+    int32_t month;
+    int32_t day;
+} CalecalcHaab;
+
+typedef struct CalecalcTzolkin {
+    int32_t number;
+    int32_t name;
+} CalecalcTzolkin;
+
+typedef struct CalecalcBaliPowukon {
+    bool luang;
+    int32_t dwiwara;
+    int32_t triwara;
+    int32_t caturwara;
+    int32_t pancawara;
+    int32_t sadwara;
+    int32_t saptawara;
+    int32_t asatawara;
+    int32_t sangawara;
+    int32_t dasawara;
+} CalecalcBaliPowukon;
+
+typedef struct CalecalcOmer {
+    int32_t week;
+    int32_t day;
+} CalecalcOmer;
+
+// the R.D. dates a date falls on in a gregorian year, only the first count are set
+typedef struct CalecalcDates {
+    int32_t count;
+    int32_t dates[2];
+} CalecalcDates;
+
+// a description of an error code that lives as long as the program
+const char *calecalc_error_message(int32_t code);
+
+// 0 for sunday through 6 for saturday
+int32_t calecalc_day_of_week_from_fixed(int32_t fixed);
+
+int32_t calecalc_fixed_from_gregorian(CalecalcGregorian date, int32_t *fixed);
+
+int32_t calecalc_gregorian_from_fixed(int32_t fixed, CalecalcGregorian *date);
+
+int32_t calecalc_fixed_from_julian(CalecalcJulian date, int32_t *fixed);
+
+int32_t calecalc_julian_from_fixed(int32_t fixed, CalecalcJulian *date);
+
+int32_t calecalc_fixed_from_roman(CalecalcRoman date, int32_t *fixed);
+
+int32_t calecalc_roman_from_fixed(int32_t fixed, CalecalcRoman *date);
+
+int32_t calecalc_fixed_from_coptic(CalecalcCopticEthiopic date, int32_t *fixed);
+
+int32_t calecalc_coptic_from_fixed(int32_t fixed, CalecalcCopticEthiopic *date);
+
+int32_t calecalc_fixed_from_ethiopic(CalecalcCopticEthiopic date, int32_t *fixed);
+
+int32_t calecalc_ethiopic_from_fixed(int32_t fixed, CalecalcCopticEthiopic *date);
+
+int32_t calecalc_fixed_from_iso(CalecalcIso date, int32_t *fixed);
+
+int32_t calecalc_iso_from_fixed(int32_t fixed, CalecalcIso *date);
+
+int32_t calecalc_fixed_from_islamic(CalecalcIslamic date, int32_t *fixed);
+
+int32_t calecalc_islamic_from_fixed(int32_t fixed, CalecalcIslamic *date);
+
+int32_t calecalc_fixed_from_hebrew(CalecalcHebrew date, int32_t *fixed);
+
+int32_t calecalc_hebrew_from_fixed(int32_t fixed, CalecalcHebrew *date);
+
+int32_t calecalc_fixed_from_hindu_solar(CalecalcHinduSolar date, int32_t *fixed);
+
+int32_t calecalc_hindu_solar_from_fixed(int32_t fixed, CalecalcHinduSolar *date);
+
+int32_t calecalc_fixed_from_hindu_lunisolar(CalecalcHinduLunisolar date, int32_t *fixed);
+
+int32_t calecalc_hindu_lunisolar_from_fixed(int32_t fixed, CalecalcHinduLunisolar *date);
+
+int32_t calecalc_fixed_from_long_count(CalecalcLongCount date, int32_t *fixed);
+
+int32_t calecalc_long_count_from_fixed(int32_t fixed, CalecalcLongCount *date);
+
+// the last R.D. date on or before `fixed` with this haab date
+int32_t calecalc_haab_on_or_before(CalecalcHaab date, int32_t fixed, int32_t *result);
+
+int32_t calecalc_haab_from_fixed(int32_t fixed, CalecalcHaab *date);
+
+// the last R.D. date on or before `fixed` with this tzolkin date
+int32_t calecalc_tzolkin_on_or_before(CalecalcTzolkin date, int32_t fixed, int32_t *result);
+
+int32_t calecalc_tzolkin_from_fixed(int32_t fixed, CalecalcTzolkin *date);
+
+// the last R.D. date on or before `fixed` with this pawukon date
+int32_t calecalc_bali_powukon_on_or_before(CalecalcBaliPowukon date, int32_t fixed, int32_t *result);
+
+int32_t calecalc_bali_powukon_from_fixed(int32_t fixed, CalecalcBaliPowukon *date);
+
+int32_t calecalc_independence_day(int32_t year);
+
+int32_t calecalc_labor_day(int32_t year);
+
+int32_t calecalc_memorial_day(int32_t year);
+
+int32_t calecalc_election_day(int32_t year);
+
+int32_t calecalc_thanksgiving(int32_t year);
+
+int32_t calecalc_daylight_saving_start(int32_t year);
+
+int32_t calecalc_daylight_saving_end(int32_t year);
+
+int32_t calecalc_easter(int32_t gregorian_year);
+
+int32_t calecalc_orthodox_easter(int32_t gregorian_year);
+
+int32_t calecalc_yom_kippur(int32_t gregorian_year);
+
+int32_t calecalc_passover(int32_t gregorian_year);
+
+int32_t calecalc_hanukkah(int32_t gregorian_year);
+
+int32_t calecalc_purim(int32_t gregorian_year);
+
+int32_t calecalc_taanit_esther(int32_t gregorian_year);
+
+int32_t calecalc_tisha_bav(int32_t gregorian_year);
+
+int32_t calecalc_yom_hashoa(int32_t gregorian_year);
+
+int32_t calecalc_yom_hazikaron(int32_t gregorian_year);
+
+int32_t calecalc_sheela(int32_t gregorian_year);
+
+// CALECALC_NO_DATE in the years without it
+int32_t calecalc_birkath_hachama(int32_t gregorian_year, int32_t *fixed);
+
+// 10 tevet can fall twice in a gregorian year or not at all
+CalecalcDates calecalc_tzom_tevet(int32_t gregorian_year);
+
+// CALECALC_INVALID_MONTH or CALECALC_INVALID_DAY for a month or day no hebrew year has
+int32_t calecalc_hebrew_in_gregorian(int32_t month, int32_t day, int32_t gregorian_year, CalecalcDates *found);
+
+// CALECALC_NO_DATE outside of the omer
+int32_t calecalc_omer(int32_t fixed, CalecalcOmer *omer);
+
+int32_t calecalc_hebrew_birthday(CalecalcHebrew birth_date, int32_t year, int32_t *fixed);
+
+int32_t calecalc_yahrtzeit(CalecalcHebrew death_date, int32_t year, int32_t *fixed);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,684 @@
+//! calecalc for C and C++, the header include/calecalc.h is generated from this file by build.rs
+//!
+//! conversions to R.D. dates check the date first and give back one of the CALECALC_ error codes,
+//! the result is written through the pointer which may be null only when an error is returned.
+//! every pointer has to be null or point to memory the function can write to
+#![allow(clippy::missing_safety_doc)]
+
+extern crate calecalc;
+
+use calecalc::calendar::{Date, DateError};
+use calecalc::common::cycles_of_days::day_of_week_from_fixed;
+use calecalc::arithmetical::{gregorian, julian, coptic_ethiopic, iso, islamic, hebrew, hindu, mayan, balinese_powukon, ecclesiastical};
+use calecalc::arithmetical::julian::roman::{self, Event};
+use std::os::raw::c_char;
+
+pub const CALECALC_OK: i32 = 0;
+pub const CALECALC_INVALID_MONTH: i32 = 1;
+pub const CALECALC_INVALID_DAY: i32 = 2;
+/// a field other than the month or day is out of range
+pub const CALECALC_INVALID_FIELD: i32 = 3;
+pub const CALECALC_NULL_POINTER: i32 = 4;
+/// there is no such date, like birkath hachama in most years
+pub const CALECALC_NO_DATE: i32 = 5;
+
+/// the roman events
+pub const CALECALC_KALENDS: i32 = 1;
+pub const CALECALC_NONES: i32 = 2;
+pub const CALECALC_IDES: i32 = 3;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CalecalcGregorian {
+    pub year: i32,
+    pub month: i32,
+    pub day: i32
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CalecalcJulian {
+    pub year: i32,
+    pub month: i32,
+    pub day: i32
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CalecalcRoman {
+    pub year: i32,
+    pub month: i32,
+    /// one of CALECALC_KALENDS, CALECALC_NONES or CALECALC_IDES
+    pub event: i32,
+    pub count: i32,
+    pub leap: bool
+}
+
+/// both the coptic and the ethiopic calendar
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CalecalcCopticEthiopic {
+    pub year: i32,
+    pub month: i32,
+    pub day: i32
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CalecalcIso {
+    pub year: i32,
+    pub week: i32,
+    pub day: i32
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CalecalcIslamic {
+    pub year: i32,
+    pub month: i32,
+    pub day: i32
+}
+
+/// months are counted from nisan, tishrei is 7 and adar ii 13
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CalecalcHebrew {
+    pub year: i32,
+    pub month: i32,
+    pub day: i32
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CalecalcHinduSolar {
+    pub year: i32,
+    pub month: i32,
+    pub day: i32
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CalecalcHinduLunisolar {
+    pub year: i32,
+    pub month: i32,
+    pub leap_month: bool,
+    pub day: i32
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CalecalcLongCount {
+    pub baktun: i32,
+    pub katun: i32,
+    pub tun: i32,
+    pub uinal: i32,
+    pub kin: i32
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CalecalcHaab {
+    pub month: i32,
+    pub day: i32
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CalecalcTzolkin {
+    pub number: i32,
+    pub name: i32
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CalecalcBaliPowukon {
+    pub luang: bool,
+    pub dwiwara: i32,
+    pub triwara: i32,
+    pub caturwara: i32,
+    pub pancawara: i32,
+    pub sadwara: i32,
+    pub saptawara: i32,
+    pub asatawara: i32,
+    pub sangawara: i32,
+    pub dasawara: i32
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CalecalcOmer {
+    pub week: i32,
+    pub day: i32
+}
+
+/// the R.D. dates a date falls on in a gregorian year, only the first count are set
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CalecalcDates {
+    pub count: i32,
+    pub dates: [i32; 2]
+}
+
+impl From<CalecalcGregorian> for gregorian::Gregorian {
+    fn from(date: CalecalcGregorian) -> gregorian::Gregorian {
+        gregorian::Gregorian { year: date.year, month: date.month, day: date.day }
+    }
+}
+
+impl From<gregorian::Gregorian> for CalecalcGregorian {
+    fn from(date: gregorian::Gregorian) -> CalecalcGregorian {
+        CalecalcGregorian { year: date.year, month: date.month, day: date.day }
+    }
+}
+
+impl From<CalecalcJulian> for julian::Julian {
+    fn from(date: CalecalcJulian) -> julian::Julian {
+        julian::Julian { year: date.year, month: date.month, day: date.day }
+    }
+}
+
+impl From<julian::Julian> for CalecalcJulian {
+    fn from(date: julian::Julian) -> CalecalcJulian {
+        CalecalcJulian { year: date.year, month: date.month, day: date.day }
+    }
+}
+
+impl From<roman::Roman> for CalecalcRoman {
+    fn from(date: roman::Roman) -> CalecalcRoman {
+        let event = match date.event {
+            Event::Kalends => CALECALC_KALENDS,
+            Event::Nones => CALECALC_NONES,
+            Event::Ides => CALECALC_IDES
+        };
+        CalecalcRoman { year: date.year, month: date.month, event, count: date.count, leap: date.leap }
+    }
+}
+
+impl From<CalecalcCopticEthiopic> for coptic_ethiopic::CopticEthiopic {
+    fn from(date: CalecalcCopticEthiopic) -> coptic_ethiopic::CopticEthiopic {
+        coptic_ethiopic::CopticEthiopic { year: date.year, month: date.month, day: date.day }
+    }
+}
+
+impl From<coptic_ethiopic::CopticEthiopic> for CalecalcCopticEthiopic {
+    fn from(date: coptic_ethiopic::CopticEthiopic) -> CalecalcCopticEthiopic {
+        CalecalcCopticEthiopic { year: date.year, month: date.month, day: date.day }
+    }
+}
+
+impl From<CalecalcIso> for iso::Iso {
+    fn from(date: CalecalcIso) -> iso::Iso {
+        iso::Iso { year: date.year, week: date.week, day: date.day }
+    }
+}
+
+impl From<iso::Iso> for CalecalcIso {
+    fn from(date: iso::Iso) -> CalecalcIso {
+        CalecalcIso { year: date.year, week: date.week, day: date.day }
+    }
+}
+
+impl From<CalecalcIslamic> for islamic::Islamic {
+    fn from(date: CalecalcIslamic) -> islamic::Islamic {
+        islamic::Islamic { year: date.year, month: date.month, day: date.day }
+    }
+}
+
+impl From<islamic::Islamic> for CalecalcIslamic {
+    fn from(date: islamic::Islamic) -> CalecalcIslamic {
+        CalecalcIslamic { year: date.year, month: date.month, day: date.day }
+    }
+}
+
+impl From<CalecalcHebrew> for hebrew::Hebrew {
+    fn from(date: CalecalcHebrew) -> hebrew::Hebrew {
+        hebrew::Hebrew { year: date.year, month: date.month, day: date.day }
+    }
+}
+
+impl From<hebrew::Hebrew> for CalecalcHebrew {
+    fn from(date: hebrew::Hebrew) -> CalecalcHebrew {
+        CalecalcHebrew { year: date.year, month: date.month, day: date.day }
+    }
+}
+
+impl From<CalecalcHinduSolar> for hindu::solar::HinduSolar {
+    fn from(date: CalecalcHinduSolar) -> hindu::solar::HinduSolar {
+        hindu::solar::HinduSolar { year: date.year, month: date.month, day: date.day }
+    }
+}
+
+impl From<hindu::solar::HinduSolar> for CalecalcHinduSolar {
+    fn from(date: hindu::solar::HinduSolar) -> CalecalcHinduSolar {
+        CalecalcHinduSolar { year: date.year, month: date.month, day: date.day }
+    }
+}
+
+impl From<CalecalcHinduLunisolar> for hindu::lunisolar::HinduLunisolar {
+    fn from(date: CalecalcHinduLunisolar) -> hindu::lunisolar::HinduLunisolar {
+        hindu::lunisolar::HinduLunisolar { year: date.year, month: date.month, leap_month: date.leap_month, day: date.day }
+    }
+}
+
+impl From<hindu::lunisolar::HinduLunisolar> for CalecalcHinduLunisolar {
+    fn from(date: hindu::lunisolar::HinduLunisolar) -> CalecalcHinduLunisolar {
+        CalecalcHinduLunisolar { year: date.year, month: date.month, leap_month: date.leap_month, day: date.day }
+    }
+}
+
+impl From<CalecalcLongCount> for mayan::long_count::LongCount {
+    fn from(date: CalecalcLongCount) -> mayan::long_count::LongCount {
+        mayan::long_count::LongCount { baktun: date.baktun, katun: date.katun, tun: date.tun, uinal: date.uinal, kin: date.kin }
+    }
+}
+
+impl From<mayan::long_count::LongCount> for CalecalcLongCount {
+    fn from(date: mayan::long_count::LongCount) -> CalecalcLongCount {
+        CalecalcLongCount { baktun: date.baktun, katun: date.katun, tun: date.tun, uinal: date.uinal, kin: date.kin }
+    }
+}
+
+impl From<CalecalcHaab> for mayan::haab::Haab {
+    fn from(date: CalecalcHaab) -> mayan::haab::Haab {
+        mayan::haab::Haab { month: date.month, day: date.day }
+    }
+}
+
+impl From<mayan::haab::Haab> for CalecalcHaab {
+    fn from(date: mayan::haab::Haab) -> CalecalcHaab {
+        CalecalcHaab { month: date.month, day: date.day }
+    }
+}
+
+impl From<CalecalcTzolkin> for mayan::tzolkin::Tzolkin {
+    fn from(date: CalecalcTzolkin) -> mayan::tzolkin::Tzolkin {
+        mayan::tzolkin::Tzolkin { number: date.number, name: date.name }
+    }
+}
+
+impl From<mayan::tzolkin::Tzolkin> for CalecalcTzolkin {
+    fn from(date: mayan::tzolkin::Tzolkin) -> CalecalcTzolkin {
+        CalecalcTzolkin { number: date.number, name: date.name }
+    }
+}
+
+impl From<CalecalcBaliPowukon> for balinese_powukon::BaliPowukon {
+    fn from(date: CalecalcBaliPowukon) -> balinese_powukon::BaliPowukon {
+        balinese_powukon::BaliPowukon {
+            luang: date.luang,
+            dwiwara: date.dwiwara,
+            triwara: date.triwara,
+            caturwara: date.caturwara,
+            pancawara: date.pancawara,
+            sadwara: date.sadwara,
+            saptawara: date.saptawara,
+            asatawara: date.asatawara,
+            sangawara: date.sangawara,
+            dasawara: date.dasawara
+        }
+    }
+}
+
+impl From<balinese_powukon::BaliPowukon> for CalecalcBaliPowukon {
+    fn from(date: balinese_powukon::BaliPowukon) -> CalecalcBaliPowukon {
+        CalecalcBaliPowukon {
+            luang: date.luang,
+            dwiwara: date.dwiwara,
+            triwara: date.triwara,
+            caturwara: date.caturwara,
+            pancawara: date.pancawara,
+            sadwara: date.sadwara,
+            saptawara: date.saptawara,
+            asatawara: date.asatawara,
+            sangawara: date.sangawara,
+            dasawara: date.dasawara
+        }
+    }
+}
+
+fn error_code(error: DateError) -> i32 {
+    match error {
+        DateError::InvalidMonth => CALECALC_INVALID_MONTH,
+        DateError::InvalidDay => CALECALC_INVALID_DAY,
+        DateError::InvalidField(_) => CALECALC_INVALID_FIELD
+    }
+}
+
+unsafe fn write<T>(out: *mut T, value: T) -> i32 {
+    match out.as_mut() {
+        Some(out) => {
+            *out = value;
+            CALECALC_OK
+        },
+        None => CALECALC_NULL_POINTER
+    }
+}
+
+/// writes the R.D. date on or before `date` the date falls on, which is just its R.D. date outside the cyclic calendars
+unsafe fn write_fixed(date: Date, on_or_before: i32, out: *mut i32) -> i32 {
+    match date.validate() {
+        Ok(()) => write(out, date.on_or_before(on_or_before)),
+        Err(error) => error_code(error)
+    }
+}
+
+fn dates(found: Vec<i32>) -> CalecalcDates {
+    let mut dates = CalecalcDates { count: found.len().min(2) as i32, dates: [0; 2] };
+    for (date, &fixed) in dates.dates.iter_mut().zip(found.iter()) {
+        *date = fixed;
+    }
+    dates
+}
+
+/// a description of an error code that lives as long as the program
+#[no_mangle]
+pub extern "C" fn calecalc_error_message(code: i32) -> *const c_char {
+    let message: &'static [u8] = match code {
+        CALECALC_OK => b"no error\0",
+        CALECALC_INVALID_MONTH => b"month is not in the year\0",
+        CALECALC_INVALID_DAY => b"day is not in the month\0",
+        CALECALC_INVALID_FIELD => b"a field is out of range\0",
+        CALECALC_NULL_POINTER => b"a pointer was null\0",
+        CALECALC_NO_DATE => b"there is no such date\0",
+        _ => b"unknown error\0"
+    };
+    message.as_ptr() as *const c_char
+}
+
+/// 0 for sunday through 6 for saturday
+#[no_mangle]
+pub extern "C" fn calecalc_day_of_week_from_fixed(fixed: i32) -> i32 {
+    day_of_week_from_fixed(fixed)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_fixed_from_gregorian(date: CalecalcGregorian, fixed: *mut i32) -> i32 {
+    write_fixed(Date::Gregorian(date.into()), 0, fixed)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_gregorian_from_fixed(fixed: i32, date: *mut CalecalcGregorian) -> i32 {
+    write(date, gregorian::gregorian_from_fixed(fixed).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_fixed_from_julian(date: CalecalcJulian, fixed: *mut i32) -> i32 {
+    write_fixed(Date::Julian(date.into()), 0, fixed)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_julian_from_fixed(fixed: i32, date: *mut CalecalcJulian) -> i32 {
+    write(date, julian::julian_from_fixed(fixed).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_fixed_from_roman(date: CalecalcRoman, fixed: *mut i32) -> i32 {
+    let event = match date.event {
+        CALECALC_KALENDS => Event::Kalends,
+        CALECALC_NONES => Event::Nones,
+        CALECALC_IDES => Event::Ides,
+        _ => return CALECALC_INVALID_FIELD
+    };
+    let roman = roman::Roman { year: date.year, month: date.month, event, count: date.count, leap: date.leap };
+    write_fixed(Date::Roman(roman), 0, fixed)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_roman_from_fixed(fixed: i32, date: *mut CalecalcRoman) -> i32 {
+    write(date, roman::roman_from_fixed(fixed).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_fixed_from_coptic(date: CalecalcCopticEthiopic, fixed: *mut i32) -> i32 {
+    write_fixed(Date::Coptic(date.into()), 0, fixed)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_coptic_from_fixed(fixed: i32, date: *mut CalecalcCopticEthiopic) -> i32 {
+    write(date, coptic_ethiopic::coptic::coptic_from_fixed(fixed).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_fixed_from_ethiopic(date: CalecalcCopticEthiopic, fixed: *mut i32) -> i32 {
+    write_fixed(Date::Ethiopic(date.into()), 0, fixed)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_ethiopic_from_fixed(fixed: i32, date: *mut CalecalcCopticEthiopic) -> i32 {
+    write(date, coptic_ethiopic::ethiopic::ethiopic_from_fixed(fixed).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_fixed_from_iso(date: CalecalcIso, fixed: *mut i32) -> i32 {
+    write_fixed(Date::Iso(date.into()), 0, fixed)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_iso_from_fixed(fixed: i32, date: *mut CalecalcIso) -> i32 {
+    write(date, iso::iso_from_fixed(fixed).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_fixed_from_islamic(date: CalecalcIslamic, fixed: *mut i32) -> i32 {
+    write_fixed(Date::Islamic(date.into()), 0, fixed)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_islamic_from_fixed(fixed: i32, date: *mut CalecalcIslamic) -> i32 {
+    write(date, islamic::islamic_from_fixed(fixed).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_fixed_from_hebrew(date: CalecalcHebrew, fixed: *mut i32) -> i32 {
+    write_fixed(Date::Hebrew(date.into()), 0, fixed)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_hebrew_from_fixed(fixed: i32, date: *mut CalecalcHebrew) -> i32 {
+    write(date, hebrew::hebrew_from_fixed(fixed).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_fixed_from_hindu_solar(date: CalecalcHinduSolar, fixed: *mut i32) -> i32 {
+    write_fixed(Date::HinduSolar(date.into()), 0, fixed)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_hindu_solar_from_fixed(fixed: i32, date: *mut CalecalcHinduSolar) -> i32 {
+    write(date, hindu::solar::hindu_solar_from_fixed(fixed).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_fixed_from_hindu_lunisolar(date: CalecalcHinduLunisolar, fixed: *mut i32) -> i32 {
+    write_fixed(Date::HinduLunisolar(date.into()), 0, fixed)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_hindu_lunisolar_from_fixed(fixed: i32, date: *mut CalecalcHinduLunisolar) -> i32 {
+    write(date, hindu::lunisolar::hindu_lunisolar_from_fixed(fixed).into())
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_fixed_from_long_count(date: CalecalcLongCount, fixed: *mut i32) -> i32 {
+    write_fixed(Date::LongCount(date.into()), 0, fixed)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_long_count_from_fixed(fixed: i32, date: *mut CalecalcLongCount) -> i32 {
+    write(date, mayan::long_count::long_count_from_fixed(fixed).into())
+}
+
+/// the last R.D. date on or before `fixed` with this haab date
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_haab_on_or_before(date: CalecalcHaab, fixed: i32, result: *mut i32) -> i32 {
+    write_fixed(Date::Haab(date.into()), fixed, result)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_haab_from_fixed(fixed: i32, date: *mut CalecalcHaab) -> i32 {
+    write(date, mayan::haab::mayan_haab_from_fixed(fixed).into())
+}
+
+/// the last R.D. date on or before `fixed` with this tzolkin date
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_tzolkin_on_or_before(date: CalecalcTzolkin, fixed: i32, result: *mut i32) -> i32 {
+    write_fixed(Date::Tzolkin(date.into()), fixed, result)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_tzolkin_from_fixed(fixed: i32, date: *mut CalecalcTzolkin) -> i32 {
+    write(date, mayan::tzolkin::mayan_tzolkin_from_fixed(fixed).into())
+}
+
+/// the last R.D. date on or before `fixed` with this pawukon date
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_bali_powukon_on_or_before(date: CalecalcBaliPowukon, fixed: i32, result: *mut i32) -> i32 {
+    write_fixed(Date::BaliPowukon(date.into()), fixed, result)
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_bali_powukon_from_fixed(fixed: i32, date: *mut CalecalcBaliPowukon) -> i32 {
+    write(date, balinese_powukon::bali_powukon_from_fixed(fixed).into())
+}
+
+#[no_mangle]
+pub extern "C" fn calecalc_independence_day(year: i32) -> i32 {
+    gregorian::independence_day(year)
+}
+
+#[no_mangle]
+pub extern "C" fn calecalc_labor_day(year: i32) -> i32 {
+    gregorian::labor_day(year)
+}
+
+#[no_mangle]
+pub extern "C" fn calecalc_memorial_day(year: i32) -> i32 {
+    gregorian::memorial_day(year)
+}
+
+#[no_mangle]
+pub extern "C" fn calecalc_election_day(year: i32) -> i32 {
+    gregorian::election_day(year)
+}
+
+#[no_mangle]
+pub extern "C" fn calecalc_thanksgiving(year: i32) -> i32 {
+    gregorian::thanksgiving(year)
+}
+
+#[no_mangle]
+pub extern "C" fn calecalc_daylight_saving_start(year: i32) -> i32 {
+    gregorian::daylight_saving_start(year)
+}
+
+#[no_mangle]
+pub extern "C" fn calecalc_daylight_saving_end(year: i32) -> i32 {
+    gregorian::daylight_saving_end(year)
+}
+
+#[no_mangle]
+pub extern "C" fn calecalc_easter(gregorian_year: i32) -> i32 {
+    ecclesiastical::easter(gregorian_year)
+}
+
+#[no_mangle]
+pub extern "C" fn calecalc_orthodox_easter(gregorian_year: i32) -> i32 {
+    ecclesiastical::orthodox_easter(gregorian_year)
+}
+
+#[no_mangle]
+pub extern "C" fn calecalc_yom_kippur(gregorian_year: i32) -> i32 {
+    hebrew::yom_kippur(gregorian_year)
+}
+
+#[no_mangle]
+pub extern "C" fn calecalc_passover(gregorian_year: i32) -> i32 {
+    hebrew::passover(gregorian_year)
+}
+
+#[no_mangle]
+pub extern "C" fn calecalc_hanukkah(gregorian_year: i32) -> i32 {
+    hebrew::hanukkah(gregorian_year)
+}
+
+#[no_mangle]
+pub extern "C" fn calecalc_purim(gregorian_year: i32) -> i32 {
+    hebrew::purim(gregorian_year)
+}
+
+#[no_mangle]
+pub extern "C" fn calecalc_taanit_esther(gregorian_year: i32) -> i32 {
+    hebrew::taanit_esther(gregorian_year)
+}
+
+#[no_mangle]
+pub extern "C" fn calecalc_tisha_bav(gregorian_year: i32) -> i32 {
+    hebrew::tisha_bav(gregorian_year)
+}
+
+#[no_mangle]
+pub extern "C" fn calecalc_yom_hashoa(gregorian_year: i32) -> i32 {
+    hebrew::yom_hashoa(gregorian_year)
+}
+
+#[no_mangle]
+pub extern "C" fn calecalc_yom_hazikaron(gregorian_year: i32) -> i32 {
+    hebrew::yom_hazikaron(gregorian_year)
+}
+
+#[no_mangle]
+pub extern "C" fn calecalc_sheela(gregorian_year: i32) -> i32 {
+    hebrew::sheela(gregorian_year)
+}
+
+/// CALECALC_NO_DATE in the years without it
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_birkath_hachama(gregorian_year: i32, fixed: *mut i32) -> i32 {
+    match hebrew::birkath_hachama(gregorian_year) {
+        Some(date) => write(fixed, date),
+        None => CALECALC_NO_DATE
+    }
+}
+
+/// 10 tevet can fall twice in a gregorian year or not at all
+#[no_mangle]
+pub extern "C" fn calecalc_tzom_tevet(gregorian_year: i32) -> CalecalcDates {
+    dates(hebrew::tzom_tevet(gregorian_year))
+}
+
+/// CALECALC_INVALID_MONTH or CALECALC_INVALID_DAY for a month or day no hebrew year has
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_hebrew_in_gregorian(month: i32, day: i32, gregorian_year: i32, found: *mut CalecalcDates) -> i32 {
+    if !(1..=13).contains(&month) {
+        return CALECALC_INVALID_MONTH;
+    }
+    if !(1..=30).contains(&day) {
+        return CALECALC_INVALID_DAY;
+    }
+    write(found, dates(hebrew::hebrew_in_gregorian(month, day, gregorian_year)))
+}
+
+/// CALECALC_NO_DATE outside of the omer
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_omer(fixed: i32, omer: *mut CalecalcOmer) -> i32 {
+    match hebrew::omer(fixed) {
+        Some(hebrew::Omer { week, day }) => write(omer, CalecalcOmer { week, day }),
+        None => CALECALC_NO_DATE
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_hebrew_birthday(birth_date: CalecalcHebrew, year: i32, fixed: *mut i32) -> i32 {
+    match Date::Hebrew(birth_date.into()).validate() {
+        Ok(()) => write(fixed, hebrew::hebrew_birthday(birth_date.into(), year)),
+        Err(error) => error_code(error)
+    }
+}
+
+#[no_mangle]
+pub unsafe extern "C" fn calecalc_yahrtzeit(death_date: CalecalcHebrew, year: i32, fixed: *mut i32) -> i32 {
+    match Date::Hebrew(death_date.into()).validate() {
+        Ok(()) => write(fixed, hebrew::yahrtzeit(death_date.into(), year)),
+        Err(error) => error_code(error)
+    }
+}
@@ -0,0 +1,192 @@
+// exercises the C interface, compiled and run by tests/c_program.rs
+//
+//     cc tests/c/test.c -Iinclude ../target/debug/libcalecalc_ffi.a -lpthread -ldl -lm -o test && ./test
+
+#include <stdio.h>
+#include <string.h>
+#include "calecalc.h"
+
+static int failures = 0;
+
+#define CHECK(condition) do { \
+    if (!(condition)) { \
+        fprintf(stderr, "%s:%d: %s\n", __FILE__, __LINE__, #condition); \
+        failures++; \
+    } \
+} while (0)
+
+// the example date of calendrical calculations, gregorian 1945-11-12
+static const int32_t FIXED = 710347;
+
+static void test_conversions(void) {
+    int32_t fixed = 0;
+
+    CalecalcGregorian gregorian = { 1945, 11, 12 };
+    CHECK(calecalc_fixed_from_gregorian(gregorian, &fixed) == CALECALC_OK && fixed == FIXED);
+    CHECK(calecalc_gregorian_from_fixed(FIXED, &gregorian) == CALECALC_OK);
+    CHECK(gregorian.year == 1945 && gregorian.month == 11 && gregorian.day == 12);
+
+    CalecalcJulian julian;
+    CHECK(calecalc_julian_from_fixed(FIXED, &julian) == CALECALC_OK);
+    CHECK(julian.year == 1945 && julian.month == 10 && julian.day == 30);
+    CHECK(calecalc_fixed_from_julian(julian, &fixed) == CALECALC_OK && fixed == FIXED);
+
+    CalecalcRoman roman;
+    CHECK(calecalc_roman_from_fixed(FIXED, &roman) == CALECALC_OK);
+    CHECK(roman.year == 1945 && roman.month == 11 && roman.event == CALECALC_KALENDS && roman.count == 3 && !roman.leap);
+    CHECK(calecalc_fixed_from_roman(roman, &fixed) == CALECALC_OK && fixed == FIXED);
+
+    CalecalcCopticEthiopic coptic;
+    CHECK(calecalc_coptic_from_fixed(FIXED, &coptic) == CALECALC_OK);
+    CHECK(coptic.year == 1662 && coptic.month == 3 && coptic.day == 3);
+    CHECK(calecalc_fixed_from_coptic(coptic, &fixed) == CALECALC_OK && fixed == FIXED);
+
+    CalecalcCopticEthiopic ethiopic;
+    CHECK(calecalc_ethiopic_from_fixed(FIXED, &ethiopic) == CALECALC_OK);
+    CHECK(ethiopic.year == 1938 && ethiopic.month == 3 && ethiopic.day == 3);
+    CHECK(calecalc_fixed_from_ethiopic(ethiopic, &fixed) == CALECALC_OK && fixed == FIXED);
+
+    CalecalcIso iso;
+    CHECK(calecalc_iso_from_fixed(FIXED, &iso) == CALECALC_OK);
+    CHECK(iso.year == 1945 && iso.week == 46 && iso.day == 1);
+    CHECK(calecalc_fixed_from_iso(iso, &fixed) == CALECALC_OK && fixed == FIXED);
+
+    CalecalcIslamic islamic;
+    CHECK(calecalc_islamic_from_fixed(FIXED, &islamic) == CALECALC_OK);
+    CHECK(islamic.year == 1364 && islamic.month == 12 && islamic.day == 6);
+    CHECK(calecalc_fixed_from_islamic(islamic, &fixed) == CALECALC_OK && fixed == FIXED);
+
+    CalecalcHebrew hebrew;
+    CHECK(calecalc_hebrew_from_fixed(FIXED, &hebrew) == CALECALC_OK);
+    CHECK(hebrew.year == 5706 && hebrew.month == 9 && hebrew.day == 7);
+    CHECK(calecalc_fixed_from_hebrew(hebrew, &fixed) == CALECALC_OK && fixed == FIXED);
+
+    CalecalcHinduSolar solar;
+    CHECK(calecalc_hindu_solar_from_fixed(FIXED, &solar) == CALECALC_OK);
+    CHECK(solar.year == 5046 && solar.month == 7 && solar.day == 29);
+    CHECK(calecalc_fixed_from_hindu_solar(solar, &fixed) == CALECALC_OK && fixed == FIXED);
+
+    CalecalcHinduLunisolar lunisolar;
+    CHECK(calecalc_hindu_lunisolar_from_fixed(FIXED, &lunisolar) == CALECALC_OK);
+    CHECK(lunisolar.year == 5046 && lunisolar.month == 8 && !lunisolar.leap_month && lunisolar.day == 8);
+    CHECK(calecalc_fixed_from_hindu_lunisolar(lunisolar, &fixed) == CALECALC_OK && fixed == FIXED);
+
+    CalecalcLongCount long_count;
+    CHECK(calecalc_long_count_from_fixed(FIXED, &long_count) == CALECALC_OK);
+    CHECK(long_count.baktun == 12 && long_count.katun == 16 && long_count.tun == 11 && long_count.uinal == 16 && long_count.kin == 9);
+    CHECK(calecalc_fixed_from_long_count(long_count, &fixed) == CALECALC_OK && fixed == FIXED);
+
+    CalecalcHaab haab;
+    CHECK(calecalc_haab_from_fixed(FIXED, &haab) == CALECALC_OK);
+    CHECK(haab.month == 11 && haab.day == 7);
+    CHECK(calecalc_haab_on_or_before(haab, FIXED + 100, &fixed) == CALECALC_OK && fixed == FIXED);
+
+    CalecalcTzolkin tzolkin;
+    CHECK(calecalc_tzolkin_from_fixed(FIXED, &tzolkin) == CALECALC_OK);
+    CHECK(tzolkin.number == 11 && tzolkin.name == 9);
+    CHECK(calecalc_tzolkin_on_or_before(tzolkin, FIXED + 100, &fixed) == CALECALC_OK && fixed == FIXED);
+
+    CalecalcBaliPowukon bali;
+    CHECK(calecalc_bali_powukon_from_fixed(FIXED, &bali) == CALECALC_OK);
+    CHECK(bali.luang && bali.dwiwara == 2 && bali.pancawara == 3 && bali.saptawara == 2 && bali.dasawara == 2);
+    CHECK(calecalc_bali_powukon_on_or_before(bali, FIXED + 100, &fixed) == CALECALC_OK && fixed == FIXED);
+
+    CHECK(calecalc_day_of_week_from_fixed(FIXED) == 1);
+}
+
+static void test_errors(void) {
+    int32_t fixed = 0;
+
+    CalecalcGregorian february30 = { 2026, 2, 30 };
+    CHECK(calecalc_fixed_from_gregorian(february30, &fixed) == CALECALC_INVALID_DAY);
+    CalecalcGregorian month13 = { 2026, 13, 1 };
+    CHECK(calecalc_fixed_from_gregorian(month13, &fixed) == CALECALC_INVALID_MONTH);
+    CalecalcGregorian valid = { 2026, 1, 1 };
+    CHECK(calecalc_fixed_from_gregorian(valid, NULL) == CALECALC_NULL_POINTER);
+    CHECK(calecalc_gregorian_from_fixed(FIXED, NULL) == CALECALC_NULL_POINTER);
+
+    // 5786 is a common year so it has no adar ii
+    CalecalcHebrew adar2 = { 5786, 13, 1 };
+    CHECK(calecalc_fixed_from_hebrew(adar2, &fixed) == CALECALC_INVALID_MONTH);
+    CalecalcHebrew month0 = { 5785, 0, 1 };
+    CHECK(calecalc_fixed_from_hebrew(month0, &fixed) == CALECALC_INVALID_MONTH);
+    CalecalcHebrew month14 = { 5785, 14, 1 };
+    CHECK(calecalc_fixed_from_hebrew(month14, &fixed) == CALECALC_INVALID_MONTH);
+    CHECK(calecalc_hebrew_birthday(month0, 5786, &fixed) == CALECALC_INVALID_MONTH);
+    CHECK(calecalc_yahrtzeit(month14, 5786, &fixed) == CALECALC_INVALID_MONTH);
+
+    CalecalcDates dates;
+    CHECK(calecalc_hebrew_in_gregorian(0, 1, 2026, &dates) == CALECALC_INVALID_MONTH);
+    CHECK(calecalc_hebrew_in_gregorian(14, 1, 2026, &dates) == CALECALC_INVALID_MONTH);
+    CHECK(calecalc_hebrew_in_gregorian(7, 31, 2026, &dates) == CALECALC_INVALID_DAY);
+    CHECK(calecalc_hebrew_in_gregorian(7, 1, 2026, NULL) == CALECALC_NULL_POINTER);
+
+    CalecalcRoman roman = { 2026, 1, 7, 1, false };
+    CHECK(calecalc_fixed_from_roman(roman, &fixed) == CALECALC_INVALID_FIELD);
+
+    CalecalcLongCount long_count = { 13, 0, 0, 0, 20 };
+    CHECK(calecalc_fixed_from_long_count(long_count, &fixed) == CALECALC_INVALID_FIELD);
+
+    CalecalcTzolkin tzolkin = { 14, 1 };
+    CHECK(calecalc_tzolkin_on_or_before(tzolkin, FIXED, &fixed) == CALECALC_INVALID_FIELD);
+
+    CHECK(strcmp(calecalc_error_message(CALECALC_INVALID_DAY), "day is not in the month") == 0);
+    CHECK(strcmp(calecalc_error_message(-1), "unknown error") == 0);
+}
+
+static void test_holidays(void) {
+    int32_t fixed = 0;
+    CalecalcGregorian date;
+
+    calecalc_gregorian_from_fixed(calecalc_thanksgiving(2013), &date);
+    CHECK(date.month == 11 && date.day == 28);
+    CHECK(calecalc_hanukkah(2013) == calecalc_thanksgiving(2013));
+    calecalc_gregorian_from_fixed(calecalc_easter(2026), &date);
+    CHECK(date.month == 4 && date.day == 5);
+    calecalc_gregorian_from_fixed(calecalc_orthodox_easter(2026), &date);
+    CHECK(date.month == 4 && date.day == 12);
+    calecalc_gregorian_from_fixed(calecalc_passover(2026), &date);
+    CHECK(date.month == 4 && date.day == 2);
+    calecalc_gregorian_from_fixed(calecalc_yom_kippur(2026), &date);
+    CHECK(date.month == 9 && date.day == 21);
+    calecalc_gregorian_from_fixed(calecalc_labor_day(2026), &date);
+    CHECK(date.month == 9 && date.day == 7);
+
+    CHECK(calecalc_birkath_hachama(2009, &fixed) == CALECALC_OK);
+    calecalc_gregorian_from_fixed(fixed, &date);
+    CHECK(date.month == 4 && date.day == 8);
+    CHECK(calecalc_birkath_hachama(2026, &fixed) == CALECALC_NO_DATE);
+
+    CalecalcDates tevet = calecalc_tzom_tevet(2026);
+    CHECK(tevet.count == 1);
+    calecalc_gregorian_from_fixed(tevet.dates[0], &date);
+    CHECK(date.month == 12 && date.day == 20);
+
+    // rosh hashanah 5787 is the only 1 tishri of 2026
+    CalecalcDates tishri;
+    CHECK(calecalc_hebrew_in_gregorian(7, 1, 2026, &tishri) == CALECALC_OK && tishri.count == 1);
+    calecalc_gregorian_from_fixed(tishri.dates[0], &date);
+    CHECK(date.month == 9 && date.day == 12);
+
+    CalecalcOmer omer;
+    CHECK(calecalc_omer(calecalc_passover(2026) + 1, &omer) == CALECALC_OK && omer.week == 0 && omer.day == 1);
+    CHECK(calecalc_omer(calecalc_yom_kippur(2026), &omer) == CALECALC_NO_DATE);
+
+    CalecalcHebrew birth = { 5706, 9, 7 };
+    CHECK(calecalc_hebrew_birthday(birth, 5706, &fixed) == CALECALC_OK && fixed == FIXED);
+    CHECK(calecalc_yahrtzeit(birth, 5706, &fixed) == CALECALC_OK && fixed == FIXED);
+    CalecalcHebrew invalid = { 5706, 9, 31 };
+    CHECK(calecalc_yahrtzeit(invalid, 5786, &fixed) == CALECALC_INVALID_DAY);
+}
+
+int main(void) {
+    test_conversions();
+    test_errors();
+    test_holidays();
+    if (failures > 0) {
+        fprintf(stderr, "%d checks failed\n", failures);
+        return 1;
+    }
+    printf("all checks passed\n");
+    return 0;
+}
@@ -0,0 +1,34 @@
+use std::env;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// compiles tests/c/test.c against the static library and runs it, skipped when there is no C compiler
+#[test]
+fn c_program() {
+    let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+    if Command::new(&compiler).arg("--version").output().is_err() {
+        eprintln!("skipping the C test, {} was not found", compiler);
+        return;
+    }
+
+    let manifest = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    // the test binary is in target/<profile>/deps and the library in target/<profile>
+    let executable = env::current_exe().expect("The test knows where it is");
+    let profile = executable.parent().and_then(|deps| deps.parent()).expect("Tests are in target/<profile>/deps");
+    let library = profile.join("libcalecalc_ffi.a");
+    let program = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("calecalc_c_test");
+
+    let compiled = Command::new(&compiler)
+        .arg(manifest.join("tests/c/test.c"))
+        .arg("-I").arg(manifest.join("include"))
+        .arg("-std=c99").arg("-Wall").arg("-Werror")
+        .arg(&library)
+        .args(["-lpthread", "-ldl", "-lm"])
+        .arg("-o").arg(&program)
+        .status()
+        .expect("The compiler runs");
+    assert!(compiled.success(), "compiling tests/c/test.c failed");
+
+    let output = Command::new(&program).output().expect("The C test runs");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+}
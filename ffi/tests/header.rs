@@ -0,0 +1,14 @@
+use std::fs;
+use std::path::PathBuf;
+
+/// the header build.rs generates from src/lib.rs
+const GENERATED: &str = include_str!(concat!(env!("OUT_DIR"), "/calecalc.h"));
+
+/// include/calecalc.h is committed so C programs can use it without building, it has to be
+/// copied from the generated one whenever src/lib.rs changes what C sees
+#[test]
+fn header_is_up_to_date() {
+    let committed = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("include/calecalc.h");
+    let header = fs::read_to_string(&committed).expect("include/calecalc.h can be read");
+    assert!(header == GENERATED, "include/calecalc.h is out of date, copy it from {}/calecalc.h", env!("OUT_DIR"));
+}
@@ -0,0 +1,155 @@
+//! anniversaries of dates that dont exist every year
+//!
+//! a 30th in a month that only sometimes has 30 days (marheshvan, kislev, adar i, dhu al-hijja),
+//! the 6th epagomenal day of the coptic and ethiopic calendars and february 29th all need a rule
+//! for the years they are missing in, and hebrew dates in adar need one for leap years
+
+use calendar::{Calendar, Date};
+use common::RD;
+use arithmetical::gregorian::{fixed_from_gregorian, Gregorian};
+use arithmetical::hebrew::{fixed_from_hebrew, is_hebrew_leap_year, last_day_of_hebrew_month, Hebrew};
+use alloc::vec::Vec;
+
+/// what to do when the day of the month doesnt exist in the year
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MissingDay {
+    /// the last day of the month, february 28th or 29 marheshvan
+    LastDay,
+    /// the day after the last day of the month, march 1st or 1 kislev
+    NextDay
+}
+
+/// which adar a date in adar of a common year falls in during a leap year
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Adar {
+    AdarI,
+    AdarII,
+    Both
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HebrewRule {
+    pub adar: Adar,
+    pub missing_day: MissingDay,
+    /// 30 adar i is kept on 30 shevat in common years instead of by the missing day rule
+    pub shevat: bool
+}
+
+/// the rema, a yahrzeit from adar is kept in adar i, same as `hebrew::yahrtzeit` apart from
+/// 30 marheshvan and 30 kislev which it keeps by the missing day rule
+pub const ASHKENAZI_YAHRZEIT: HebrewRule = HebrewRule { adar: Adar::AdarI, missing_day: MissingDay::LastDay, shevat: true };
+
+/// the shulchan aruch, a yahrzeit from adar is kept in adar ii
+pub const SEPHARDI_YAHRZEIT: HebrewRule = HebrewRule { adar: Adar::AdarII, missing_day: MissingDay::LastDay, shevat: true };
+
+/// a birthday (like a bar mitzvah) from adar is in adar ii, same as `hebrew::hebrew_birthday`
+pub const BIRTHDAY: HebrewRule = HebrewRule { adar: Adar::AdarII, missing_day: MissingDay::NextDay, shevat: false };
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Rule {
+    Hebrew(HebrewRule),
+    /// for every other calendar only the missing day needs a rule
+    MissingDay(MissingDay)
+}
+
+/// an anniversary as the date it is kept on in the calendar of the original date and as an R.D. date
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Observance {
+    pub date: Date,
+    pub fixed: RD
+}
+
+/// the anniversaries of a hebrew date in a hebrew year, there are two when adar is kept in both adars
+pub fn hebrew_anniversary(date: Hebrew, year: i32, rule: HebrewRule) -> Vec<RD> {
+    let months = match date.month {
+        13 => vec![if is_hebrew_leap_year(year) { 13 } else { 12 }],
+        12 if !is_hebrew_leap_year(date.year) && is_hebrew_leap_year(year) => match rule.adar {
+            Adar::AdarI => vec![12],
+            Adar::AdarII => vec![13],
+            Adar::Both => vec![12, 13]
+        },
+        month => vec![month]
+    };
+
+    months.into_iter().map(|month| {
+        let last_day = last_day_of_hebrew_month(month, year);
+        // only adar i has a 30th so it is the one missing in a common year
+        if rule.shevat && month == 12 && date.day == 30 && last_day == 29 {
+            return fixed_from_hebrew(Hebrew { year, month: 11, day: 30 });
+        }
+        if date.day <= last_day {
+            fixed_from_hebrew(Hebrew { year, month, day: date.day })
+        } else {
+            fixed_from_hebrew(Hebrew { year, month, day: last_day }) + match rule.missing_day {
+                MissingDay::LastDay => 0,
+                MissingDay::NextDay => 1
+            }
+        }
+    }).collect()
+}
+
+/// the anniversary of a year, month and day date in another year of its calendar
+/// works for any calendar whose dates start with year, month and day
+pub fn anniversary(date: Date, year: i32, missing_day: MissingDay) -> Option<RD> {
+    let calendar = date.calendar();
+    if calendar.fields().get(..2) != Some(&["year", "month"][..]) || !calendar.fields().contains(&"day") {
+        return None;
+    }
+    let with_day = |day: i32| {
+        let mut values = date.field_values();
+        values[0] = year;
+        let last = values.len() - 1;
+        values[last] = day;
+        calendar.date_from_fields(&values)
+    };
+    let exists = |candidate: Date| candidate.to_fixed().map(|fixed| calendar.from_fixed(fixed) == candidate);
+    let day = date.field("day")?;
+
+    // the day that is missing is always one of the last few days of the month so step back from it
+    (1..=day).rev().filter_map(|candidate| with_day(candidate).map(|date| (candidate, date)))
+        .find(|&(_, candidate)| exists(candidate) == Some(true))
+        .and_then(|(found, candidate)| candidate.to_fixed().map(|fixed| if found == day {
+            fixed
+        } else {
+            match missing_day {
+                MissingDay::LastDay => fixed,
+                MissingDay::NextDay => fixed + 1
+            }
+        }))
+}
+
+/// the anniversaries of a date in a year of its own calendar
+pub fn observances(date: Date, year: i32, rule: Rule) -> Vec<Observance> {
+    let calendar = date.calendar();
+    let fixed = match (date, rule) {
+        (Date::Hebrew(hebrew), Rule::Hebrew(rule)) => hebrew_anniversary(hebrew, year, rule),
+        (Date::Hebrew(hebrew), Rule::MissingDay(missing_day)) => hebrew_anniversary(hebrew, year, HebrewRule { adar: BIRTHDAY.adar, missing_day, shevat: false }),
+        (_, Rule::MissingDay(missing_day)) => anniversary(date, year, missing_day).into_iter().collect(),
+        (_, Rule::Hebrew(rule)) => anniversary(date, year, rule.missing_day).into_iter().collect()
+    };
+    fixed.into_iter().map(|fixed| Observance { date: calendar.from_fixed(fixed), fixed }).collect()
+}
+
+/// the anniversaries of a date that fall in a gregorian year
+pub fn observances_in_gregorian(date: Date, gregorian_year: i32, rule: Rule) -> Vec<Observance> {
+    let calendar = date.calendar();
+    let jan1 = fixed_from_gregorian(Gregorian { year: gregorian_year, month: 1, day: 1 });
+    let dec31 = fixed_from_gregorian(Gregorian { year: gregorian_year, month: 12, day: 31 });
+    let year = |fixed: RD| calendar.from_fixed(fixed).field("year");
+
+    match (year(jan1), year(dec31)) {
+        (Some(first), Some(last)) => (first..=last)
+            .flat_map(|year| observances(date, year, rule))
+            .filter(|observance| jan1 <= observance.fixed && observance.fixed <= dec31)
+            .collect(),
+        _ => Vec::new()
+    }
+}
+
+/// the default rule for a calendar, birthdays for hebrew dates and the last day of the month otherwise
+pub fn default_rule(calendar: Calendar) -> Rule {
+    match calendar {
+        Calendar::Hebrew => Rule::Hebrew(BIRTHDAY),
+        _ => Rule::MissingDay(MissingDay::LastDay)
+    }
+}
@@ -1,5 +1,5 @@
 use common::{divide, alternate_divide, RD};
-use std::cmp::max;
+use core::cmp::max;
 
 // julian day 146
 pub static EPOCH: i32 = -1721279;
@@ -4,15 +4,15 @@ use super::*;
 // julian 8/29/284
 const EPOCH: RD = 103605;
 
-pub fn is_coptic_leap_year(year: i32) -> bool {
+pub const fn is_coptic_leap_year(year: i32) -> bool {
     is_leap_year(year)
 }
 
-pub fn fixed_from_coptic(date: CopticEthiopic) -> RD {
+pub const fn fixed_from_coptic(date: CopticEthiopic) -> RD {
     fixed_from_date(date, EPOCH)
 }
 
-pub fn coptic_from_fixed(date: RD) -> CopticEthiopic {
+pub const fn coptic_from_fixed(date: RD) -> CopticEthiopic {
     date_from_fixed(date, EPOCH)
 }
 
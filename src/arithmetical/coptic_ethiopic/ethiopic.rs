@@ -4,15 +4,15 @@ use super::*;
 // julian 8/29/4
 const EPOCH: RD = 2796;
 
-pub fn is_ethiopic_leap_year(year: i32) -> bool {
+pub const fn is_ethiopic_leap_year(year: i32) -> bool {
     is_leap_year(year)
 }
 
-pub fn fixed_from_ethiopic(date: CopticEthiopic) -> RD {
+pub const fn fixed_from_ethiopic(date: CopticEthiopic) -> RD {
     fixed_from_date(date, EPOCH)
 }
 
-pub fn ethiopic_from_fixed(date: RD) -> CopticEthiopic {
+pub const fn ethiopic_from_fixed(date: RD) -> CopticEthiopic {
     date_from_fixed(date, EPOCH)
 }
 
@@ -1,6 +1,6 @@
 use common::divide;
 use common::RD;
-use gregorian::{fixed_from_gregorian, Gregorian};
+use arithmetical::gregorian::{fixed_from_gregorian, Gregorian};
 
 pub mod coptic;
 pub mod ethiopic;
@@ -12,11 +12,11 @@ pub struct CopticEthiopic {
     pub day: i32
 }
 
-fn is_leap_year(year: i32) -> bool {
+const fn is_leap_year(year: i32) -> bool {
     divide(year, 4).1 == 3
 }
 
-fn fixed_from_date(date: CopticEthiopic, epoch: RD) -> RD {
+const fn fixed_from_date(date: CopticEthiopic, epoch: RD) -> RD {
     epoch - 1
         + 365 * (date.year - 1)
         + divide(date.year, 4).0
@@ -24,7 +24,7 @@ fn fixed_from_date(date: CopticEthiopic, epoch: RD) -> RD {
         + date.day
 }
 
-fn date_from_fixed(date: RD, epoch: RD) -> CopticEthiopic {
+const fn date_from_fixed(date: RD, epoch: RD) -> CopticEthiopic {
     let year = divide(4 * (date - epoch) + 1463, 1461).0;
     let month = divide(date - fixed_from_date(CopticEthiopic { year, month: 1, day: 1 }, epoch), 30).0 + 1;
     let day = date + 1 - fixed_from_date(CopticEthiopic { year, month, day: 1 }, epoch);
@@ -0,0 +1,299 @@
+//! arithmetic calendars defined at runtime from a short description
+//!
+//! ```text
+//! # the coptic calendar
+//! name: coptic
+//! epoch: 103605
+//! months: 30 30 30 30 30 30 30 30 30 30 30 30 5
+//! month_names: Thoout Paope Athor Koiak Tobe Meshir Paremotep Parmoute Pashons Paone Epep Mesore Epagomene
+//! leap_years: 4 1 1
+//! leap_day: 13
+//! ```
+//!
+//! `epoch` is the R.D. date of the first day of year 1 and `months` the lengths of the months of
+//! a common year. `leap_years: cycle offset count` makes `count` years out of every `cycle` leap,
+//! year y being leap when (count * y + offset) mod cycle < count, so the islamic calendar is
+//! `30 14 11` and birashks variant `30 15 11`. a leap year either gets a day added to the month
+//! given by `leap_day` or has an extra month `leap_month: position length [name]` inserted as
+//! month number `position`
+
+use common::{divide, RD};
+use calendar::{DateError, normalize_name};
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+use alloc::vec::Vec;
+use alloc::string::{String, ToString};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CustomDate {
+    pub year: i32,
+    pub month: i32,
+    pub day: i32
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LeapRule {
+    pub cycle: i32,
+    pub offset: i32,
+    pub count: i32
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeapMonth {
+    pub position: i32,
+    pub length: i32,
+    pub name: Option<String>
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DescriptionError {
+    pub line: usize,
+    pub message: String
+}
+
+impl fmt::Display for DescriptionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for DescriptionError {}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CustomCalendar {
+    pub name: String,
+    pub epoch: RD,
+    pub months: Vec<i32>,
+    pub month_names: Vec<String>,
+    pub leap_years: Option<LeapRule>,
+    pub leap_day: Option<i32>,
+    pub leap_month: Option<LeapMonth>
+}
+
+impl CustomCalendar {
+    pub fn parse(description: &str) -> Result<CustomCalendar, DescriptionError> {
+        let mut name = String::new();
+        let mut epoch = None;
+        let mut months = Vec::new();
+        let mut month_names = Vec::new();
+        let mut leap_years = None;
+        let mut leap_day = None;
+        let mut leap_month = None;
+
+        for (i, line) in description.lines().enumerate() {
+            let error = |message: String| DescriptionError { line: i + 1, message };
+            let line = line.split('#').next().unwrap_or("").trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (key, value) = match line.find(':') {
+                Some(colon) => (line[..colon].trim(), line[colon + 1..].trim()),
+                None => return Err(error("expected 'key: value'".to_string()))
+            };
+            let numbers = || value.split_whitespace()
+                .map(|number| number.parse::<i32>().map_err(|_| error(format!("'{}' is not a number", number))))
+                .collect::<Result<Vec<_>, _>>();
+
+            match key {
+                "name" => name = value.to_string(),
+                "epoch" => epoch = Some(single(numbers()?, &error)?),
+                "months" => months = numbers()?,
+                "month_names" => month_names = value.split_whitespace().map(String::from).collect(),
+                "leap_years" => leap_years = match numbers()?.as_slice() {
+                    &[cycle, offset, count] if cycle > 0 && 0 < count && count <= cycle => Some(LeapRule { cycle, offset, count }),
+                    _ => return Err(error("expected 'cycle offset count' with 0 < count <= cycle".to_string()))
+                },
+                "leap_day" => leap_day = Some(single(numbers()?, &error)?),
+                "leap_month" => {
+                    let mut parts = value.split_whitespace();
+                    let mut number = || parts.next().and_then(|part| part.parse::<i32>().ok())
+                        .ok_or_else(|| error("expected 'position length [name]'".to_string()));
+                    let position = number()?;
+                    let length = number()?;
+                    leap_month = Some(LeapMonth { position, length, name: value.split_whitespace().nth(2).map(String::from) });
+                },
+                _ => return Err(error(format!("unknown key '{}'", key)))
+            }
+        }
+
+        let end = description.lines().count();
+        let error = |message: &str| Err(DescriptionError { line: end, message: message.to_string() });
+        let epoch = match epoch {
+            Some(epoch) => epoch,
+            None => return error("missing epoch")
+        };
+        if months.is_empty() || months.iter().any(|&length| length <= 0) {
+            return error("months must be a list of positive lengths");
+        }
+        if !month_names.is_empty() && month_names.len() != months.len() {
+            return error("month_names must name every month of a common year");
+        }
+        if (leap_day.is_some() || leap_month.is_some()) && leap_years.is_none() {
+            return error("leap_day and leap_month need leap_years");
+        }
+        if leap_day.is_some() && leap_month.is_some() {
+            return error("only one of leap_day and leap_month can be given");
+        }
+        if leap_day.is_some_and(|month| month < 1 || month > months.len() as i32) {
+            return error("leap_day must be a month of the year");
+        }
+        if leap_month.as_ref().is_some_and(|leap| leap.position < 1 || leap.position > months.len() as i32 + 1 || leap.length <= 0) {
+            return error("leap_month must be a position in the year and a positive length");
+        }
+
+        Ok(CustomCalendar { name, epoch, months, month_names, leap_years, leap_day, leap_month })
+    }
+
+    pub fn is_leap_year(&self, year: i32) -> bool {
+        match self.leap_years {
+            Some(rule) => divide(rule.count * year + rule.offset, rule.cycle).1 < rule.count,
+            None => false
+        }
+    }
+
+    /// how many days a leap year has over a common year
+    fn leap_length(&self) -> i32 {
+        match (self.leap_day, &self.leap_month) {
+            (Some(_), _) => 1,
+            (_, Some(leap)) => leap.length,
+            _ => 0
+        }
+    }
+
+    /// leap years in [1, year) which by telescoping the leap year rule is
+    /// floor((count * (year - 1) + offset) / cycle) - floor(offset / cycle)
+    fn leap_years_before(&self, year: i32) -> i64 {
+        match self.leap_years {
+            Some(rule) => {
+                let cycle = rule.cycle as i64;
+                let before = rule.count as i64 * (year as i64 - 1) + rule.offset as i64;
+                before.div_euclid(cycle) - (rule.offset as i64).div_euclid(cycle)
+            },
+            None => 0
+        }
+    }
+
+    fn common_year_length(&self) -> i32 {
+        self.months.iter().sum()
+    }
+
+    pub fn days_in_year(&self, year: i32) -> i32 {
+        self.common_year_length() + if self.is_leap_year(year) { self.leap_length() } else { 0 }
+    }
+
+    pub fn months_in_year(&self, year: i32) -> i32 {
+        self.months.len() as i32 + if self.leap_month.is_some() && self.is_leap_year(year) { 1 } else { 0 }
+    }
+
+    pub fn days_in_month(&self, year: i32, month: i32) -> Option<i32> {
+        if month < 1 || month > self.months_in_year(year) {
+            return None;
+        }
+        let leap = self.is_leap_year(year);
+        Some(match self.leap_month {
+            Some(ref leap_month) if leap && month == leap_month.position => leap_month.length,
+            Some(ref leap_month) if leap && month > leap_month.position => self.months[month as usize - 2],
+            _ => self.months[month as usize - 1] + if leap && self.leap_day == Some(month) { 1 } else { 0 }
+        })
+    }
+
+    pub fn new_year(&self, year: i32) -> RD {
+        let days = self.common_year_length() as i64 * (year as i64 - 1) + self.leap_length() as i64 * self.leap_years_before(year);
+        self.epoch + days as RD
+    }
+
+    pub fn validate(&self, date: CustomDate) -> Result<(), DateError> {
+        match self.days_in_month(date.year, date.month) {
+            None => Err(DateError::InvalidMonth),
+            Some(days) if date.day < 1 || date.day > days => Err(DateError::InvalidDay),
+            Some(_) => Ok(())
+        }
+    }
+
+    pub fn fixed_from_date(&self, date: CustomDate) -> RD {
+        self.new_year(date.year)
+            + (1..date.month).map(|month| self.days_in_month(date.year, month).unwrap_or(0)).sum::<i32>()
+            + date.day - 1
+    }
+
+    pub fn date_from_fixed(&self, date: RD) -> CustomDate {
+        // number of days in a whole cycle of leap years
+        let (cycle, count) = self.leap_years.map_or((1, 0), |rule| (rule.cycle as i64, rule.count as i64));
+        let cycle_length = self.common_year_length() as i64 * cycle + self.leap_length() as i64 * count;
+        let mut year = ((date - self.epoch) as i64 * cycle).div_euclid(cycle_length) as i32 + 1;
+        // the approximation is off by at most a year but step until it is right anyway
+        while self.new_year(year) > date {
+            year -= 1;
+        }
+        while self.new_year(year + 1) <= date {
+            year += 1;
+        }
+
+        let mut start = self.new_year(year);
+        let mut month = 1;
+        while let Some(days) = self.days_in_month(year, month) {
+            if date < start + days {
+                break;
+            }
+            start += days;
+            month += 1;
+        }
+        CustomDate { year, month, day: date - start + 1 }
+    }
+
+    pub fn month_name(&self, year: i32, month: i32) -> Option<String> {
+        match self.leap_month {
+            Some(ref leap) if self.is_leap_year(year) && month >= leap.position => if month == leap.position {
+                leap.name.clone()
+            } else {
+                self.month_names.get(month as usize - 2).cloned()
+            },
+            _ => self.month_names.get((month - 1) as usize).cloned()
+        }
+    }
+
+    /// "day month year" when the months have names and "year-month-day" when they dont
+    pub fn format(&self, date: CustomDate) -> String {
+        match self.month_name(date.year, date.month) {
+            Some(name) => format!("{} {} {}", date.day, name, date.year),
+            None => format!("{}-{}-{}", date.year, date.month, date.day)
+        }
+    }
+
+    /// reads back what `format` writes and checks the date is valid
+    pub fn parse_date(&self, text: &str) -> Result<CustomDate, DateError> {
+        let parts: Vec<&str> = text.split_whitespace().collect();
+        let number = |text: &str, field: &'static str| text.parse::<i32>().map_err(|_| DateError::InvalidField(field));
+        let date = if parts.len() == 3 {
+            let year = number(parts[2], "year")?;
+            let month = (1..=self.months_in_year(year))
+                .find(|&month| self.month_name(year, month).is_some_and(|name| normalize_name(&name) == normalize_name(parts[1])))
+                .map_or_else(|| number(parts[1], "month"), Ok)?;
+            CustomDate { year, month, day: number(parts[0], "day")? }
+        } else {
+            // the year can be negative so split off the month and day from the end
+            let mut fields = text.trim().rsplitn(3, '-');
+            let day = number(fields.next().unwrap_or(""), "day")?;
+            let month = number(fields.next().unwrap_or(""), "month")?;
+            let year = number(fields.next().unwrap_or(""), "year")?;
+            CustomDate { year, month, day }
+        };
+        self.validate(date).map(|_| date)
+    }
+}
+
+impl FromStr for CustomCalendar {
+    type Err = DescriptionError;
+
+    fn from_str(description: &str) -> Result<CustomCalendar, DescriptionError> {
+        CustomCalendar::parse(description)
+    }
+}
+
+fn single<F>(numbers: Vec<i32>, error: &F) -> Result<i32, DescriptionError> where F: Fn(String) -> DescriptionError {
+    match numbers.as_slice() {
+        &[number] => Ok(number),
+        _ => Err(error("expected a single number".to_string()))
+    }
+}
@@ -1,6 +1,6 @@
 use common::{divide, RD, cycles_of_days::{kday_after}};
-use gregorian::{fixed_from_gregorian, Gregorian};
-use julian::{fixed_from_julian, Julian};
+use arithmetical::gregorian::{fixed_from_gregorian, Gregorian};
+use arithmetical::julian::{fixed_from_julian, Julian};
 
 pub fn orthodox_easter(gregorian_year: i32) -> RD {
     let shifted_epact = divide(14 + 11 * divide(gregorian_year, 19).1, 30).1;
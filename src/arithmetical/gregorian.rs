@@ -1,4 +1,4 @@
-use common::{divide, alternate_divide, divide_f, RD};
+use common::{divide, alternate_divide, RD};
 use common::cycles_of_days::{nth_kday as base_nth_kday};
 
 // gregorian 1/1/1
@@ -12,7 +12,7 @@ pub struct Gregorian {
 }
 
 /// every 4th year besides every 100,200 and 300 year is a leap year
-pub fn is_leap_year(year: i32) -> bool {
+pub const fn is_leap_year(year: i32) -> bool {
     match divide(year, 4).1 {
         0 => match divide(year, 400).1 {
             100|200|300 => false,
@@ -23,7 +23,7 @@ pub fn is_leap_year(year: i32) -> bool {
 }
 
 /// calculates R.D. date from gregorian date
-pub fn fixed_from_gregorian(gregorian: Gregorian) -> RD {
+pub const fn fixed_from_gregorian(gregorian: Gregorian) -> RD {
     let elapsed_years = gregorian.year - 1;
     EPOCH - 1
         + 365 * elapsed_years
@@ -36,7 +36,7 @@ pub fn fixed_from_gregorian(gregorian: Gregorian) -> RD {
 }
 
 /// calculates year and ordinal day in year from R.D. date
-pub fn gregorian_year_from_fixed(date: RD) -> (i32, i32) {
+pub const fn gregorian_year_from_fixed(date: RD) -> (i32, i32) {
     let d0 = date - EPOCH;
     // days in 400 years 4
     let (n400, d1) = divide(d0, 146097);
@@ -55,7 +55,7 @@ pub fn gregorian_year_from_fixed(date: RD) -> (i32, i32) {
 }
 
 /// calculates gregorian date struct from R.D. date
-pub fn gregorian_from_fixed(date: RD) -> Gregorian {
+pub const fn gregorian_from_fixed(date: RD) -> Gregorian {
     let (year, days) = gregorian_year_from_fixed(date);
     let correction = if date < fixed_from_gregorian(Gregorian { year, month: 3, day: 1 }) {
         0
@@ -71,12 +71,12 @@ pub fn gregorian_from_fixed(date: RD) -> Gregorian {
 }
 
 /// calculates the difference in days between 2 dates
-pub fn gregorian_date_difference(date1: Gregorian, date2: Gregorian) -> i32 {
+pub const fn gregorian_date_difference(date1: Gregorian, date2: Gregorian) -> i32 {
     fixed_from_gregorian(date1) - fixed_from_gregorian(date2)
 }
 
 /// calculates the days remaining in a year
-pub fn days_remaining(date: Gregorian) -> i32 {
+pub const fn days_remaining(date: Gregorian) -> i32 {
     gregorian_date_difference(date, Gregorian {year: date.year, month: 12, day: 31})
 }
 
@@ -85,7 +85,7 @@ pub fn days_remaining(date: Gregorian) -> i32 {
 /// and shift the month number to 1 starting form march then unshift by subtracting
 /// the amount of days in those 9 months you get the same number
 /// without needing to pretend that february is 30 days long
-pub fn alt_fixed_from_gregorian(date: Gregorian) -> RD {
+pub const fn alt_fixed_from_gregorian(date: Gregorian) -> RD {
     // consider it the next year if the month is march or later (adding 9 makes it a full year later when the
     // month is 3 or more)
     let y = date.year + divide(date.month + 9, 12).0 - 1;
@@ -106,7 +106,7 @@ pub fn alt_fixed_from_gregorian(date: Gregorian) -> RD {
 }
 
 /// shifted month gregorian date from R.D. date
-pub fn alt_gregorian_from_fixed(date: RD) -> Gregorian {
+pub const fn alt_gregorian_from_fixed(date: RD) -> Gregorian {
     let (y, days) = alt_gregorian_year_from_fixed(EPOCH - 1 + date + 306);
     let month = alternate_divide(divide(5 * days - 1 + 155, 153).0 + 2, 12).1;
     let year = y - divide(month + 9, 12).0;
@@ -115,9 +115,10 @@ pub fn alt_gregorian_from_fixed(date: RD) -> Gregorian {
 }
 
 /// shifted month gregorian year from R.D. date
-pub fn alt_gregorian_year_from_fixed(date: RD) -> (i32, i32) {
+pub const fn alt_gregorian_year_from_fixed(date: RD) -> (i32, i32) {
     // get approximate year by adding 2 to the date and dividing by the average amount of days in a year
-    let approx = divide_f((date - EPOCH + 2) as f64, 365.2425).0 as i32;
+    // 365.2425 days as 3652425 / 10000 to stay in integers
+    let approx = ((date - EPOCH + 2) as i64 * 10000).div_euclid(3652425) as i32;
     let start = EPOCH
         + 365 * approx
         + divide(approx, 4).0
@@ -132,38 +133,43 @@ pub fn alt_gregorian_year_from_fixed(date: RD) -> (i32, i32) {
     )
 }
 
-pub fn independence_day(year: i32) -> RD {
+pub const fn independence_day(year: i32) -> RD {
     fixed_from_gregorian(Gregorian { year, month: 7, day: 4 })
 }
 
-pub fn nth_kday(n: i32, k: i32, date: Gregorian) -> RD {
+pub const fn nth_kday(n: i32, k: i32, date: Gregorian) -> RD {
     base_nth_kday(fixed_from_gregorian(date), n, k)
 }
 
-pub fn first_kday(k: i32, date: Gregorian) -> RD {
+pub const fn first_kday(k: i32, date: Gregorian) -> RD {
     nth_kday(1, k, date)
 }
 
-pub fn last_kday(k: i32, date: Gregorian) -> RD {
+pub const fn last_kday(k: i32, date: Gregorian) -> RD {
     nth_kday(-1, k, date)
 }
 
-pub fn labor_day(year: i32) -> RD {
+pub const fn labor_day(year: i32) -> RD {
     first_kday(1, Gregorian { year, month: 9, day: 1 })
 }
 
-pub fn memorial_day(year: i32) -> RD {
+pub const fn memorial_day(year: i32) -> RD {
     last_kday(1, Gregorian { year, month: 5, day: 31 })
 }
 
-pub fn election_day(year: i32) -> RD {
+pub const fn election_day(year: i32) -> RD {
     first_kday(2, Gregorian { year, month: 11, day: 2 })
 }
 
-pub fn daylight_saving_start(year: i32) -> RD {
+/// us thanksgiving is the 4th thursday of november
+pub const fn thanksgiving(year: i32) -> RD {
+    nth_kday(4, 4, Gregorian { year, month: 11, day: 1 })
+}
+
+pub const fn daylight_saving_start(year: i32) -> RD {
     first_kday(0, Gregorian { year, month: 4, day: 1 })
 }
 
-pub fn daylight_saving_end(year: i32) -> RD {
+pub const fn daylight_saving_end(year: i32) -> RD {
     last_kday(0, Gregorian { year, month: 10, day: 31 })
 }
@@ -1,7 +1,10 @@
-use common::{divide, divide_f, RD, cycles_of_days::{day_of_week_from_fixed, kday_before}};
-use gregorian::{fixed_from_gregorian, gregorian_year_from_fixed, Gregorian};
-use coptic_ethiopic::coptic::{coptic_in_gregorian, coptic_from_fixed};
-use std::iter::Iterator;
+use common::{divide, divide_f, Moment, RD, cycles_of_days::{day_of_week_from_fixed, kday_before}};
+use arithmetical::gregorian::gregorian_year_from_fixed;
+#[cfg(feature = "alloc")]
+use arithmetical::gregorian::{fixed_from_gregorian, Gregorian};
+use arithmetical::coptic_ethiopic::coptic::{coptic_in_gregorian, coptic_from_fixed};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 pub const EPOCH: i32 = -1373427;
 
@@ -32,8 +35,10 @@ pub struct Molad {
     pub chalakim: i32
 }
 
+/// the mean conjunction of a month in jerusalem mean time, the hour counting from midnight
 pub fn molad(month: i32, year: i32) -> Molad {
-    // 7 is tishrei
+    // 7 is tishrei, the months before it end the year so they are counted from the next tishrei
+    let year = if month < 7 { year + 1 } else { year };
     let months_elapsed = month - 7 + divide(235 * year - 234, 19).0;
     let half_days_elapsed = divide(months_elapsed, 2);
     let chalakim_elapsed = divide(months_elapsed * 793 - 876 + 12960 * half_days_elapsed.1, 25920);
@@ -44,6 +49,11 @@ pub fn molad(month: i32, year: i32) -> Molad {
     Molad { date: EPOCH + elapsed_days, hour: hours.0, minute: minutes.0, chalakim: minutes.1 }
 }
 
+/// the molad as a moment of jerusalem mean time, 25920 chalakim to a day
+pub fn moment_from_molad(molad: Molad) -> Moment {
+    molad.date as Moment + (molad.hour * 1080 + molad.minute * 18 + molad.chalakim) as Moment / 25920.0
+}
+
 pub fn hebrew_calendar_elapsed_days(year: i32) -> i32 {
     let molad = molad(7, year);
     let day = if molad.hour >= 12 {
@@ -80,7 +90,7 @@ pub fn hebrew_new_year(year: i32) -> RD {
 pub fn last_day_of_hebrew_month(month: i32, year: i32) -> RD {
     match month {
         2|4|6|10|13 => 29,
-        12 if is_hebrew_leap_year(year) => 29,
+        12 if !is_hebrew_leap_year(year) => 29,
         8 if !is_long_marcheshvan(year) => 29,
         9 if is_short_kislev(year) => 29,
         _ => 30
@@ -106,41 +116,186 @@ pub fn days_in_hebrew_year(year: i32) -> i32 {
 }
 
 pub fn fixed_from_hebrew(date: Hebrew) -> RD {
-    hebrew_new_year(date.year)
-        + date.day - 1
-        + if date.month < 7 {
-            (7..last_month_of_hebrew_year(date.year) + 1).map(|month| last_day_of_hebrew_month(month, date.year)).sum::<i32>()
-                + (1..date.month).map(|month| last_day_of_hebrew_month(month, date.year)).sum::<i32>()
-        } else {
-            (7..date.month).map(|month| last_day_of_hebrew_month(month, date.year)).sum::<i32>()
-        }
+    HebrewYear::new(date.year).fixed_from_hebrew(date.month, date.day)
 }
 
 pub fn hebrew_from_fixed(date: RD) -> Hebrew {
     // 35975351.0/98496.0 is the average length of a hebrew year
     let approx_year = divide_f(date as f64 - EPOCH as f64, 35975351.0/98496.0).0 as i32 + 1;
+    let year = HebrewYear::new(approx_year);
 
-    // because years have irregular size the min year can be up to 2 off
-    let year = (approx_year - 1..=approx_year + 1)
-        .filter(|&year| hebrew_new_year(year) <= date)
-        .last()
-        .expect("Should always have a value")
-    ;
-
-    let start_month = if date < fixed_from_hebrew(Hebrew { year, month: 1, day: 1 }) {
-        7
+    // because years have irregular size the approximate year can be a year off either way
+    if date < year.new_year {
+        HebrewYear::new(approx_year - 1)
+    } else if date >= year.new_year + year.days {
+        HebrewYear::new(approx_year + 1)
     } else {
-        1
-    };
+        year
+    }.hebrew_from_fixed(date)
+}
 
-    let month = (start_month..)
-        .filter(|&month| fixed_from_hebrew(Hebrew { year, month, day: last_day_of_hebrew_month(month, year) }) >= date )
-        .next()
-        .expect("Should always have a value")
-    ;
+/// the length of a year, deficient years have a short kislev and complete years a long marheshvan
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum YearKind {
+    Deficient,
+    Regular,
+    Complete
+}
 
-    let day = date - fixed_from_hebrew(Hebrew { year, month, day: 1}) + 1;
-    Hebrew { year, month, day }
+/// the weekday of rosh hashana, the kind of year and the weekday of the first day of passover
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Keviah {
+    pub new_year_weekday: i32,
+    pub kind: YearKind,
+    pub passover_weekday: i32
+}
+
+/// everything needed to convert dates in a hebrew year, worked out from
+/// four molad computations instead of the dozens the month functions need
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HebrewYear {
+    pub year: i32,
+    pub new_year: RD,
+    pub days: i32,
+    /// days from 1 tishri to the first of each month, indexed by month - 1
+    pub month_starts: [i32; 13],
+    /// indexed by month - 1 and 0 for adar ii in a common year
+    pub month_lengths: [i32; 13],
+    pub keviah: Keviah
+}
+
+impl HebrewYear {
+    pub fn new(year: i32) -> HebrewYear {
+        let elapsed = [
+            hebrew_calendar_elapsed_days(year - 1),
+            hebrew_calendar_elapsed_days(year),
+            hebrew_calendar_elapsed_days(year + 1),
+            hebrew_calendar_elapsed_days(year + 2)
+        ];
+        let delay = |ny0: i32, ny1: i32, ny2: i32| if ny2 - ny1 == 356 {
+            2
+        } else if ny1 - ny0 == 382 {
+            1
+        } else {
+            0
+        };
+        let new_year = EPOCH + elapsed[1] + delay(elapsed[0], elapsed[1], elapsed[2]);
+        let days = EPOCH + elapsed[2] + delay(elapsed[1], elapsed[2], elapsed[3]) - new_year;
+        let kind = match days {
+            353|383 => YearKind::Deficient,
+            355|385 => YearKind::Complete,
+            _ => YearKind::Regular
+        };
+        let leap = is_hebrew_leap_year(year);
+
+        let mut month_lengths = [0; 13];
+        for (i, length) in month_lengths.iter_mut().enumerate() {
+            let month = i as i32 + 1;
+            *length = match month {
+                13 if !leap => 0,
+                2|4|6|10|13 => 29,
+                12 if !leap => 29,
+                8 if kind != YearKind::Complete => 29,
+                9 if kind == YearKind::Deficient => 29,
+                _ => 30
+            };
+        }
+
+        // the year starts with tishri and nisan comes after adar
+        let mut month_starts = [0; 13];
+        let mut start = 0;
+        for month in (7..=13).chain(1..7) {
+            month_starts[month - 1] = start;
+            start += month_lengths[month - 1];
+        }
+
+        let passover = new_year + month_starts[0] + 14;
+        HebrewYear {
+            year,
+            new_year,
+            days,
+            month_starts,
+            month_lengths,
+            keviah: Keviah { new_year_weekday: day_of_week_from_fixed(new_year), kind, passover_weekday: day_of_week_from_fixed(passover) }
+        }
+    }
+
+    pub fn is_leap_year(&self) -> bool {
+        self.month_lengths[12] != 0
+    }
+
+    pub fn last_day_of_month(&self, month: i32) -> i32 {
+        self.month_lengths[month as usize - 1]
+    }
+
+    /// like `fixed_from_hebrew` days past the end of the month run on into the next month
+    /// and months outside the year are counted the way summing the month lengths would,
+    /// before 1 as nisan and after 13 as 30 day months following adar ii
+    pub fn fixed_from_hebrew(&self, month: i32, day: i32) -> RD {
+        let start = match month {
+            1..=13 => self.month_starts[month as usize - 1],
+            _ if month < 1 => self.month_starts[0],
+            _ => self.month_starts[0] + if self.is_leap_year() { 0 } else { 29 } + (month - 14) * 30
+        };
+        self.new_year + start + day - 1
+    }
+
+    /// the date must be in this year
+    pub fn hebrew_from_fixed(&self, date: RD) -> Hebrew {
+        let offset = date - self.new_year;
+        let month = (1..=13)
+            .filter(|&month| self.month_lengths[month as usize - 1] != 0)
+            .find(|&month| {
+                let start = self.month_starts[month as usize - 1];
+                start <= offset && offset < start + self.month_lengths[month as usize - 1]
+            })
+            .expect("Date should be in the year");
+        Hebrew { year: self.year, month, day: offset - self.month_starts[month as usize - 1] + 1 }
+    }
+}
+
+/// a bounded cache of hebrew years for converting many dates
+/// when it is full the year that has been in it longest is dropped
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug)]
+pub struct HebrewYearCache {
+    capacity: usize,
+    years: Vec<HebrewYear>
+}
+
+#[cfg(feature = "alloc")]
+impl HebrewYearCache {
+    pub fn new(capacity: usize) -> HebrewYearCache {
+        HebrewYearCache { capacity: capacity.max(1), years: Vec::new() }
+    }
+
+    pub fn year(&mut self, year: i32) -> HebrewYear {
+        if let Some(&cached) = self.years.iter().rev().find(|cached| cached.year == year) {
+            return cached;
+        }
+        if self.years.len() >= self.capacity {
+            self.years.remove(0);
+        }
+        let computed = HebrewYear::new(year);
+        self.years.push(computed);
+        computed
+    }
+
+    pub fn fixed_from_hebrew(&mut self, date: Hebrew) -> RD {
+        self.year(date.year).fixed_from_hebrew(date.month, date.day)
+    }
+
+    pub fn hebrew_from_fixed(&mut self, date: RD) -> Hebrew {
+        let approx_year = divide_f(date as f64 - EPOCH as f64, 35975351.0/98496.0).0 as i32 + 1;
+        let year = self.year(approx_year);
+        if date < year.new_year {
+            self.year(approx_year - 1)
+        } else if date >= year.new_year + year.days {
+            self.year(approx_year + 1)
+        } else {
+            year
+        }.hebrew_from_fixed(date)
+    }
 }
 
 pub fn yom_kippur(gregorian_year: i32) -> RD {
@@ -153,6 +308,12 @@ pub fn passover(gregorian_year: i32) -> RD {
     fixed_from_hebrew(Hebrew { year, month: 1, day: 15 })
 }
 
+/// first day of hanukkah (25 kislev), kislev always falls in november or december
+pub fn hanukkah(gregorian_year: i32) -> RD {
+    let year = gregorian_year - gregorian_year_from_fixed(EPOCH).0 + 1;
+    fixed_from_hebrew(Hebrew { year, month: 9, day: 25 })
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Omer {
     pub week: i32,
@@ -191,6 +352,7 @@ pub fn tisha_bav(gregorian_year: i32) -> RD {
     }
 }
 
+#[cfg(feature = "alloc")]
 pub fn hebrew_in_gregorian(month: i32, day: i32, gregorian_year: i32) -> Vec<RD> {
     let jan1 = fixed_from_gregorian(Gregorian { year: gregorian_year, month: 1, day: 1 });
     let dec31 = fixed_from_gregorian(Gregorian { year: gregorian_year, month: 12, day: 31 });
@@ -201,6 +363,7 @@ pub fn hebrew_in_gregorian(month: i32, day: i32, gregorian_year: i32) -> Vec<RD>
     ].iter().cloned().map(fixed_from_hebrew).filter(|&date| jan1 <= date && date <= dec31).collect()
 }
 
+#[cfg(feature = "alloc")]
 pub fn tzom_tevet(gregorian_year: i32) -> Vec<RD> {
     hebrew_in_gregorian(10, 10, gregorian_year)
 }
@@ -246,6 +409,7 @@ pub fn hebrew_birthday(birth_date: Hebrew, year: i32) -> RD {
     }
 }
 
+#[cfg(feature = "alloc")]
 pub fn hebrew_birthday_in_gregorian(birth_date: Hebrew, gregorian_year: i32) -> Vec<RD> {
     let jan1 = fixed_from_gregorian(Gregorian { year: gregorian_year, month: 1, day: 1 });
     let dec31 = fixed_from_gregorian(Gregorian { year: gregorian_year, month: 12, day: 31 });
@@ -272,6 +436,7 @@ pub fn yahrtzeit(death_date: Hebrew, year: i32) -> RD {
     fixed_from_hebrew(Hebrew { year, month, day })
 }
 
+#[cfg(feature = "alloc")]
 pub fn yahrtzeit_in_gregorian(death_date: Hebrew, gregorian_year: i32) -> Vec<RD> {
     let jan1 = fixed_from_gregorian(Gregorian { year: gregorian_year, month: 1, day: 1 });
     let dec31 = fixed_from_gregorian(Gregorian { year: gregorian_year, month: 12, day: 31 });
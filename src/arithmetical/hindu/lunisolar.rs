@@ -1,4 +1,4 @@
-use common::{divide, divide_f, RD};
+use common::{divide, divide_f, floor, ceil, RD};
 use super::{EPOCH, solar::{ARYA_SOLAR_YEAR, ARYA_SOLAR_MONTH, hindu_day_count}};
 
 // seems becsuse of the ratios we have a choice between using f64 or if we want to leave
@@ -23,18 +23,18 @@ pub fn hindu_lunisolar_from_fixed(date: RD) -> HinduLunisolar {
     let sun = hindu_day_count(date) as f64 + 1.0/4.0;
     let new_moon = sun - divide_f(sun, ARYA_LUNAR_MONTH).1;
     let leap_month = ARYA_SOLAR_MONTH - ARYA_LUNAR_MONTH >= divide_f(new_moon, ARYA_SOLAR_MONTH).1 && divide_f(new_moon, ARYA_SOLAR_MONTH).1 > 0.0;
-    let month = divide((new_moon/ARYA_SOLAR_MONTH).ceil() as i32, 12).1 + 1;
+    let month = divide(ceil(new_moon/ARYA_SOLAR_MONTH) as i32, 12).1 + 1;
     let day = divide(divide_f(sun, ARYA_LUNAR_DAY).0 as i32, 30).1 + 1;
-    let year = ((new_moon + ARYA_SOLAR_MONTH)/ARYA_SOLAR_YEAR).ceil() as i32 - 1;
+    let year = ceil((new_moon + ARYA_SOLAR_MONTH)/ARYA_SOLAR_YEAR) as i32 - 1;
     HinduLunisolar { year, month, leap_month, day }
 }
 
 pub fn fixed_from_hindu_lunisolar(date: HinduLunisolar) -> RD {
     let mina = (12 * date.year - 1) as f64 * ARYA_SOLAR_MONTH;
     let lunar_new_year = ARYA_LUNAR_MONTH * (divide_f(mina, ARYA_LUNAR_MONTH).0 + 1.0);
-    (EPOCH as f64 + lunar_new_year + ARYA_LUNAR_MONTH * if !date.leap_month && ((lunar_new_year - mina)/(ARYA_SOLAR_MONTH - ARYA_LUNAR_MONTH)).ceil() as i32 <= date.month {
+    floor(EPOCH as f64 + lunar_new_year + ARYA_LUNAR_MONTH * if !date.leap_month && ceil((lunar_new_year - mina)/(ARYA_SOLAR_MONTH - ARYA_LUNAR_MONTH)) as i32 <= date.month {
         date.month as f64
     } else {
         date.month as f64 - 1.0
-    } + (date.day as f64 - 1.0) * ARYA_LUNAR_DAY + 0.75).floor() as RD
+    } + (date.day as f64 - 1.0) * ARYA_LUNAR_DAY + 0.75) as RD
 }
@@ -1,4 +1,4 @@
-use common::{divide, divide_f, RD};
+use common::{divide, divide_f, ceil, RD};
 use super::EPOCH;
 
 // seems becsuse of the ratios we have a choice between using f64 or if we want to leave
@@ -24,8 +24,9 @@ pub fn jovian_year(date: RD) -> i32 {
 }
 
 pub fn fixed_from_hindu_solar(date: HinduSolar) -> RD {
-    // we subtract 1/4 since
-    divide_f(EPOCH as f64 + date.year as f64 * ARYA_SOLAR_YEAR + (date.month - 1) as f64 * ARYA_SOLAR_MONTH + date.day as f64 - 1.0/4.0, 1.0).0 as RD
+    // we subtract 1/4 since the day starts at sunrise, the ceiling of one less rather than the floor
+    // gives the right day when the sum lands exactly on a whole number
+    ceil(EPOCH as f64 + date.year as f64 * ARYA_SOLAR_YEAR + (date.month - 1) as f64 * ARYA_SOLAR_MONTH + date.day as f64 - 1.0/4.0 - 1.0) as RD
 }
 
 pub fn hindu_solar_from_fixed(date: RD) -> HinduSolar {
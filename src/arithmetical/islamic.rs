@@ -1,5 +1,8 @@
 use common::{divide, RD};
-use gregorian::{fixed_from_gregorian, Gregorian};
+#[cfg(feature = "alloc")]
+use arithmetical::gregorian::{fixed_from_gregorian, Gregorian};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 pub const EPOCH: i32 = 227015;
 
@@ -11,16 +14,16 @@ pub struct Islamic {
     pub day: i32
 }
 
-pub fn is_islamic_leap_year(year: i32) -> bool {
+pub const fn is_islamic_leap_year(year: i32) -> bool {
     divide(14 + 11 * year, 30).1 < 11
 }
 
 /// birashk has the 15th year instead of the 16th year of a 30 year cycle be leap
-pub fn is_islamic_birashk_leap_year(year: i32) -> bool {
+pub const fn is_islamic_birashk_leap_year(year: i32) -> bool {
     divide(15 + 11 * year, 30).1 < 11
 }
 
-pub fn fixed_from_islamic(date: Islamic) -> RD {
+pub const fn fixed_from_islamic(date: Islamic) -> RD {
     date.day
         + 29 * (date.month - 1)
         + divide(6 * date.month - 1, 11).0
@@ -29,7 +32,7 @@ pub fn fixed_from_islamic(date: Islamic) -> RD {
         + EPOCH - 1
 }
 
-pub fn fixed_from_islamic_birashk(date: Islamic) -> RD {
+pub const fn fixed_from_islamic_birashk(date: Islamic) -> RD {
     date.day
         + 29 * (date.month - 1)
         + divide(6 * date.month - 1, 11).0
@@ -38,7 +41,7 @@ pub fn fixed_from_islamic_birashk(date: Islamic) -> RD {
         + EPOCH - 1
 }
 
-pub fn islamic_from_fixed(date: RD) -> Islamic {
+pub const fn islamic_from_fixed(date: RD) -> Islamic {
     let year = divide(30 * (date - EPOCH) + 10646, 10631).0;
     let prior_days = date - fixed_from_islamic(Islamic { year, month: 1, day: 1 });
     let month = divide(11 * prior_days + 330, 325).0;
@@ -46,14 +49,15 @@ pub fn islamic_from_fixed(date: RD) -> Islamic {
     Islamic { year, month, day }
 }
 
-pub fn islamic_birashk_from_fixed(date: RD) -> Islamic {
+pub const fn islamic_birashk_from_fixed(date: RD) -> Islamic {
     let year = divide(30 * (date - EPOCH) + 10645, 10631).0;
-    let prior_days = date - fixed_from_islamic(Islamic { year, month: 1, day: 1 });
+    let prior_days = date - fixed_from_islamic_birashk(Islamic { year, month: 1, day: 1 });
     let month = divide(11 * prior_days + 330, 325).0;
-    let day = date - fixed_from_islamic(Islamic { year, month, day: 1 });
+    let day = date - fixed_from_islamic_birashk(Islamic { year, month, day: 1 }) + 1;
     Islamic { year, month, day }
 }
 
+#[cfg(feature = "alloc")]
 pub fn islamic_in_gregorian(month: i32, day: i32, gregorian_year: i32) -> Vec<RD> {
     let jan1 = fixed_from_gregorian(Gregorian { year: gregorian_year, month: 1, day: 1 });
     let dec31 = fixed_from_gregorian(Gregorian { year: gregorian_year, month: 12, day: 31 });
@@ -1,5 +1,6 @@
 use common::{RD, divide, alternate_divide};
-use gregorian::{gregorian_year_from_fixed, nth_kday, Gregorian};
+use common::cycles_of_days::day_of_week_from_fixed;
+use arithmetical::gregorian::{fixed_from_gregorian, gregorian_year_from_fixed, nth_kday, Gregorian};
 
 pub const EPOCH: i32 = 1;
 
@@ -10,11 +11,18 @@ pub struct Iso {
     pub day: i32
 }
 
-pub fn fixed_from_iso(date: Iso) -> RD {
+/// a year has 53 weeks when it starts or ends on a thursday
+pub const fn is_iso_long_year(year: i32) -> bool {
+    let jan1 = day_of_week_from_fixed(fixed_from_gregorian(Gregorian { year, month: 1, day: 1 }));
+    let dec31 = day_of_week_from_fixed(fixed_from_gregorian(Gregorian { year, month: 12, day: 31 }));
+    jan1 == 4 || dec31 == 4
+}
+
+pub const fn fixed_from_iso(date: Iso) -> RD {
     nth_kday(date.week, 0, Gregorian { year: date.year - 1, month: 12, day: 28 }) + date.day
 }
 
-pub fn iso_from_fixed(date: RD) -> Iso {
+pub const fn iso_from_fixed(date: RD) -> Iso {
     let approx = gregorian_year_from_fixed(date - 3).0;
 
     let year = if date >= fixed_from_iso(Iso { year: approx + 1, week: 1, day: 1 }) {
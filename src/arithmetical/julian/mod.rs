@@ -1,7 +1,7 @@
 pub mod roman;
 
 use common::{divide, RD};
-use gregorian::{fixed_from_gregorian, Gregorian};
+use arithmetical::gregorian::{fixed_from_gregorian, Gregorian};
 
 // gregorian 12/30/0
 pub const EPOCH: RD = -1;
@@ -13,7 +13,7 @@ pub struct Julian {
     pub day: i32
 }
 
-pub fn is_julian_leap_year(year: i32) -> bool {
+pub const fn is_julian_leap_year(year: i32) -> bool {
     divide(year, 4).1 == if year > 0 {
         0
     } else {
@@ -21,7 +21,7 @@ pub fn is_julian_leap_year(year: i32) -> bool {
     }
 }
 
-pub fn fixed_from_julian(date: Julian) -> RD {
+pub const fn fixed_from_julian(date: Julian) -> RD {
     let y = if date.year < 0 {
         date.year + 1
     } else {
@@ -42,7 +42,7 @@ pub fn fixed_from_julian(date: Julian) -> RD {
         + date.day
 }
 
-pub fn julian_from_fixed(date: RD) -> Julian {
+pub const fn julian_from_fixed(date: RD) -> Julian {
     let approx = divide(4 * (date - EPOCH) + 1464, 1461).0;
     let year = if approx <= 0 { approx - 1 } else { approx };
     let prior_days = date - fixed_from_julian(Julian { year, month: 1, day: 1 });
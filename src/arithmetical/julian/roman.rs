@@ -36,7 +36,8 @@ pub fn fixed_from_roman(date: Roman) -> i32 {
         Event::Ides => fixed_from_julian(Julian { year: date.year, month: date.month, day: ides_of_month(date.month) })
     };
     start - date.count
-    + if is_julian_leap_year(date.year) && date.month == 3 && date.event == Event::Kalends {
+    // the leap day comes between the 6th and 7th days before the kalends of march
+    + if is_julian_leap_year(date.year) && date.month == 3 && date.event == Event::Kalends && (6..=16).contains(&date.count) {
         0
     } else {
         1
@@ -47,7 +48,8 @@ pub fn fixed_from_roman(date: Roman) -> i32 {
 pub fn roman_from_fixed(date: RD) -> Roman {
     let Julian { year, month, day } = julian_from_fixed(date);
     let next_month = alternate_divide(month + 1, 12).1;
-    let adjusted_year = if next_month == 1 { year + 1 } else { year };
+    // there is no year 0 so 1 b.c.e. is followed by 1 c.e.
+    let adjusted_year = if next_month != 1 { year } else if year != -1 { year + 1 } else { 1 };
     if day == 1 {
         Roman { year, month, event: Event::Kalends, count: 1, leap: false }
     } else if day <= nones_of_month(month) {
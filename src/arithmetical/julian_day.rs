@@ -6,26 +6,29 @@ pub const MODIFIED_EPOCH: RD = 678576;
 type JulianDay = f64;
 type ModifiedJulianDay = i32;
 
-pub fn moment_from_jd(day: JulianDay) -> Moment {
+pub const fn moment_from_jd(day: JulianDay) -> Moment {
     day + EPOCH
 }
 
-pub fn jd_from_moment(moment: Moment) -> JulianDay {
+pub const fn jd_from_moment(moment: Moment) -> JulianDay {
     moment - EPOCH
 }
 
-pub fn fixed_from_jd(day: JulianDay) -> RD {
-    moment_from_jd(day).floor() as RD
+pub const fn fixed_from_jd(day: JulianDay) -> RD {
+    let moment = moment_from_jd(day);
+    // casting truncates towards 0 so step down for negative moments with a fraction
+    let truncated = moment as RD;
+    if (truncated as Moment) > moment { truncated - 1 } else { truncated }
 }
 
-pub fn jd_from_fixed(date: RD) -> JulianDay {
+pub const fn jd_from_fixed(date: RD) -> JulianDay {
     jd_from_moment(date as JulianDay)
 }
 
-pub fn fixed_from_mjd(day: ModifiedJulianDay) -> RD {
+pub const fn fixed_from_mjd(day: ModifiedJulianDay) -> RD {
     day + MODIFIED_EPOCH
 }
 
-pub fn mjd_from_fixed(date: RD) -> ModifiedJulianDay {
+pub const fn mjd_from_fixed(date: RD) -> ModifiedJulianDay {
     date - MODIFIED_EPOCH
 }
\ No newline at end of file
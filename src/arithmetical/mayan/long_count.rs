@@ -10,11 +10,11 @@ pub struct LongCount {
     pub kin: i32
 }
 
-pub fn fixed_from_long_count(date: LongCount) -> RD {
+pub const fn fixed_from_long_count(date: LongCount) -> RD {
     EPOCH + date.baktun * 144000 + date.katun * 7200 + date.tun * 360 + date.uinal * 20 + date.kin
 }
 
-pub fn long_count_from_fixed(date: RD) -> LongCount {
+pub const fn long_count_from_fixed(date: RD) -> LongCount {
     let long_count = date - EPOCH;
     let (baktun, day_of_baktun) = divide(long_count, 144000);
     let (katun, day_of_katun) = divide(day_of_baktun, 7200);
@@ -5,9 +5,9 @@ pub mod tzolkin;
 use common::{RD, divide};
 
 // julian day 9/6/-3114 julian
-pub static EPOCH: RD = -1137142;
+pub const EPOCH: RD = -1137142;
 
-pub fn mayan_calendar_round_on_or_before(haab: haab::Haab, tzolkin: tzolkin::Tzolkin, date: RD) -> Option<RD> {
+pub const fn mayan_calendar_round_on_or_before(haab: haab::Haab, tzolkin: tzolkin::Tzolkin, date: RD) -> Option<RD> {
     let haab_count = haab::mayan_haab_ordinal(haab) + haab::EPOCH;
     let tzolkin_count = tzolkin::mayan_tzolkin_ordinal(tzolkin) + tzolkin::EPOCH;
     let diff = tzolkin_count - haab_count;
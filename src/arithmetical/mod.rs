@@ -8,4 +8,6 @@ pub mod hebrew;
 pub mod ecclesiastical;
 pub mod hindu;
 pub mod mayan;
-pub mod balinese_powukon;
\ No newline at end of file
+pub mod balinese_powukon;
+#[cfg(feature = "alloc")]
+pub mod custom;
\ No newline at end of file
@@ -0,0 +1,198 @@
+//! where the moon is and when it is new, full or at a quarter
+//!
+//! the longitude is the main part of the elp-2000/82 theory as meeus gives it and the new moons
+//! are his series for the true conjunctions, both within a few minutes for thousands of years
+
+use common::{divide_f, round, Moment};
+use arithmetical::hebrew::{molad, moment_from_molad};
+use super::solar::{nutation, solar_longitude};
+use super::time::{julian_centuries, universal_from_dynamical, universal_from_local};
+use super::{degrees, poly, sin_degrees, JERUSALEM, J2000, MEAN_SYNODIC_MONTH};
+
+/// the lunar phases of the new moon, the quarters and the full moon
+pub const NEW: f64 = 0.0;
+pub const FIRST_QUARTER: f64 = 90.0;
+pub const FULL: f64 = 180.0;
+pub const LAST_QUARTER: f64 = 270.0;
+
+/// new moons counted from the one of january 11th 1 so that the new moon near J2000 is 24724
+const NEW_MOONS_TO_J2000: i32 = 24724;
+
+/// how close in days the moments found by bisection are, about a second
+const PRECISION: f64 = 0.00001;
+
+/// the multiples of the elongation, the solar anomaly, the lunar anomaly and the argument of
+/// latitude and the coefficient in millionths of a degree of the periodic terms of the longitude
+const LONGITUDE_TERMS: [(f64, f64, f64, f64, f64); 59] = [
+    (0.0, 0.0, 1.0, 0.0, 6288774.0), (2.0, 0.0, -1.0, 0.0, 1274027.0), (2.0, 0.0, 0.0, 0.0, 658314.0),
+    (0.0, 0.0, 2.0, 0.0, 213618.0), (0.0, 1.0, 0.0, 0.0, -185116.0), (0.0, 0.0, 0.0, 2.0, -114332.0),
+    (2.0, 0.0, -2.0, 0.0, 58793.0), (2.0, -1.0, -1.0, 0.0, 57066.0), (2.0, 0.0, 1.0, 0.0, 53322.0),
+    (2.0, -1.0, 0.0, 0.0, 45758.0), (0.0, 1.0, -1.0, 0.0, -40923.0), (1.0, 0.0, 0.0, 0.0, -34720.0),
+    (0.0, 1.0, 1.0, 0.0, -30383.0), (2.0, 0.0, 0.0, -2.0, 15327.0), (0.0, 0.0, 1.0, 2.0, -12528.0),
+    (0.0, 0.0, 1.0, -2.0, 10980.0), (4.0, 0.0, -1.0, 0.0, 10675.0), (0.0, 0.0, 3.0, 0.0, 10034.0),
+    (4.0, 0.0, -2.0, 0.0, 8548.0), (2.0, 1.0, -1.0, 0.0, -7888.0), (2.0, 1.0, 0.0, 0.0, -6766.0),
+    (1.0, 0.0, -1.0, 0.0, -5163.0), (1.0, 1.0, 0.0, 0.0, 4987.0), (2.0, -1.0, 1.0, 0.0, 4036.0),
+    (2.0, 0.0, 2.0, 0.0, 3994.0), (4.0, 0.0, 0.0, 0.0, 3861.0), (2.0, 0.0, -3.0, 0.0, 3665.0),
+    (0.0, 1.0, -2.0, 0.0, -2689.0), (2.0, 0.0, -1.0, 2.0, -2602.0), (2.0, -1.0, -2.0, 0.0, 2390.0),
+    (1.0, 0.0, 1.0, 0.0, -2348.0), (2.0, -2.0, 0.0, 0.0, 2236.0), (0.0, 1.0, 2.0, 0.0, -2120.0),
+    (0.0, 2.0, 0.0, 0.0, -2069.0), (2.0, -2.0, -1.0, 0.0, 2048.0), (2.0, 0.0, 1.0, -2.0, -1773.0),
+    (2.0, 0.0, 0.0, 2.0, -1595.0), (4.0, -1.0, -1.0, 0.0, 1215.0), (0.0, 0.0, 2.0, 2.0, -1110.0),
+    (3.0, 0.0, -1.0, 0.0, -892.0), (2.0, 1.0, 1.0, 0.0, -810.0), (4.0, -1.0, -2.0, 0.0, 759.0),
+    (0.0, 2.0, -1.0, 0.0, -713.0), (2.0, 2.0, -1.0, 0.0, -700.0), (2.0, 1.0, -2.0, 0.0, 691.0),
+    (2.0, -1.0, 0.0, -2.0, 596.0), (4.0, 0.0, 1.0, 0.0, 549.0), (0.0, 0.0, 4.0, 0.0, 537.0),
+    (4.0, -1.0, 0.0, 0.0, 520.0), (1.0, 0.0, -2.0, 0.0, -487.0), (2.0, 1.0, 0.0, -2.0, -399.0),
+    (0.0, 0.0, 2.0, -2.0, -381.0), (1.0, 1.0, 1.0, 0.0, 351.0), (3.0, 0.0, -2.0, 0.0, -340.0),
+    (4.0, 0.0, -3.0, 0.0, 330.0), (2.0, -1.0, 2.0, 0.0, 327.0), (0.0, 2.0, 1.0, 0.0, -323.0),
+    (1.0, 1.0, -1.0, 0.0, 299.0), (2.0, 0.0, 3.0, 0.0, 294.0)
+];
+
+/// the power of the eccentricity factor, the multiples of the solar anomaly, the lunar anomaly
+/// and the argument of latitude and the coefficient in days of the periodic terms of a new moon
+const NEW_MOON_TERMS: [(i32, f64, f64, f64, f64); 24] = [
+    (0, 0.0, 1.0, 0.0, -0.40720), (1, 1.0, 0.0, 0.0, 0.17241), (0, 0.0, 2.0, 0.0, 0.01608),
+    (0, 0.0, 0.0, 2.0, 0.01039), (1, -1.0, 1.0, 0.0, 0.00739), (1, 1.0, 1.0, 0.0, -0.00514),
+    (2, 2.0, 0.0, 0.0, 0.00208), (0, 0.0, 1.0, -2.0, -0.00111), (0, 0.0, 1.0, 2.0, -0.00057),
+    (1, 1.0, 2.0, 0.0, 0.00056), (0, 0.0, 3.0, 0.0, -0.00042), (1, 1.0, 0.0, 2.0, 0.00042),
+    (1, 1.0, 0.0, -2.0, 0.00038), (1, -1.0, 2.0, 0.0, -0.00024), (0, 2.0, 1.0, 0.0, -0.00007),
+    (0, 0.0, 2.0, -2.0, 0.00004), (0, 3.0, 0.0, 0.0, 0.00004), (0, 1.0, 1.0, -2.0, 0.00003),
+    (0, 0.0, 2.0, 2.0, 0.00003), (0, 1.0, 1.0, 2.0, -0.00003), (0, -1.0, 1.0, 2.0, 0.00003),
+    (0, -1.0, 1.0, -2.0, -0.00002), (0, 1.0, 3.0, 0.0, -0.00002), (0, 0.0, 4.0, 0.0, 0.00002)
+];
+
+/// the phase in degrees, the rate in degrees per lunation and the coefficient in days of the
+/// terms the planets add to a new moon
+const PLANETARY_TERMS: [(f64, f64, f64); 13] = [
+    (251.88, 0.016321, 0.000165), (251.83, 26.651886, 0.000164), (349.42, 36.412478, 0.000126),
+    (84.66, 18.206239, 0.000110), (141.74, 53.303771, 0.000062), (207.14, 2.453732, 0.000060),
+    (154.84, 7.306860, 0.000056), (34.52, 27.261239, 0.000047), (207.19, 0.121824, 0.000042),
+    (291.34, 1.844379, 0.000040), (161.72, 24.198154, 0.000037), (239.56, 25.513099, 0.000035),
+    (331.55, 3.592518, 0.000023)
+];
+
+/// a whole power without needing std
+fn power(x: f64, n: i32) -> f64 {
+    (0..n).fold(1.0, |product, _| product * x)
+}
+
+pub fn mean_lunar_longitude(c: f64) -> f64 {
+    degrees(poly(c, &[218.3164477, 481267.88123421, -0.0015786, 1.0 / 538841.0, -1.0 / 65194000.0]))
+}
+
+/// how far the moon is ahead of the sun on average
+pub fn lunar_elongation(c: f64) -> f64 {
+    degrees(poly(c, &[297.8501921, 445267.1114034, -0.0018819, 1.0 / 545868.0, -1.0 / 113065000.0]))
+}
+
+pub fn solar_anomaly(c: f64) -> f64 {
+    degrees(poly(c, &[357.5291092, 35999.0502909, -0.0001536, 1.0 / 24490000.0]))
+}
+
+pub fn lunar_anomaly(c: f64) -> f64 {
+    degrees(poly(c, &[134.9633964, 477198.8675055, 0.0087414, 1.0 / 69699.0, -1.0 / 14712000.0]))
+}
+
+/// the argument of latitude, how far the moon is from its ascending node
+pub fn moon_node(c: f64) -> f64 {
+    degrees(poly(c, &[93.2720950, 483202.0175233, -0.0036539, -1.0 / 3526000.0, 1.0 / 863310000.0]))
+}
+
+/// the longitude of the moon at a moment of universal time, from the true equinox of the date
+pub fn lunar_longitude(tee: Moment) -> f64 {
+    let c = julian_centuries(tee);
+    let mean = mean_lunar_longitude(c);
+    let (elongation, solar, lunar, node) = (lunar_elongation(c), solar_anomaly(c), lunar_anomaly(c), moon_node(c));
+    // the orbit of the earth gets rounder so terms with the solar anomaly shrink
+    let eccentricity = poly(c, &[1.0, -0.002516, -0.0000074]);
+    let correction: f64 = LONGITUDE_TERMS.iter().map(|&(w, x, y, z, v)| {
+        v * power(eccentricity, x.abs() as i32) * sin_degrees(w * elongation + x * solar + y * lunar + z * node)
+    }).sum::<f64>() / 1000000.0;
+    let venus = 3958.0 / 1000000.0 * sin_degrees(119.75 + c * 131.849);
+    let jupiter = 318.0 / 1000000.0 * sin_degrees(53.09 + c * 479264.29);
+    let flat_earth = 1962.0 / 1000000.0 * sin_degrees(mean - node);
+    degrees(mean + correction + venus + jupiter + flat_earth + nutation(tee))
+}
+
+/// the moment in universal time of the `n`th new moon after the one of january 11th 1
+pub fn nth_new_moon(n: i32) -> Moment {
+    let k = (n - NEW_MOONS_TO_J2000) as f64;
+    let c = k / 1236.85;
+    let approximate = J2000 + poly(c, &[5.09766, MEAN_SYNODIC_MONTH * 1236.85, 0.00015437, -0.000000150, 0.00000000073]);
+    let eccentricity = poly(c, &[1.0, -0.002516, -0.0000074]);
+    let solar = poly(c, &[2.5534, 1236.85 * 29.10535670, -0.0000014, -0.00000011]);
+    let lunar = poly(c, &[201.5643, 385.81693528 * 1236.85, 0.0107582, 0.00001238, -0.000000058]);
+    let node = poly(c, &[160.7108, 390.67050284 * 1236.85, -0.0016118, -0.00000227, 0.000000011]);
+    let omega = poly(c, &[124.7746, -1.56375588 * 1236.85, 0.0020672, 0.00000215]);
+    let correction = -0.00017 * sin_degrees(omega) + NEW_MOON_TERMS.iter().map(|&(power, x, y, z, v)| {
+        v * self::power(eccentricity, power) * sin_degrees(x * solar + y * lunar + z * node)
+    }).sum::<f64>();
+    let extra = 0.000325 * sin_degrees(poly(c, &[299.77, 132.8475848, -0.009173]));
+    let additional: f64 = PLANETARY_TERMS.iter().map(|&(i, j, l)| l * sin_degrees(i + j * k)).sum();
+    universal_from_dynamical(approximate + correction + extra + additional)
+}
+
+/// the new moon before `tee` is about its phase worth of lunations back
+fn lunations_before(tee: Moment) -> i32 {
+    round((tee - nth_new_moon(0)) / MEAN_SYNODIC_MONTH - lunar_phase(tee) / 360.0) as i32
+}
+
+/// the last new moon before `tee`
+pub fn new_moon_before(tee: Moment) -> Moment {
+    let mut n = lunations_before(tee) - 1;
+    while nth_new_moon(n + 1) < tee {
+        n += 1;
+    }
+    nth_new_moon(n)
+}
+
+/// the first new moon at or after `tee`
+pub fn new_moon_at_or_after(tee: Moment) -> Moment {
+    let mut n = lunations_before(tee);
+    while nth_new_moon(n) < tee {
+        n += 1;
+    }
+    nth_new_moon(n)
+}
+
+/// how far the moon is ahead of the sun in longitude, 0 at new moon and 180 at full moon
+pub fn lunar_phase(tee: Moment) -> f64 {
+    let phase = degrees(lunar_longitude(tee) - solar_longitude(tee));
+    // close to 0 and 360 the longitudes can disagree with the new moons, which then decide
+    let n = round((tee - nth_new_moon(0)) / MEAN_SYNODIC_MONTH) as i32;
+    let from_new_moons = degrees(360.0 * ((tee - nth_new_moon(n)) / MEAN_SYNODIC_MONTH));
+    if (phase - from_new_moons).abs() > 180.0 { from_new_moons } else { phase }
+}
+
+/// bisects [low, high] for the moment the phase reaches `phase`
+fn phase_between(phase: f64, mut low: Moment, mut high: Moment) -> Moment {
+    while high - low > PRECISION {
+        let middle = (low + high) / 2.0;
+        if divide_f(lunar_phase(middle) - phase, 360.0).1 < 180.0 {
+            high = middle;
+        } else {
+            low = middle;
+        }
+    }
+    (low + high) / 2.0
+}
+
+/// the first moment at or after `tee` the moon reaches the phase, like `FULL`
+pub fn lunar_phase_at_or_after(phase: f64, tee: Moment) -> Moment {
+    let estimate = tee + MEAN_SYNODIC_MONTH / 360.0 * divide_f(phase - lunar_phase(tee), 360.0).1;
+    phase_between(phase, tee.max(estimate - 2.0), estimate + 2.0)
+}
+
+/// the last moment at or before `tee` the moon reaches the phase
+pub fn lunar_phase_at_or_before(phase: f64, tee: Moment) -> Moment {
+    let estimate = tee - MEAN_SYNODIC_MONTH / 360.0 * divide_f(lunar_phase(tee) - phase, 360.0).1;
+    phase_between(phase, estimate - 2.0, tee.min(estimate + 2.0))
+}
+
+/// the true new moon closest to the molad of a hebrew month, both in universal time
+///
+/// the molad is a mean conjunction so the true one is up to about 14 hours either side of it
+pub fn molad_and_conjunction(month: i32, year: i32) -> (Moment, Moment) {
+    let mean = universal_from_local(moment_from_molad(molad(month, year)), JERUSALEM);
+    let before = new_moon_before(mean);
+    let after = new_moon_at_or_after(mean);
+    (mean, if mean - before < after - mean { before } else { after })
+}
@@ -0,0 +1,74 @@
+//! calendars that follow the real sun and moon instead of rules that only approximate them
+//!
+//! moments are in universal time unless said otherwise, angles are in degrees and the sines
+//! and cosines are worked out here so nothing needs std
+
+use common::{divide_f, Moment};
+
+pub mod time;
+pub mod solar;
+pub mod lunar;
+
+pub const MEAN_TROPICAL_YEAR: f64 = 365.242189;
+pub const MEAN_SYNODIC_MONTH: f64 = 29.530588861;
+
+/// noon of january 1st 2000 in dynamical time, the epoch of the julian centuries
+pub const J2000: Moment = 730120.5;
+
+/// a place on earth, `zone` is how far its standard time is ahead of universal time in days
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Location {
+    pub latitude: f64,
+    pub longitude: f64,
+    pub elevation: f64,
+    pub zone: f64
+}
+
+/// the temple mount, where the molad is reckoned in local mean time
+pub const JERUSALEM: Location = Location { latitude: 31.78, longitude: 35.24, elevation: 740.0, zone: 2.0 / 24.0 };
+
+/// an angle given in degrees, arcminutes and arcseconds
+pub fn angle(degrees: f64, minutes: f64, seconds: f64) -> f64 {
+    degrees + (minutes + seconds / 60.0) / 60.0
+}
+
+/// an angle moved into the range [0, 360)
+pub fn degrees(theta: f64) -> f64 {
+    divide_f(theta, 360.0).1
+}
+
+/// the polynomial with `coefficients` starting from the constant term at `x`
+pub fn poly(x: f64, coefficients: &[f64]) -> f64 {
+    coefficients.iter().rev().fold(0.0, |sum, &coefficient| sum * x + coefficient)
+}
+
+/// the taylor series of sine and cosine, which are exact to the last bit within 45 degrees of 0
+fn sin_series(x: f64) -> f64 {
+    let x2 = x * x;
+    (1..9).rev().fold(1.0, |sum, n| 1.0 - sum * x2 / ((2 * n) * (2 * n + 1)) as f64) * x
+}
+
+fn cos_series(x: f64) -> f64 {
+    let x2 = x * x;
+    (1..10).rev().fold(1.0, |sum, n| 1.0 - sum * x2 / ((2 * n - 1) * (2 * n)) as f64)
+}
+
+pub fn sin_degrees(theta: f64) -> f64 {
+    // the angle is taken to within 45 degrees of a quarter turn so the series converge quickly
+    let (quarter, rest) = divide_f(theta + 45.0, 90.0);
+    let x = (rest - 45.0).to_radians();
+    match divide_f(quarter, 4.0).1 as i32 {
+        0 => sin_series(x),
+        1 => cos_series(x),
+        2 => -sin_series(x),
+        _ => -cos_series(x)
+    }
+}
+
+pub fn cos_degrees(theta: f64) -> f64 {
+    sin_degrees(theta + 90.0)
+}
+
+pub fn tan_degrees(theta: f64) -> f64 {
+    sin_degrees(theta) / cos_degrees(theta)
+}
@@ -0,0 +1,90 @@
+//! where the sun is seen from the earth
+//!
+//! the longitude is the sum of the periodic terms bretagnon and simon fitted, which is within
+//! about a thousandth of a degree for 2000 years either side of J2000
+
+use common::{divide_f, Moment};
+use arithmetical::gregorian::{fixed_from_gregorian, Gregorian};
+use super::time::julian_centuries;
+use super::{angle, degrees, poly, sin_degrees, cos_degrees, MEAN_TROPICAL_YEAR};
+
+/// the solar longitudes of the equinoxes and solstices
+pub const SPRING: f64 = 0.0;
+pub const SUMMER: f64 = 90.0;
+pub const AUTUMN: f64 = 180.0;
+pub const WINTER: f64 = 270.0;
+
+/// how close in days the moments found by `solar_longitude_after` are, about a second
+const PRECISION: f64 = 0.00001;
+
+/// the coefficients, phases in degrees and rates in degrees per julian century of the periodic terms
+const LONGITUDE_TERMS: [(f64, f64, f64); 49] = [
+    (403406.0, 270.54861, 0.9287892), (195207.0, 340.19128, 35999.1376958), (119433.0, 63.91854, 35999.4089666),
+    (112392.0, 331.26220, 35998.7287385), (3891.0, 317.843, 71998.20261), (2819.0, 86.631, 71998.4403),
+    (1721.0, 240.052, 36000.35726), (660.0, 310.26, 71997.4812), (350.0, 247.23, 32964.4678),
+    (334.0, 260.87, -19.4410), (314.0, 297.82, 445267.1117), (268.0, 343.14, 45036.8840),
+    (242.0, 166.79, 3.1008), (234.0, 81.53, 22518.4434), (158.0, 3.50, -19.9739),
+    (132.0, 132.75, 65928.9345), (129.0, 182.95, 9038.0293), (114.0, 162.03, 3034.7684),
+    (99.0, 29.8, 33718.148), (93.0, 266.4, 3034.448), (86.0, 249.2, -2280.773),
+    (78.0, 157.6, 29929.992), (72.0, 257.8, 31556.493), (68.0, 185.1, 149.588),
+    (64.0, 69.9, 9037.750), (46.0, 8.0, 107997.405), (38.0, 197.1, -4444.176),
+    (37.0, 250.4, 151.771), (32.0, 65.3, 67555.316), (29.0, 162.7, 31556.080),
+    (28.0, 341.5, -4561.540), (27.0, 291.6, 107996.706), (27.0, 98.5, 1221.655),
+    (25.0, 146.7, 62894.167), (24.0, 110.0, 31437.369), (21.0, 5.2, 14578.298),
+    (21.0, 342.6, -31931.757), (20.0, 230.9, 34777.243), (18.0, 256.1, 1221.999),
+    (17.0, 45.3, 62894.511), (14.0, 242.9, -4442.039), (13.0, 115.2, 107997.909),
+    (13.0, 151.8, 119.066), (13.0, 285.3, 16859.071), (12.0, 53.3, -4.578),
+    (10.0, 126.6, 26895.292), (10.0, 205.7, -39.127), (10.0, 85.9, 12297.536),
+    (10.0, 146.1, 90073.778)
+];
+
+/// the longitude of the sun at a moment of universal time, from the true equinox of the date
+pub fn solar_longitude(tee: Moment) -> f64 {
+    let c = julian_centuries(tee);
+    let terms: f64 = LONGITUDE_TERMS.iter().map(|&(x, y, z)| x * sin_degrees(y + z * c)).sum();
+    // the terms are in units of 10^-7 radians
+    let longitude = 282.7771834 + 36000.76953744 * c + 0.000005729577951308232 * terms;
+    degrees(longitude + aberration(tee) + nutation(tee))
+}
+
+/// the wobble of the earths axis, which moves the equinox the longitude is counted from
+pub fn nutation(tee: Moment) -> f64 {
+    let c = julian_centuries(tee);
+    let a = poly(c, &[124.90, -1934.134, 0.002063]);
+    let b = poly(c, &[201.11, 72001.5377, 0.00057]);
+    -0.004778 * sin_degrees(a) - 0.0003667 * sin_degrees(b)
+}
+
+/// how far the sun seems displaced by the earth moving while its light travels to it
+pub fn aberration(tee: Moment) -> f64 {
+    let c = julian_centuries(tee);
+    0.0000974 * cos_degrees(177.63 + 35999.01848 * c) - 0.005575
+}
+
+/// the angle between the equator and the ecliptic
+pub fn obliquity(tee: Moment) -> f64 {
+    let c = julian_centuries(tee);
+    angle(23.0, 26.0, 21.448) + poly(c, &[0.0, angle(0.0, 0.0, -46.8150), angle(0.0, 0.0, -0.00059), angle(0.0, 0.0, 0.001813)])
+}
+
+/// the first moment after `tee` that the sun reaches the longitude `lambda`
+pub fn solar_longitude_after(lambda: f64, tee: Moment) -> Moment {
+    let rate = MEAN_TROPICAL_YEAR / 360.0;
+    // the real sun is never more than a few days off the mean one so the moment is bracketed closely
+    let estimate = tee + rate * divide_f(lambda - solar_longitude(tee), 360.0).1;
+    let (mut low, mut high) = (tee.max(estimate - 5.0), estimate + 5.0);
+    while high - low > PRECISION {
+        let middle = (low + high) / 2.0;
+        if divide_f(solar_longitude(middle) - lambda, 360.0).1 < 180.0 {
+            high = middle;
+        } else {
+            low = middle;
+        }
+    }
+    (low + high) / 2.0
+}
+
+/// the moment in universal time of an equinox or solstice in a gregorian year, like `SPRING`
+pub fn season_in_gregorian(season: f64, year: i32) -> Moment {
+    solar_longitude_after(season, fixed_from_gregorian(Gregorian { year, month: 1, day: 1 }) as Moment)
+}
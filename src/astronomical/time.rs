@@ -0,0 +1,110 @@
+//! universal time is the mean solar time of greenwich, dynamical time is the uniform time the
+//! positions of the sun and moon are worked out in and local and apparent time follow the mean
+//! and the real sun at a location
+
+use common::{floor, Moment};
+use arithmetical::gregorian::{gregorian_year_from_fixed, gregorian_date_difference, Gregorian};
+use super::{poly, sin_degrees, cos_degrees, tan_degrees, Location, J2000};
+use super::solar::obliquity;
+
+/// the difference between dynamical and universal time, ΔT, in days
+///
+/// it comes from the polynomials espenak and meeus fitted to observations, which are best from
+/// 1620 on when telescopes timed eclipses and occultations, and a parabola before and after them
+pub fn ephemeris_correction(tee: Moment) -> f64 {
+    let year = gregorian_year_from_fixed(floor(tee) as i32).0;
+    let y = year as f64;
+    let seconds = |x: f64, coefficients: &[f64]| poly(x, coefficients) / 86400.0;
+    match year {
+        1900..=1986 => {
+            let c = gregorian_date_difference(Gregorian { year, month: 7, day: 1 }, Gregorian { year: 1900, month: 1, day: 1 }) as f64 / 36525.0;
+            poly(c, &[-0.00002, 0.000297, 0.025184, -0.181133, 0.553040, -0.861938, 0.677066, -0.212591])
+        },
+        1800..=1899 => {
+            let c = gregorian_date_difference(Gregorian { year, month: 7, day: 1 }, Gregorian { year: 1900, month: 1, day: 1 }) as f64 / 36525.0;
+            poly(c, &[-0.000009, 0.003844, 0.083563, 0.865736, 4.867575, 15.845535, 31.332267, 38.291999, 28.316289, 11.636204, 2.043794])
+        },
+        2051..=2150 => (-20.0 + 32.0 * ((y - 1820.0) / 100.0) * ((y - 1820.0) / 100.0) - 0.5628 * (2150.0 - y)) / 86400.0,
+        2006..=2050 => seconds(y - 2000.0, &[62.92, 0.32217, 0.005589]),
+        1987..=2005 => seconds(y - 2000.0, &[63.86, 0.3345, -0.060374, 0.0017275, 0.000651814, 0.00002373599]),
+        1700..=1799 => seconds(y - 1700.0, &[8.118780842, -0.005092142, 0.003336121, -0.0000266484]),
+        1600..=1699 => seconds(y - 1600.0, &[120.0, -0.9808, -0.01532, 0.000140272128]),
+        500..=1599 => seconds((y - 1000.0) / 100.0, &[1574.2, -556.01, 71.23472, 0.319781, -0.8503463, -0.005050998, 0.0083572073]),
+        -499..=499 => seconds(y / 100.0, &[10583.6, -1014.41, 33.78311, -5.952053, -0.1798452, 0.022174192, 0.0090316521]),
+        _ => seconds((y - 1820.0) / 100.0, &[-20.0, 0.0, 32.0])
+    }
+}
+
+pub fn dynamical_from_universal(tee: Moment) -> Moment {
+    tee + ephemeris_correction(tee)
+}
+
+pub fn universal_from_dynamical(tee: Moment) -> Moment {
+    tee - ephemeris_correction(tee)
+}
+
+/// the julian centuries of dynamical time since J2000 at a moment of universal time
+pub fn julian_centuries(tee: Moment) -> f64 {
+    (dynamical_from_universal(tee) - J2000) / 36525.0
+}
+
+/// the difference between local mean time and standard time in days for a longitude
+pub fn zone_from_longitude(longitude: f64) -> f64 {
+    longitude / 360.0
+}
+
+pub fn local_from_universal(tee: Moment, location: Location) -> Moment {
+    tee + zone_from_longitude(location.longitude)
+}
+
+pub fn universal_from_local(tee: Moment, location: Location) -> Moment {
+    tee - zone_from_longitude(location.longitude)
+}
+
+pub fn standard_from_universal(tee: Moment, location: Location) -> Moment {
+    tee + location.zone
+}
+
+pub fn universal_from_standard(tee: Moment, location: Location) -> Moment {
+    tee - location.zone
+}
+
+pub fn standard_from_local(tee: Moment, location: Location) -> Moment {
+    standard_from_universal(universal_from_local(tee, location), location)
+}
+
+pub fn local_from_standard(tee: Moment, location: Location) -> Moment {
+    local_from_universal(universal_from_standard(tee, location), location)
+}
+
+/// how far the real sun is ahead of the mean sun in days, at most half a day either way
+pub fn equation_of_time(tee: Moment) -> f64 {
+    let c = julian_centuries(tee);
+    let longitude = poly(c, &[280.46645, 36000.76983, 0.0003032]);
+    let anomaly = poly(c, &[357.52910, 35999.05030, -0.0001559, -0.00000048]);
+    let eccentricity = poly(c, &[0.016708617, -0.000042037, -0.0000001236]);
+    let y = tan_degrees(obliquity(tee) / 2.0) * tan_degrees(obliquity(tee) / 2.0);
+    let equation = (y * sin_degrees(2.0 * longitude)
+        - 2.0 * eccentricity * sin_degrees(anomaly)
+        + 4.0 * eccentricity * y * sin_degrees(anomaly) * cos_degrees(2.0 * longitude)
+        - 0.5 * y * y * sin_degrees(4.0 * longitude)
+        - 1.25 * eccentricity * eccentricity * sin_degrees(2.0 * anomaly)) / (2.0 * core::f64::consts::PI);
+    equation.clamp(-0.5, 0.5)
+}
+
+/// sundial time from local mean time
+pub fn apparent_from_local(tee: Moment, location: Location) -> Moment {
+    tee + equation_of_time(universal_from_local(tee, location))
+}
+
+pub fn local_from_apparent(tee: Moment, location: Location) -> Moment {
+    tee - equation_of_time(universal_from_local(tee, location))
+}
+
+pub fn apparent_from_universal(tee: Moment, location: Location) -> Moment {
+    apparent_from_local(local_from_universal(tee, location), location)
+}
+
+pub fn universal_from_apparent(tee: Moment, location: Location) -> Moment {
+    universal_from_local(local_from_apparent(tee, location), location)
+}
@@ -0,0 +1,187 @@
+//! converting many dates at once
+//!
+//! dates next to each other are almost always in the same month, so a `Converter` remembers the
+//! month of the last date it converted and only does a full conversion when a date falls outside
+//! it. sorted input gets the most out of this but any order gives the right answer
+
+use calendar::{Calendar, Date};
+use common::RD;
+use arithmetical::{gregorian, julian, coptic_ethiopic, iso, islamic};
+use arithmetical::mayan::long_count::LongCount;
+use arithmetical::hebrew::{HebrewYearCache, Hebrew};
+use alloc::vec::Vec;
+
+/// hebrew years kept around, enough for a column of dates that goes back and forth a little
+const HEBREW_CACHE_SIZE: usize = 8;
+
+/// a run of days [start, end) that only differ in their last field, which goes up by one each day
+#[derive(Clone, Copy, Debug)]
+struct Span {
+    start: RD,
+    end: RD,
+    first: Date
+}
+
+impl Span {
+    fn date(&self, date: RD) -> Date {
+        let first = last_field(self.first);
+        with_last_field(self.first, first + date - self.start)
+    }
+}
+
+fn last_field(date: Date) -> i32 {
+    match date {
+        Date::Gregorian(date) => date.day,
+        Date::Julian(date) => date.day,
+        Date::Coptic(date) | Date::Ethiopic(date) => date.day,
+        Date::Iso(date) => date.day,
+        Date::Islamic(date) => date.day,
+        Date::Hebrew(date) => date.day,
+        Date::LongCount(date) => date.kin,
+        date => unreachable!("{:?} has no days of the month", date.calendar())
+    }
+}
+
+/// the same date with its day (or kin) changed, only for the calendars that spans are made for
+fn with_last_field(date: Date, value: i32) -> Date {
+    match date {
+        Date::Gregorian(date) => Date::Gregorian(gregorian::Gregorian { day: value, ..date }),
+        Date::Julian(date) => Date::Julian(julian::Julian { day: value, ..date }),
+        Date::Coptic(date) => Date::Coptic(coptic_ethiopic::CopticEthiopic { day: value, ..date }),
+        Date::Ethiopic(date) => Date::Ethiopic(coptic_ethiopic::CopticEthiopic { day: value, ..date }),
+        Date::Iso(date) => Date::Iso(iso::Iso { day: value, ..date }),
+        Date::Islamic(date) => Date::Islamic(islamic::Islamic { day: value, ..date }),
+        Date::Hebrew(date) => Date::Hebrew(Hebrew { day: value, ..date }),
+        Date::LongCount(date) => Date::LongCount(LongCount { kin: value, ..date }),
+        date => date
+    }
+}
+
+/// converts dates of one calendar reusing the month of the last conversion
+pub struct Converter {
+    calendar: Calendar,
+    span: Option<Span>,
+    /// the first day of the last month converted to an R.D. date
+    month: Option<(Date, RD)>,
+    hebrew: HebrewYearCache
+}
+
+impl Converter {
+    pub fn new(calendar: Calendar) -> Converter {
+        Converter { calendar, span: None, month: None, hebrew: HebrewYearCache::new(HEBREW_CACHE_SIZE) }
+    }
+
+    pub fn calendar(&self) -> Calendar {
+        self.calendar
+    }
+
+    pub fn from_fixed(&mut self, date: RD) -> Date {
+        if !has_days_of_month(self.calendar) && !matches!(self.calendar, Calendar::Iso | Calendar::LongCount) {
+            return self.calendar.from_fixed(date);
+        }
+        match self.span {
+            Some(span) if span.start <= date && date < span.end => span.date(date),
+            _ => {
+                let span = self.span_of(date);
+                self.span = Some(span);
+                span.date(date)
+            }
+        }
+    }
+
+    /// the R.D. date of a date of this calendar or `None` for the cyclic calendars
+    pub fn to_fixed(&mut self, date: Date) -> Option<RD> {
+        if !has_days_of_month(self.calendar) || date.calendar() != self.calendar {
+            return date.to_fixed();
+        }
+        let day = last_field(date);
+        let first = with_last_field(date, 1);
+        match self.month {
+            Some((month, start)) if month == first => Some(start + day - 1),
+            _ => {
+                let start = match first {
+                    Date::Hebrew(hebrew) => self.hebrew.fixed_from_hebrew(hebrew),
+                    first => first.to_fixed().expect("Only calendars with months are looked up by month")
+                };
+                self.month = Some((first, start));
+                Some(start + day - 1)
+            }
+        }
+    }
+
+    fn full_from_fixed(&mut self, date: RD) -> Date {
+        match self.calendar {
+            Calendar::Hebrew => Date::Hebrew(self.hebrew.hebrew_from_fixed(date)),
+            calendar => calendar.from_fixed(date)
+        }
+    }
+
+    fn span_of(&mut self, date: RD) -> Span {
+        let converted = self.full_from_fixed(date);
+        let last = last_field(converted);
+        let (start, length) = match converted {
+            Date::Iso(_) => (date - last + 1, 7),
+            Date::LongCount(_) => (date - last, 20),
+            Date::Hebrew(Hebrew { year, month, .. }) => (date - last + 1, self.hebrew.year(year).last_day_of_month(month)),
+            _ => (date - last + 1, converted.days_in_month().expect("Spans are only made for calendars with months"))
+        };
+        Span { start, end: start + length, first: with_last_field(converted, last - (date - start)) }
+    }
+}
+
+fn has_days_of_month(calendar: Calendar) -> bool {
+    matches!(calendar, Calendar::Gregorian | Calendar::Julian | Calendar::Coptic | Calendar::Ethiopic | Calendar::Islamic | Calendar::Hebrew)
+}
+
+/// converts R.D. dates to a calendar
+pub fn from_fixed_batch(calendar: Calendar, dates: &[RD]) -> Vec<Date> {
+    let mut converter = Converter::new(calendar);
+    dates.iter().map(|&date| converter.from_fixed(date)).collect()
+}
+
+/// converts dates of one calendar to R.D. dates, `None` for dates of the cyclic calendars
+pub fn to_fixed_batch(calendar: Calendar, dates: &[Date]) -> Vec<Option<RD>> {
+    let mut converter = Converter::new(calendar);
+    dates.iter().map(|&date| converter.to_fixed(date)).collect()
+}
+
+/// walks a calendar a day at a time without converting each day from scratch
+pub struct Cursor {
+    converter: Converter,
+    fixed: RD,
+    date: Date
+}
+
+impl Cursor {
+    pub fn new(calendar: Calendar, date: RD) -> Cursor {
+        let mut converter = Converter::new(calendar);
+        let converted = converter.from_fixed(date);
+        Cursor { converter, fixed: date, date: converted }
+    }
+
+    pub fn fixed(&self) -> RD {
+        self.fixed
+    }
+
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// moves by a number of days, backwards when negative
+    pub fn step(&mut self, days: i32) -> Date {
+        self.fixed += days;
+        self.date = self.converter.from_fixed(self.fixed);
+        self.date
+    }
+}
+
+/// gives the current day and then moves on to the next one
+impl Iterator for Cursor {
+    type Item = (RD, Date);
+
+    fn next(&mut self) -> Option<(RD, Date)> {
+        let current = (self.fixed, self.date);
+        self.step(1);
+        Some(current)
+    }
+}
@@ -0,0 +1,573 @@
+use common::RD;
+use arithmetical::*;
+use arithmetical::gregorian::Gregorian;
+use arithmetical::julian::Julian;
+use arithmetical::julian::roman::{Roman, Event};
+use arithmetical::coptic_ethiopic::CopticEthiopic;
+use arithmetical::iso::Iso;
+use arithmetical::islamic::Islamic;
+use arithmetical::hebrew::Hebrew;
+use arithmetical::hindu::solar::HinduSolar;
+use arithmetical::hindu::lunisolar::HinduLunisolar;
+use arithmetical::mayan::long_count::LongCount;
+use arithmetical::mayan::haab::Haab;
+use arithmetical::mayan::tzolkin::Tzolkin;
+use arithmetical::balinese_powukon::BaliPowukon;
+use core::error::Error;
+use core::fmt;
+use core::ops::RangeInclusive;
+use alloc::vec::Vec;
+use alloc::string::String;
+
+/// why a date isnt a real date of its calendar
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DateError {
+    /// the month isnt in the year
+    InvalidMonth,
+    /// the day isnt in the month
+    InvalidDay,
+    /// some other field is out of its range
+    InvalidField(&'static str)
+}
+
+impl fmt::Display for DateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DateError::InvalidMonth => write!(f, "month is not in the year"),
+            DateError::InvalidDay => write!(f, "day is not in the month"),
+            DateError::InvalidField(field) => write!(f, "{} is out of range", field)
+        }
+    }
+}
+
+impl Error for DateError {}
+
+/// the R.D. dates every calendar can convert, about 98 thousand years either side of the epoch
+pub const FIXED_RANGE: RangeInclusive<RD> = -36_000_000..=36_000_000;
+
+/// every calendar in `arithmetical` so they can be picked at runtime
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Calendar {
+    Gregorian,
+    Julian,
+    Roman,
+    Coptic,
+    Ethiopic,
+    Iso,
+    Islamic,
+    Hebrew,
+    HinduSolar,
+    HinduLunisolar,
+    LongCount,
+    Haab,
+    Tzolkin,
+    BaliPowukon
+}
+
+pub const ALL: [Calendar; 14] = [
+    Calendar::Gregorian,
+    Calendar::Julian,
+    Calendar::Roman,
+    Calendar::Coptic,
+    Calendar::Ethiopic,
+    Calendar::Iso,
+    Calendar::Islamic,
+    Calendar::Hebrew,
+    Calendar::HinduSolar,
+    Calendar::HinduLunisolar,
+    Calendar::LongCount,
+    Calendar::Haab,
+    Calendar::Tzolkin,
+    Calendar::BaliPowukon
+];
+
+/// a date in any of the calendars
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Date {
+    Gregorian(Gregorian),
+    Julian(Julian),
+    Roman(Roman),
+    Coptic(CopticEthiopic),
+    Ethiopic(CopticEthiopic),
+    Iso(Iso),
+    Islamic(Islamic),
+    Hebrew(Hebrew),
+    HinduSolar(HinduSolar),
+    HinduLunisolar(HinduLunisolar),
+    LongCount(LongCount),
+    Haab(Haab),
+    Tzolkin(Tzolkin),
+    BaliPowukon(BaliPowukon)
+}
+
+pub const WEEKDAY_NAMES: &[(&str, i32)] = &[
+    ("Sunday", 0), ("Monday", 1), ("Tuesday", 2), ("Wednesday", 3), ("Thursday", 4), ("Friday", 5), ("Saturday", 6),
+    ("Sun", 0), ("Mon", 1), ("Tue", 2), ("Wed", 3), ("Thu", 4), ("Fri", 5), ("Sat", 6)
+];
+
+const ISO_DAY_NAMES: &[(&str, i32)] = &[
+    ("Monday", 1), ("Tuesday", 2), ("Wednesday", 3), ("Thursday", 4), ("Friday", 5), ("Saturday", 6), ("Sunday", 7),
+    ("Mon", 1), ("Tue", 2), ("Wed", 3), ("Thu", 4), ("Fri", 5), ("Sat", 6), ("Sun", 7)
+];
+
+const GREGORIAN_MONTH_NAMES: &[(&str, i32)] = &[
+    ("January", 1), ("February", 2), ("March", 3), ("April", 4), ("May", 5), ("June", 6),
+    ("July", 7), ("August", 8), ("September", 9), ("October", 10), ("November", 11), ("December", 12),
+    ("Jan", 1), ("Feb", 2), ("Mar", 3), ("Apr", 4), ("Jun", 6), ("Jul", 7),
+    ("Aug", 8), ("Sep", 9), ("Oct", 10), ("Nov", 11), ("Dec", 12)
+];
+
+const ROMAN_EVENT_NAMES: &[(&str, i32)] = &[("Kalends", 1), ("Nones", 2), ("Ides", 3)];
+
+const COPTIC_MONTH_NAMES: &[(&str, i32)] = &[
+    ("Thoout", 1), ("Paope", 2), ("Athor", 3), ("Koiak", 4), ("Tobe", 5), ("Meshir", 6), ("Paremotep", 7),
+    ("Parmoute", 8), ("Pashons", 9), ("Paone", 10), ("Epep", 11), ("Mesore", 12), ("Epagomene", 13)
+];
+
+const ETHIOPIC_MONTH_NAMES: &[(&str, i32)] = &[
+    ("Meskerem", 1), ("Tekemt", 2), ("Hedar", 3), ("Tahsas", 4), ("Tir", 5), ("Yekatit", 6), ("Megabit", 7),
+    ("Miazia", 8), ("Genbot", 9), ("Sene", 10), ("Hamle", 11), ("Nehasse", 12), ("Pagume", 13),
+    ("Maskaram", 1), ("Teqemt", 2), ("Takhsas", 4), ("Ter", 5), ("Yakatit", 6), ("Magabit", 7),
+    ("Miyazya", 8), ("Sane", 10), ("Nahase", 12), ("Paguemen", 13)
+];
+
+const ISLAMIC_MONTH_NAMES: &[(&str, i32)] = &[
+    ("Muharram", 1), ("Safar", 2), ("Rabi I", 3), ("Rabi II", 4), ("Jumada I", 5), ("Jumada II", 6),
+    ("Rajab", 7), ("Sha'ban", 8), ("Ramadan", 9), ("Shawwal", 10), ("Dhu al-Qa'da", 11), ("Dhu al-Hijja", 12),
+    ("Rabi al-Awwal", 3), ("Rabi al-Thani", 4), ("Jumada al-Ula", 5), ("Jumada al-Akhira", 6)
+];
+
+/// in a leap year month 12 is adar i and month 13 is adar ii
+const HEBREW_MONTH_NAMES: &[(&str, i32)] = &[
+    ("Nisan", 1), ("Iyyar", 2), ("Sivan", 3), ("Tammuz", 4), ("Av", 5), ("Elul", 6), ("Tishri", 7),
+    ("Marheshvan", 8), ("Kislev", 9), ("Tevet", 10), ("Shevat", 11), ("Adar", 12), ("Adar II", 13),
+    ("Adar I", 12), ("Iyar", 2), ("Tishrei", 7), ("Cheshvan", 8), ("Heshvan", 8), ("Teves", 10), ("Shvat", 11),
+    ("Veadar", 13)
+];
+
+const HINDU_SOLAR_MONTH_NAMES: &[(&str, i32)] = &[
+    ("Mesha", 1), ("Vrishabha", 2), ("Mithuna", 3), ("Karka", 4), ("Simha", 5), ("Kanya", 6),
+    ("Tula", 7), ("Vrischika", 8), ("Dhanus", 9), ("Makara", 10), ("Kumbha", 11), ("Mina", 12)
+];
+
+const HINDU_LUNAR_MONTH_NAMES: &[(&str, i32)] = &[
+    ("Chaitra", 1), ("Vaisakha", 2), ("Jyaishtha", 3), ("Ashadha", 4), ("Sravana", 5), ("Bhadrapada", 6),
+    ("Asvina", 7), ("Kartika", 8), ("Margasirsha", 9), ("Pausha", 10), ("Magha", 11), ("Phalguna", 12)
+];
+
+const HAAB_MONTH_NAMES: &[(&str, i32)] = &[
+    ("Pop", 1), ("Uo", 2), ("Zip", 3), ("Zotz", 4), ("Tzec", 5), ("Xul", 6), ("Yaxkin", 7), ("Mol", 8),
+    ("Chen", 9), ("Yax", 10), ("Zac", 11), ("Ceh", 12), ("Mac", 13), ("Kankin", 14), ("Muan", 15),
+    ("Pax", 16), ("Kayab", 17), ("Cumku", 18), ("Uayeb", 19)
+];
+
+const TZOLKIN_NAMES: &[(&str, i32)] = &[
+    ("Imix", 1), ("Ik", 2), ("Akbal", 3), ("Kan", 4), ("Chicchan", 5), ("Cimi", 6), ("Manik", 7),
+    ("Lamat", 8), ("Muluc", 9), ("Oc", 10), ("Chuen", 11), ("Eb", 12), ("Ben", 13), ("Ix", 14),
+    ("Men", 15), ("Cib", 16), ("Caban", 17), ("Etznab", 18), ("Cauac", 19), ("Ahau", 20),
+    ("Batz", 11), ("Ajaw", 20)
+];
+
+const BOOLEAN_NAMES: &[(&str, i32)] = &[("false", 0), ("true", 1)];
+
+/// names are compared ignoring case and anything that isnt a letter or digit
+/// so "Dhu al-Hijja", "dhu_al_hijja" and "DHUALHIJJA" are all the same name
+pub fn normalize_name(name: &str) -> String {
+    name.chars().filter(|c| c.is_alphanumeric()).flat_map(|c| c.to_lowercase()).collect()
+}
+
+/// looks up a value by one of its names
+pub fn value_from_name(names: &[(&str, i32)], name: &str) -> Option<i32> {
+    let name = normalize_name(name);
+    names.iter().find(|&&(candidate, _)| normalize_name(candidate) == name).map(|&(_, value)| value)
+}
+
+/// the display name of a value, which is the first name given for it
+pub fn name_from_value(names: &[(&'static str, i32)], value: i32) -> Option<&'static str> {
+    names.iter().find(|&&(_, candidate)| candidate == value).map(|&(name, _)| name)
+}
+
+impl Calendar {
+    pub fn name(self) -> &'static str {
+        match self {
+            Calendar::Gregorian => "gregorian",
+            Calendar::Julian => "julian",
+            Calendar::Roman => "roman",
+            Calendar::Coptic => "coptic",
+            Calendar::Ethiopic => "ethiopic",
+            Calendar::Iso => "iso",
+            Calendar::Islamic => "islamic",
+            Calendar::Hebrew => "hebrew",
+            Calendar::HinduSolar => "hindu_solar",
+            Calendar::HinduLunisolar => "hindu_lunisolar",
+            Calendar::LongCount => "long_count",
+            Calendar::Haab => "haab",
+            Calendar::Tzolkin => "tzolkin",
+            Calendar::BaliPowukon => "bali"
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Calendar> {
+        let name = normalize_name(name);
+        let alias = match name.as_str() {
+            "pawukon" | "powukon" | "balinese" => Some(Calendar::BaliPowukon),
+            "mayan" => Some(Calendar::LongCount),
+            "hindu" => Some(Calendar::HinduSolar),
+            _ => None
+        };
+        ALL.iter().cloned().find(|calendar| normalize_name(calendar.name()) == name).or(alias)
+    }
+
+    /// the names of the fields of the calendars date struct in order
+    pub fn fields(self) -> &'static [&'static str] {
+        match self {
+            Calendar::Gregorian | Calendar::Julian | Calendar::Coptic | Calendar::Ethiopic
+                | Calendar::Islamic | Calendar::Hebrew | Calendar::HinduSolar => &["year", "month", "day"],
+            Calendar::Roman => &["year", "month", "event", "count", "leap"],
+            Calendar::Iso => &["year", "week", "day"],
+            Calendar::HinduLunisolar => &["year", "month", "leap_month", "day"],
+            Calendar::LongCount => &["baktun", "katun", "tun", "uinal", "kin"],
+            Calendar::Haab => &["month", "day"],
+            Calendar::Tzolkin => &["number", "name"],
+            Calendar::BaliPowukon => &[
+                "luang", "dwiwara", "triwara", "caturwara", "pancawara",
+                "sadwara", "saptawara", "asatawara", "sangawara", "dasawara"
+            ]
+        }
+    }
+
+    /// the names the values of a field can be given by if it has any
+    pub fn value_names(self, field: &str) -> Option<&'static [(&'static str, i32)]> {
+        match (self, field) {
+            (Calendar::Gregorian, "month") | (Calendar::Julian, "month") | (Calendar::Roman, "month") => Some(GREGORIAN_MONTH_NAMES),
+            (Calendar::Roman, "event") => Some(ROMAN_EVENT_NAMES),
+            (Calendar::Coptic, "month") => Some(COPTIC_MONTH_NAMES),
+            (Calendar::Ethiopic, "month") => Some(ETHIOPIC_MONTH_NAMES),
+            (Calendar::Iso, "day") => Some(ISO_DAY_NAMES),
+            (Calendar::Islamic, "month") => Some(ISLAMIC_MONTH_NAMES),
+            (Calendar::Hebrew, "month") => Some(HEBREW_MONTH_NAMES),
+            (Calendar::HinduSolar, "month") => Some(HINDU_SOLAR_MONTH_NAMES),
+            (Calendar::HinduLunisolar, "month") => Some(HINDU_LUNAR_MONTH_NAMES),
+            (Calendar::Haab, "month") => Some(HAAB_MONTH_NAMES),
+            (Calendar::Tzolkin, "name") => Some(TZOLKIN_NAMES),
+            (Calendar::Roman, "leap") | (Calendar::HinduLunisolar, "leap_month") | (Calendar::BaliPowukon, "luang") => Some(BOOLEAN_NAMES),
+            _ => None
+        }
+    }
+
+    /// calendars that are only a repeating cycle of days have no year so they
+    /// can only be turned back into an R.D. date relative to another date
+    pub fn cycle_length(self) -> Option<i32> {
+        match self {
+            Calendar::Haab => Some(365),
+            Calendar::Tzolkin => Some(260),
+            Calendar::BaliPowukon => Some(210),
+            _ => None
+        }
+    }
+
+    /// the values a field can have before converting the date could overflow, which are enough
+    /// for the date of every R.D. date in `FIXED_RANGE`
+    pub fn field_range(self, field: &str) -> RangeInclusive<i32> {
+        match (self, field) {
+            (_, "year") => -110_000..=110_000,
+            (Calendar::LongCount, "baktun") => -300..=300,
+            _ => -10_000..=10_000
+        }
+    }
+
+    pub fn from_fixed(self, date: RD) -> Date {
+        match self {
+            Calendar::Gregorian => Date::Gregorian(gregorian::gregorian_from_fixed(date)),
+            Calendar::Julian => Date::Julian(julian::julian_from_fixed(date)),
+            Calendar::Roman => Date::Roman(julian::roman::roman_from_fixed(date)),
+            Calendar::Coptic => Date::Coptic(coptic_ethiopic::coptic::coptic_from_fixed(date)),
+            Calendar::Ethiopic => Date::Ethiopic(coptic_ethiopic::ethiopic::ethiopic_from_fixed(date)),
+            Calendar::Iso => Date::Iso(iso::iso_from_fixed(date)),
+            Calendar::Islamic => Date::Islamic(islamic::islamic_from_fixed(date)),
+            Calendar::Hebrew => Date::Hebrew(hebrew::hebrew_from_fixed(date)),
+            Calendar::HinduSolar => Date::HinduSolar(hindu::solar::hindu_solar_from_fixed(date)),
+            Calendar::HinduLunisolar => Date::HinduLunisolar(hindu::lunisolar::hindu_lunisolar_from_fixed(date)),
+            Calendar::LongCount => Date::LongCount(mayan::long_count::long_count_from_fixed(date)),
+            Calendar::Haab => Date::Haab(mayan::haab::mayan_haab_from_fixed(date)),
+            Calendar::Tzolkin => Date::Tzolkin(mayan::tzolkin::mayan_tzolkin_from_fixed(date)),
+            Calendar::BaliPowukon => Date::BaliPowukon(balinese_powukon::bali_powukon_from_fixed(date))
+        }
+    }
+
+    /// the order fields are written in, like 7 Kislev 5706 or 3 Kalends November 1945
+    pub fn written_order(self) -> &'static [&'static str] {
+        match self {
+            Calendar::Gregorian | Calendar::Julian | Calendar::Coptic | Calendar::Ethiopic
+                | Calendar::Islamic | Calendar::Hebrew | Calendar::HinduSolar => &["day", "month", "year"],
+            Calendar::Roman => &["count", "event", "month", "year", "leap"],
+            Calendar::HinduLunisolar => &["day", "leap_month", "month", "year"],
+            Calendar::Haab => &["day", "month"],
+            calendar => calendar.fields()
+        }
+    }
+
+    /// fields that are either true or false
+    pub fn is_flag(self, field: &str) -> bool {
+        matches!((self, field), (Calendar::Roman, "leap") | (Calendar::HinduLunisolar, "leap_month") | (Calendar::BaliPowukon, "luang"))
+    }
+
+    /// builds a date from its field values given in the same order as `fields`
+    pub fn date_from_fields(self, values: &[i32]) -> Option<Date> {
+        if values.len() != self.fields().len() {
+            return None;
+        }
+        let v = |i: usize| values[i];
+        Some(match self {
+            Calendar::Gregorian => Date::Gregorian(Gregorian { year: v(0), month: v(1), day: v(2) }),
+            Calendar::Julian => Date::Julian(Julian { year: v(0), month: v(1), day: v(2) }),
+            Calendar::Roman => Date::Roman(Roman {
+                year: v(0),
+                month: v(1),
+                event: match v(2) {
+                    1 => Event::Kalends,
+                    2 => Event::Nones,
+                    3 => Event::Ides,
+                    _ => return None
+                },
+                count: v(3),
+                leap: v(4) != 0
+            }),
+            Calendar::Coptic => Date::Coptic(CopticEthiopic { year: v(0), month: v(1), day: v(2) }),
+            Calendar::Ethiopic => Date::Ethiopic(CopticEthiopic { year: v(0), month: v(1), day: v(2) }),
+            Calendar::Iso => Date::Iso(Iso { year: v(0), week: v(1), day: v(2) }),
+            Calendar::Islamic => Date::Islamic(Islamic { year: v(0), month: v(1), day: v(2) }),
+            Calendar::Hebrew => Date::Hebrew(Hebrew { year: v(0), month: v(1), day: v(2) }),
+            Calendar::HinduSolar => Date::HinduSolar(HinduSolar { year: v(0), month: v(1), day: v(2) }),
+            Calendar::HinduLunisolar => Date::HinduLunisolar(HinduLunisolar { year: v(0), month: v(1), leap_month: v(2) != 0, day: v(3) }),
+            Calendar::LongCount => Date::LongCount(LongCount { baktun: v(0), katun: v(1), tun: v(2), uinal: v(3), kin: v(4) }),
+            Calendar::Haab => Date::Haab(Haab { month: v(0), day: v(1) }),
+            Calendar::Tzolkin => Date::Tzolkin(Tzolkin { number: v(0), name: v(1) }),
+            Calendar::BaliPowukon => Date::BaliPowukon(BaliPowukon {
+                luang: v(0) != 0,
+                dwiwara: v(1),
+                triwara: v(2),
+                caturwara: v(3),
+                pancawara: v(4),
+                sadwara: v(5),
+                saptawara: v(6),
+                asatawara: v(7),
+                sangawara: v(8),
+                dasawara: v(9)
+            })
+        })
+    }
+}
+
+impl Date {
+    pub fn calendar(&self) -> Calendar {
+        match *self {
+            Date::Gregorian(_) => Calendar::Gregorian,
+            Date::Julian(_) => Calendar::Julian,
+            Date::Roman(_) => Calendar::Roman,
+            Date::Coptic(_) => Calendar::Coptic,
+            Date::Ethiopic(_) => Calendar::Ethiopic,
+            Date::Iso(_) => Calendar::Iso,
+            Date::Islamic(_) => Calendar::Islamic,
+            Date::Hebrew(_) => Calendar::Hebrew,
+            Date::HinduSolar(_) => Calendar::HinduSolar,
+            Date::HinduLunisolar(_) => Calendar::HinduLunisolar,
+            Date::LongCount(_) => Calendar::LongCount,
+            Date::Haab(_) => Calendar::Haab,
+            Date::Tzolkin(_) => Calendar::Tzolkin,
+            Date::BaliPowukon(_) => Calendar::BaliPowukon
+        }
+    }
+
+    /// the field values in the same order as `Calendar::fields`
+    /// booleans are given as 0 or 1 and the roman event as 1 to 3 for kalends, nones and ides
+    pub fn field_values(&self) -> Vec<i32> {
+        match *self {
+            Date::Gregorian(Gregorian { year, month, day })
+                | Date::Julian(Julian { year, month, day })
+                | Date::Coptic(CopticEthiopic { year, month, day })
+                | Date::Ethiopic(CopticEthiopic { year, month, day })
+                | Date::Islamic(Islamic { year, month, day })
+                | Date::Hebrew(Hebrew { year, month, day })
+                | Date::HinduSolar(HinduSolar { year, month, day }) => vec![year, month, day],
+            Date::Roman(date) => vec![date.year, date.month, match date.event {
+                Event::Kalends => 1,
+                Event::Nones => 2,
+                Event::Ides => 3
+            }, date.count, date.leap as i32],
+            Date::Iso(date) => vec![date.year, date.week, date.day],
+            Date::HinduLunisolar(date) => vec![date.year, date.month, date.leap_month as i32, date.day],
+            Date::LongCount(date) => vec![date.baktun, date.katun, date.tun, date.uinal, date.kin],
+            Date::Haab(date) => vec![date.month, date.day],
+            Date::Tzolkin(date) => vec![date.number, date.name],
+            Date::BaliPowukon(date) => vec![
+                date.luang as i32, date.dwiwara, date.triwara, date.caturwara, date.pancawara,
+                date.sadwara, date.saptawara, date.asatawara, date.sangawara, date.dasawara
+            ]
+        }
+    }
+
+    pub fn field(&self, name: &str) -> Option<i32> {
+        self.calendar().fields().iter().position(|&field| field == name).map(|i| self.field_values()[i])
+    }
+
+    /// the R.D. date of the date or `None` for the cyclic calendars which need `on_or_before`
+    pub fn to_fixed(&self) -> Option<RD> {
+        match *self {
+            Date::Gregorian(date) => Some(gregorian::fixed_from_gregorian(date)),
+            Date::Julian(date) => Some(julian::fixed_from_julian(date)),
+            Date::Roman(date) => Some(julian::roman::fixed_from_roman(date)),
+            Date::Coptic(date) => Some(coptic_ethiopic::coptic::fixed_from_coptic(date)),
+            Date::Ethiopic(date) => Some(coptic_ethiopic::ethiopic::fixed_from_ethiopic(date)),
+            Date::Iso(date) => Some(iso::fixed_from_iso(date)),
+            Date::Islamic(date) => Some(islamic::fixed_from_islamic(date)),
+            Date::Hebrew(date) => Some(hebrew::fixed_from_hebrew(date)),
+            Date::HinduSolar(date) => Some(hindu::solar::fixed_from_hindu_solar(date)),
+            Date::HinduLunisolar(date) => Some(hindu::lunisolar::fixed_from_hindu_lunisolar(date)),
+            Date::LongCount(date) => Some(mayan::long_count::fixed_from_long_count(date)),
+            Date::Haab(_) | Date::Tzolkin(_) | Date::BaliPowukon(_) => None
+        }
+    }
+
+    /// the last R.D. date on or before `date` that falls on this date
+    pub fn on_or_before(&self, date: RD) -> RD {
+        match *self {
+            Date::Haab(haab) => mayan::haab::mayan_haab_on_or_before(haab, date),
+            Date::Tzolkin(tzolkin) => mayan::tzolkin::mayan_tzolkin_on_or_before(tzolkin, date),
+            Date::BaliPowukon(bali) => balinese_powukon::bali_on_or_before(bali, date),
+            _ => self.to_fixed().expect("Only cyclic calendars have no R.D. date")
+        }
+    }
+
+    /// the name of the value of a field, which for adar in a hebrew leap year is adar i
+    pub fn value_name(&self, field: &str) -> Option<&'static str> {
+        let value = self.field(field)?;
+        match *self {
+            Date::Hebrew(date) if field == "month" && value == 12 && hebrew::is_hebrew_leap_year(date.year) => Some("Adar I"),
+            _ => self.calendar().value_names(field).and_then(|names| name_from_value(names, value))
+        }
+    }
+
+    /// how many days the month of the date has in the calendars with a rule for it
+    pub fn days_in_month(&self) -> Option<i32> {
+        match *self {
+            Date::Gregorian(Gregorian { year, month, .. }) => Some(match month {
+                2 if gregorian::is_leap_year(year) => 29,
+                2 => 28,
+                4|6|9|11 => 30,
+                _ => 31
+            }),
+            Date::Julian(Julian { year, month, .. }) => Some(match month {
+                2 if julian::is_julian_leap_year(year) => 29,
+                2 => 28,
+                4|6|9|11 => 30,
+                _ => 31
+            }),
+            Date::Coptic(CopticEthiopic { year, month, .. }) => Some(match month {
+                13 if coptic_ethiopic::coptic::is_coptic_leap_year(year) => 6,
+                13 => 5,
+                _ => 30
+            }),
+            Date::Ethiopic(CopticEthiopic { year, month, .. }) => Some(match month {
+                13 if coptic_ethiopic::ethiopic::is_ethiopic_leap_year(year) => 6,
+                13 => 5,
+                _ => 30
+            }),
+            Date::Islamic(Islamic { year, month, .. }) => Some(match month {
+                12 if islamic::is_islamic_leap_year(year) => 30,
+                _ if month % 2 == 1 => 30,
+                _ => 29
+            }),
+            Date::Hebrew(Hebrew { year, month, .. }) => Some(hebrew::last_day_of_hebrew_month(month, year)),
+            Date::Haab(Haab { month, .. }) => Some(if month == 19 { 5 } else { 20 }),
+            _ => None
+        }
+    }
+
+    /// how many days the year of the date has in the calendars with a rule for it
+    pub fn days_in_year(&self) -> Option<i32> {
+        match *self {
+            Date::Gregorian(date) => Some(if gregorian::is_leap_year(date.year) { 366 } else { 365 }),
+            Date::Julian(date) => Some(if julian::is_julian_leap_year(date.year) { 366 } else { 365 }),
+            Date::Coptic(date) => Some(if coptic_ethiopic::coptic::is_coptic_leap_year(date.year) { 366 } else { 365 }),
+            Date::Ethiopic(date) => Some(if coptic_ethiopic::ethiopic::is_ethiopic_leap_year(date.year) { 366 } else { 365 }),
+            Date::Iso(date) => Some(if iso::is_iso_long_year(date.year) { 371 } else { 364 }),
+            Date::Islamic(date) => Some(if islamic::is_islamic_leap_year(date.year) { 355 } else { 354 }),
+            Date::Hebrew(date) => Some(hebrew::days_in_hebrew_year(date.year)),
+            _ => None
+        }
+    }
+
+    /// checks the date is one of its calendar, which is when converting it to R.D. and back gives it again
+    pub fn validate(&self) -> Result<(), DateError> {
+        let calendar = self.calendar();
+        let fields = calendar.fields();
+        let values = self.field_values();
+        if let Some((&field, _)) = fields.iter().zip(&values).find(|&(&field, value)| !calendar.field_range(field).contains(value)) {
+            return Err(field_error(field));
+        }
+
+        let round_trip = |date: Date| calendar.from_fixed(date.on_or_before(0));
+        if round_trip(*self) == *self {
+            return Ok(());
+        }
+
+        let mut values = values;
+        if let Some(day) = fields.iter().position(|&field| field == "day").filter(|_| fields.contains(&"month")) {
+            // when the first of the month is fine it has to be the day thats wrong
+            values[day] = 1;
+            let first = calendar.date_from_fields(&values).expect("Values come from a date of the calendar");
+            return Err(if round_trip(first) == first { DateError::InvalidDay } else { DateError::InvalidMonth });
+        }
+
+        // a field out of range carries over into the ones before it so the last one that changed is the culprit
+        let changed = round_trip(*self).field_values();
+        let field = fields.iter().zip(values.iter().zip(changed.iter())).rev()
+            .find(|&(_, (value, changed))| value != changed)
+            .map_or(fields[0], |(&field, _)| field);
+        Err(field_error(field))
+    }
+}
+
+fn field_error(field: &'static str) -> DateError {
+    match field {
+        "day" => DateError::InvalidDay,
+        "month" => DateError::InvalidMonth,
+        field => DateError::InvalidField(field)
+    }
+}
+
+/// written the way dates are usually said, like `7 Kislev 5706`, `1945-W46-1` or `12.16.11.16.9`
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let calendar = self.calendar();
+        match *self {
+            Date::LongCount(date) => write!(f, "{}.{}.{}.{}.{}", date.baktun, date.katun, date.tun, date.uinal, date.kin),
+            Date::Iso(date) => write!(f, "{}-W{:02}-{}", date.year, date.week, date.day),
+            _ => {
+                let (order, separator) = match calendar {
+                    Calendar::BaliPowukon => (calendar.fields(), ", "),
+                    _ => (calendar.written_order(), " ")
+                };
+                let mut first = true;
+                for &field in order {
+                    let value = self.field(field).expect("Written fields are fields of the calendar");
+                    if calendar.is_flag(field) && value == 0 {
+                        continue;
+                    }
+                    if !first {
+                        f.write_str(separator)?;
+                    }
+                    first = false;
+                    match (calendar, self.value_name(field)) {
+                        _ if calendar.is_flag(field) => f.write_str(field)?,
+                        (Calendar::BaliPowukon, _) => write!(f, "{} {}", field, value)?,
+                        (_, Some(name)) => f.write_str(name)?,
+                        (_, None) => write!(f, "{}", value)?
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! options are `--name value`, `--name=value` or a bare `--name` for flags and anything else,
+//! or anything after `--`, is positional
+
+use cli::Failure;
+
+pub struct Args {
+    pub positional: Vec<String>,
+    options: Vec<(String, Option<String>)>
+}
+
+impl Args {
+    /// `valued` are the options that take a value and `flags` the ones that dont, anything else is an error
+    pub fn parse(args: &[String], valued: &[&str], flags: &[&str]) -> Result<Args, Failure> {
+        let mut positional = Vec::new();
+        let mut options = Vec::new();
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            // everything after -- is positional
+            if arg == "--" {
+                positional.extend(args.by_ref().cloned());
+                break;
+            }
+            // a lone - or a negative number like an r.d. date before the epoch is positional
+            let name = match arg.strip_prefix("--") {
+                Some(name) => name,
+                None if arg == "-h" => "help",
+                None => {
+                    positional.push(arg.clone());
+                    continue;
+                }
+            };
+            let (name, value) = match name.find('=') {
+                Some(equals) => (&name[..equals], Some(name[equals + 1..].to_string())),
+                None => (name, None)
+            };
+            if valued.contains(&name) {
+                let value = match value {
+                    Some(value) => value,
+                    None => args.next().cloned().ok_or_else(|| Failure::usage(format!("--{} needs a value", name)))?
+                };
+                options.push((name.to_string(), Some(value)));
+            } else if flags.contains(&name) || name == "help" {
+                if value.is_some() {
+                    return Err(Failure::usage(format!("--{} doesnt take a value", name)));
+                }
+                options.push((name.to_string(), None));
+            } else {
+                return Err(Failure::usage(format!("unknown option --{}", name)));
+            }
+        }
+        Ok(Args { positional, options })
+    }
+
+    /// the value of the last time an option was given
+    pub fn value(&self, name: &str) -> Option<&str> {
+        self.options.iter().rev().find(|&(option, _)| option == name).and_then(|(_, value)| value.as_deref())
+    }
+
+    /// every value given for an option that can be given more than once
+    pub fn values(&self, name: &str) -> Vec<&str> {
+        self.options.iter().filter(|&(option, _)| option == name).filter_map(|(_, value)| value.as_deref()).collect()
+    }
+
+    pub fn flag(&self, name: &str) -> bool {
+        self.options.iter().any(|(option, _)| option == name)
+    }
+}
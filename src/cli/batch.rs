@@ -0,0 +1,116 @@
+//! `calecalc batch --input registers.csv --column 3 --from julian --to gregorian,hebrew`
+
+use calecalc::batch::Converter;
+use calecalc::calendar::Calendar;
+use cli::args::Args;
+use cli::csv::{self, Reader};
+use cli::date;
+use cli::Failure;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+
+const HELP: &str = "\
+usage: calecalc batch [--input FILE] --column COLUMN [--from CALENDAR] --to CALENDARS
+                      [--header] [--delimiter CHAR]
+
+reads csv from FILE (standard input by default) a row at a time and writes every row to standard
+output with the date in COLUMN, given in the calendar --from (gregorian by default), converted
+to each calendar of the comma separated list --to in columns added to the end
+
+COLUMN counts from 1, or is the name of a column when --header says the first row names the
+columns. rows whose date cant be read, or that arent utf-8, are listed on standard error with
+their line number and written with the added columns empty, empty dates are left empty without
+an error";
+
+fn io_failure(error: io::Error) -> Failure {
+    Failure::failed(error.to_string())
+}
+
+fn calendar(name: &str) -> Result<Calendar, Failure> {
+    Calendar::from_name(name).ok_or_else(|| Failure::usage(format!("unknown calendar {}", name)))
+}
+
+/// the index of the date column
+fn column_index(column: &str, header: Option<&[String]>) -> Result<usize, Failure> {
+    match (column.parse::<usize>(), header) {
+        (Ok(number), _) if number > 0 => Ok(number - 1),
+        (Ok(_), _) => Err(Failure::usage("columns count from 1")),
+        (Err(_), Some(header)) => header.iter().position(|name| name.trim() == column)
+            .ok_or_else(|| Failure::usage(format!("there is no column {} in the header", column))),
+        (Err(_), None) => Err(Failure::usage(format!("{} isnt a column number, use --header to name columns", column)))
+    }
+}
+
+pub fn run(args: &[String]) -> Result<(), Failure> {
+    let args = Args::parse(args, &["input", "column", "from", "to", "delimiter"], &["header"])?;
+    if args.flag("help") {
+        println!("{}", HELP);
+        return Ok(());
+    }
+    if !args.positional.is_empty() {
+        return Err(Failure::usage(format!("unexpected {}\n{}", args.positional.join(" "), HELP)));
+    }
+
+    let from = calendar(args.value("from").unwrap_or("gregorian"))?;
+    let targets = match args.value("to") {
+        Some(names) => names.split(',').map(calendar).collect::<Result<Vec<_>, _>>()?,
+        None => return Err(Failure::usage(format!("--to is needed\n{}", HELP)))
+    };
+    let column = args.value("column").ok_or_else(|| Failure::usage(format!("--column is needed\n{}", HELP)))?;
+    let mut delimiter = args.value("delimiter").unwrap_or(",").chars();
+    let delimiter = match (delimiter.next(), delimiter.next()) {
+        (Some(delimiter), None) if delimiter != '"' => delimiter,
+        _ => return Err(Failure::usage("the delimiter is one character other than a quote"))
+    };
+    let input: Box<dyn BufRead> = match args.value("input") {
+        None | Some("-") => Box::new(BufReader::new(io::stdin())),
+        Some(path) => Box::new(BufReader::new(File::open(path).map_err(|error| Failure::usage(format!("cant read {}: {}", path, error)))?))
+    };
+
+    let mut reader = Reader::new(input, delimiter);
+    let stdout = io::stdout();
+    let mut output = BufWriter::new(stdout.lock());
+    let mut fields = Vec::new();
+    let header = if args.flag("header") {
+        if reader.read_record(&mut fields).map_err(io_failure)?.is_some() {
+            fields.extend(targets.iter().map(|calendar| calendar.name().to_string()));
+            csv::write_record(&mut output, &fields, delimiter).map_err(io_failure)?;
+            fields.truncate(fields.len() - targets.len());
+        }
+        Some(fields.clone())
+    } else {
+        None
+    };
+    let index = column_index(column, header.as_deref())?;
+
+    // a converter per calendar so dates close to the last one reuse its month
+    let mut parsed = Converter::new(from);
+    let mut converters: Vec<Converter> = targets.iter().map(|&calendar| Converter::new(calendar)).collect();
+    let (mut rows, mut failed) = (0, 0);
+    while let Some(record) = reader.read_record(&mut fields).map_err(io_failure)? {
+        rows += 1;
+        let fixed = match fields.get(index).map(|text| text.trim()) {
+            _ if !record.utf8 => Err("not utf-8".to_string()),
+            Some("") => Ok(None),
+            Some(text) => date::parse(from, text).map_err(|failure| failure.message).and_then(|date| parsed.to_fixed(date)
+                .map(Some)
+                .ok_or_else(|| format!("{} dates repeat so cant be converted", from.name()))),
+            None => Err(format!("there is no column {}", index + 1))
+        };
+        let fixed = fixed.unwrap_or_else(|error| {
+            failed += 1;
+            eprintln!("line {}: {}", record.line, error);
+            None
+        });
+        for converter in &mut converters {
+            fields.push(fixed.map_or_else(String::new, |fixed| converter.from_fixed(fixed).to_string()));
+        }
+        csv::write_record(&mut output, &fields, delimiter).map_err(io_failure)?;
+    }
+    output.flush().map_err(io_failure)?;
+
+    if failed > 0 {
+        return Err(Failure::failed(format!("{} of {} rows couldnt be converted", failed, rows)));
+    }
+    Ok(())
+}
@@ -0,0 +1,203 @@
+//! `calecalc cal 2025-10 --overlay hebrew,islamic` and `calecalc cal --calendar hebrew 5786`
+
+use calecalc::calendar::{Calendar, WEEKDAY_NAMES, value_from_name};
+use calecalc::common::divide;
+use calecalc::arithmetical::iso::iso_from_fixed;
+use calecalc::holidays::{Tradition, TRADITIONS};
+use calecalc::render::{self, day_number, has_months, html, svg, Month, Page};
+use cli::args::Args;
+use cli::date;
+use cli::{today, Failure};
+
+const HELP: &str = "\
+usage: calecalc cal [--calendar CALENDAR] [YEAR | YEAR-MONTH | MONTH YEAR] [--overlay CALENDARS]
+                    [--week-start WEEKDAY] [--week-numbers] [--format text|html|svg]
+                    [--holidays TRADITIONS] [--moons]
+
+prints the month, or with only a year every month of the year, of a calendar with months
+(gregorian by default, or julian, coptic, ethiopic, islamic, hebrew or hindu_solar) and the
+current month when no date is given. months can be given by number or name
+
+--overlay shows the day of the month in other calendars under each day, --week-start picks the
+first day of the week (sunday by default) and --week-numbers adds the iso week of each row
+
+--format html or svg writes a printable page instead, which can also label the holidays of
+--holidays (a comma separated list of us, christian, orthodox, jewish and islamic, or all) and
+mark the new and full moons with --moons";
+
+/// how the weeks are laid out
+struct Layout {
+    week_start: i32,
+    week_numbers: bool,
+    overlays: Vec<Calendar>
+}
+
+impl Layout {
+    fn cell_width(&self) -> usize {
+        2 + 3 * self.overlays.len()
+    }
+
+    fn width(&self) -> usize {
+        7 * self.cell_width() + 6 + if self.week_numbers { 3 } else { 0 }
+    }
+}
+
+fn month(calendar: Calendar, year: i32, number: i32) -> Result<Month, Failure> {
+    render::month(calendar, year, number)
+        .map_err(|error| Failure::invalid_date(format!("there is no month {} in {} year {}, {}", number, calendar.name(), year, error)))
+}
+
+fn months_of_year(calendar: Calendar, year: i32) -> Result<Vec<Month>, Failure> {
+    render::months_of_year(calendar, year)
+        .map_err(|error| Failure::invalid_date(format!("there is no {} year {}, {}", calendar.name(), year, error)))
+}
+
+/// the lines of a month, all as wide as the layout
+fn lines(month: &Month, layout: &Layout) -> Vec<String> {
+    let width = layout.width();
+    let cell = layout.cell_width();
+    let mut lines = vec![format!("{:^width$}", month.title(), width = width)];
+
+    let mut header = if layout.week_numbers { "Wk ".to_string() } else { String::new() };
+    let names = (0..7).map(|i| {
+        let name = WEEKDAY_NAMES[divide(layout.week_start + i, 7).1 as usize].0;
+        format!("{:>cell$}", &name[..2], cell = cell)
+    });
+    header.push_str(&names.collect::<Vec<_>>().join(" "));
+    lines.push(header);
+
+    for row_start in month.rows(layout.week_start) {
+        let mut line = String::new();
+        if layout.week_numbers {
+            let monday = row_start + divide(1 - layout.week_start, 7).1;
+            if row_start < month.start + month.length {
+                line.push_str(&format!("{:>2} ", iso_from_fixed(monday).week));
+            } else {
+                line.push_str("   ");
+            }
+        }
+        let cells = (row_start..row_start + 7).map(|fixed| {
+            let day = fixed - month.start;
+            if day < 0 || day >= month.length {
+                return " ".repeat(cell);
+            }
+            let mut text = format!("{:>2}", day + 1);
+            for &overlay in &layout.overlays {
+                text.push_str(&format!(" {:>2}", day_number(overlay, fixed).expect("Overlays are checked to have a day")));
+            }
+            text
+        });
+        line.push_str(&cells.collect::<Vec<_>>().join(" "));
+        lines.push(format!("{:width$}", line, width = width));
+    }
+
+    for &overlay in &layout.overlays {
+        let first = overlay.from_fixed(month.start).to_string();
+        let last = overlay.from_fixed(month.start + month.length - 1).to_string();
+        lines.push(format!("{:width$}", format!("{}: {} to {}", overlay.name(), first, last), width = width));
+    }
+    lines
+}
+
+/// the month or year asked for, `None` for the month is a whole year
+fn month_and_year(calendar: Calendar, positional: &[String]) -> Result<(Option<i32>, i32), Failure> {
+    let expected = || Failure::usage("expected YEAR, YEAR-MONTH or MONTH YEAR");
+    let month_value = |token: &str| date::number(token)
+        .or_else(|| calendar.value_names("month").and_then(|names| value_from_name(names, token)))
+        .ok_or_else(|| Failure::usage(format!("{} isnt a month of the {} calendar", token, calendar.name())));
+    let year = |token: &str| token.parse::<i32>().map_err(|_| Failure::usage(format!("{} isnt a year", token)));
+
+    if positional.is_empty() {
+        let today = calendar.from_fixed(today());
+        return Ok((today.field("month"), today.field("year").expect("Grids are of calendars with years")));
+    }
+    let text = positional.join(" ");
+    let tokens = date::tokens(&text);
+    match tokens.as_slice() {
+        [only] => Ok((None, year(only)?)),
+        // year-month is only written with a dash
+        [first, second] if positional.len() == 1 && text.trim_start_matches('-').contains('-') => Ok((Some(month_value(second)?), year(first)?)),
+        [first, second] => Ok((Some(month_value(first)?), year(second)?)),
+        _ => Err(expected())
+    }
+}
+
+pub fn run(args: &[String]) -> Result<(), Failure> {
+    let args = Args::parse(args, &["calendar", "overlay", "week-start", "format", "holidays"], &["week-numbers", "moons"])?;
+    if args.flag("help") {
+        println!("{}", HELP);
+        return Ok(());
+    }
+
+    let name = args.value("calendar").unwrap_or("gregorian");
+    let calendar = Calendar::from_name(name).ok_or_else(|| Failure::usage(format!("unknown calendar {}", name)))?;
+    if !has_months(calendar) {
+        return Err(Failure::usage(format!("{} has no months to show", calendar.name())));
+    }
+    let overlays = match args.value("overlay") {
+        Some(names) => names.split(',').map(|name| match Calendar::from_name(name) {
+            Some(calendar) if calendar.fields().contains(&"day") || matches!(calendar, Calendar::Tzolkin | Calendar::LongCount) => Ok(calendar),
+            Some(calendar) => Err(Failure::usage(format!("{} has no day to show", calendar.name()))),
+            None => Err(Failure::usage(format!("unknown calendar {}", name)))
+        }).collect::<Result<Vec<_>, _>>()?,
+        None => Vec::new()
+    };
+    let week_start = match args.value("week-start") {
+        Some(name) => value_from_name(WEEKDAY_NAMES, name).ok_or_else(|| Failure::usage(format!("{} isnt a weekday", name)))?,
+        None => 0
+    };
+    let format = args.value("format").unwrap_or("text");
+    if !matches!(format, "text" | "html" | "svg") {
+        return Err(Failure::usage(format!("unknown format {}, expected text, html or svg", format)));
+    }
+    let traditions = match args.value("holidays") {
+        Some("all") => TRADITIONS.to_vec(),
+        Some(names) => names.split(',')
+            .map(|name| Tradition::from_name(name).ok_or_else(|| Failure::usage(format!("unknown tradition {}", name))))
+            .collect::<Result<Vec<_>, _>>()?,
+        None => Vec::new()
+    };
+    if format == "text" && (!traditions.is_empty() || args.flag("moons")) {
+        return Err(Failure::usage("--holidays and --moons need --format html or svg"));
+    }
+    let layout = Layout { week_start, week_numbers: args.flag("week-numbers"), overlays };
+
+    if format != "text" {
+        let page = match month_and_year(calendar, &args.positional)? {
+            (Some(number), year) => Page::month(calendar, year, number),
+            (None, year) => Page::year(calendar, year)
+        };
+        let mut page = page.map_err(|error| Failure::invalid_date(format!("there is no such {} month or year, {}", calendar.name(), error)))?
+            .secondary(&layout.overlays)
+            .holidays(&traditions)
+            .week_start(week_start);
+        if args.flag("moons") {
+            page = page.moons();
+        }
+        print!("{}", if format == "html" { html::render(&page) } else { svg::render(&page) });
+        return Ok(());
+    }
+
+    match month_and_year(calendar, &args.positional)? {
+        (Some(number), year) => {
+            for line in lines(&month(calendar, year, number)?, &layout) {
+                println!("{}", line.trim_end());
+            }
+        },
+        (None, year) => {
+            // three months across fit in a terminal, with overlays only one does
+            let across = if layout.overlays.is_empty() { 3 } else { 1 };
+            let months = months_of_year(calendar, year)?;
+            for (i, row) in months.chunks(across).enumerate() {
+                if i > 0 {
+                    println!();
+                }
+                let grids: Vec<Vec<String>> = row.iter().map(|month| lines(month, &layout)).collect();
+                for line in 0..grids[0].len() {
+                    println!("{}", grids.iter().map(|grid| grid[line].as_str()).collect::<Vec<_>>().join("   ").trim_end());
+                }
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,169 @@
+//! `calecalc convert --from hebrew 7 Kislev 5706 --to gregorian,islamic,mayan`
+
+use calecalc::calendar::{Calendar, ALL, FIXED_RANGE, WEEKDAY_NAMES, name_from_value, normalize_name};
+use calecalc::common::RD;
+use calecalc::common::cycles_of_days::day_of_week_from_fixed;
+use calecalc::arithmetical::julian_day::{fixed_from_jd, jd_from_fixed, fixed_from_mjd, mjd_from_fixed};
+use cli::args::Args;
+use cli::date;
+use cli::json::Json;
+use cli::Failure;
+
+const HELP: &str = "\
+usage: calecalc convert [--from CALENDAR] DATE [--to CALENDARS] [--format text|json] [--on-or-before RD]
+
+converts DATE, given in the calendar --from (gregorian by default), to every calendar in the
+comma separated list --to (all of them by default)
+
+calendars are gregorian, julian, roman, coptic, ethiopic, iso, islamic, hebrew, hindu_solar,
+hindu_lunisolar, long_count (or mayan), haab, tzolkin and bali, and dates can also be given or
+shown as rd, jd and mjd numbers. --to can also ask for the weekday
+
+dates are written like 12 November 1945, 1945-11-12, 7 Kislev 5706, 1945-W46-1 or 12.16.11.16.9.
+haab, tzolkin and bali dates repeat so they need --on-or-before to pick the R.D. date";
+
+/// something dates can be converted from or to
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Target {
+    Calendar(Calendar),
+    Fixed,
+    JulianDay,
+    ModifiedJulianDay,
+    Weekday
+}
+
+impl Target {
+    pub fn from_name(name: &str) -> Option<Target> {
+        match normalize_name(name).as_str() {
+            "rd" | "fixed" => Some(Target::Fixed),
+            "jd" => Some(Target::JulianDay),
+            "mjd" => Some(Target::ModifiedJulianDay),
+            "weekday" => Some(Target::Weekday),
+            _ => Calendar::from_name(name).map(Target::Calendar)
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Target::Calendar(calendar) => calendar.name(),
+            Target::Fixed => "rd",
+            Target::JulianDay => "jd",
+            Target::ModifiedJulianDay => "mjd",
+            Target::Weekday => "weekday"
+        }
+    }
+
+    pub fn text(self, fixed: RD) -> String {
+        match self {
+            Target::Calendar(calendar) => calendar.from_fixed(fixed).to_string(),
+            Target::Fixed => fixed.to_string(),
+            Target::JulianDay => jd_from_fixed(fixed).to_string(),
+            Target::ModifiedJulianDay => mjd_from_fixed(fixed).to_string(),
+            Target::Weekday => weekday(fixed).to_string()
+        }
+    }
+
+    fn json(self, fixed: RD) -> Json {
+        match self {
+            Target::Calendar(calendar) => date::to_json(calendar.from_fixed(fixed)),
+            Target::Fixed => fixed.into(),
+            Target::JulianDay => Json::Float(jd_from_fixed(fixed)),
+            Target::ModifiedJulianDay => mjd_from_fixed(fixed).into(),
+            Target::Weekday => weekday(fixed).into()
+        }
+    }
+}
+
+fn weekday(fixed: RD) -> &'static str {
+    name_from_value(WEEKDAY_NAMES, day_of_week_from_fixed(fixed)).expect("Every weekday has a name")
+}
+
+/// every calendar and number in the order they are shown by default
+fn all_targets() -> Vec<Target> {
+    let mut targets = vec![Target::Fixed, Target::JulianDay, Target::ModifiedJulianDay, Target::Weekday];
+    targets.extend(ALL.iter().map(|&calendar| Target::Calendar(calendar)));
+    targets
+}
+
+fn integer(text: &str, what: &str) -> Result<i32, Failure> {
+    text.trim().parse().map_err(|_| Failure::invalid_date(format!("{} isnt a whole number for {}", text.trim(), what)))
+}
+
+/// the R.D. date of the input, which has to be one every calendar can convert
+fn fixed_from_input(from: Target, text: &str, on_or_before: Option<RD>) -> Result<RD, Failure> {
+    let fixed = match from {
+        Target::Fixed => integer(text, "rd"),
+        // an mjd far enough out to overflow is outside the range anyway, and stays outside it when halved
+        Target::ModifiedJulianDay => integer(text, "mjd").map(|mjd| fixed_from_mjd(mjd.clamp(i32::MIN / 2, i32::MAX / 2))),
+        Target::JulianDay => text.trim().parse::<f64>().ok()
+            .filter(|jd| jd.is_finite() && jd.abs() < i32::MAX as f64)
+            .map(fixed_from_jd)
+            .ok_or_else(|| Failure::invalid_date(format!("{} isnt a number for jd", text.trim()))),
+        Target::Weekday => Err(Failure::usage("dates cant be converted from a weekday")),
+        Target::Calendar(calendar) => {
+            let date = date::parse(calendar, text)?;
+            match (date.to_fixed(), on_or_before) {
+                (Some(fixed), _) => Ok(fixed),
+                (None, Some(fixed)) => Ok(date.on_or_before(fixed)),
+                (None, None) => Err(Failure::usage(format!("{} dates repeat so --on-or-before is needed", calendar.name())))
+            }
+        }
+    }?;
+    if !FIXED_RANGE.contains(&fixed) {
+        return Err(Failure::invalid_date(format!("{} is outside R.D. {} to {}, the dates calecalc can convert", text.trim(), FIXED_RANGE.start(), FIXED_RANGE.end())));
+    }
+    Ok(fixed)
+}
+
+/// the targets named by a comma separated list, all of them for none or all
+pub fn targets(names: Option<&str>) -> Result<Vec<Target>, Failure> {
+    match names {
+        None | Some("all") => Ok(all_targets()),
+        Some(names) => names.split(',').map(|name| Target::from_name(name).ok_or_else(|| Failure::usage(format!("unknown calendar {}", name))))
+            .collect()
+    }
+}
+
+/// the R.D. date of a date given in `from`, a calendar or number name
+pub fn fixed_from_text(from: &str, text: &str, on_or_before: Option<&str>) -> Result<RD, Failure> {
+    let from = Target::from_name(from).ok_or_else(|| Failure::usage(format!("unknown calendar {}", from)))?;
+    let on_or_before = on_or_before.map(|fixed| integer(fixed, "--on-or-before")).transpose()?;
+    if let Some(fixed) = on_or_before.filter(|fixed| !FIXED_RANGE.contains(fixed)) {
+        return Err(Failure::invalid_date(format!("--on-or-before {} is outside R.D. {} to {}", fixed, FIXED_RANGE.start(), FIXED_RANGE.end())));
+    }
+    fixed_from_input(from, text, on_or_before)
+}
+
+/// a date in every target as a json object keyed by target name
+pub fn to_json(fixed: RD, targets: &[Target]) -> Json {
+    targets.iter().fold(Json::object(), |output, &target| output.with(target.name(), target.json(fixed)))
+}
+
+pub fn run(args: &[String]) -> Result<(), Failure> {
+    let args = Args::parse(args, &["from", "to", "format", "on-or-before"], &[])?;
+    if args.flag("help") {
+        println!("{}", HELP);
+        return Ok(());
+    }
+
+    let targets = targets(args.value("to"))?;
+    let json = match args.value("format") {
+        None | Some("text") => false,
+        Some("json") => true,
+        Some(format) => return Err(Failure::usage(format!("unknown format {}, expected text or json", format)))
+    };
+    if args.positional.is_empty() {
+        return Err(Failure::usage(format!("no date given\n{}", HELP)));
+    }
+
+    let fixed = fixed_from_text(args.value("from").unwrap_or("gregorian"), &args.positional.join(" "), args.value("on-or-before"))?;
+    if json {
+        println!("{}", to_json(fixed, &targets));
+    } else {
+        let width = targets.iter().map(|target| target.name().len()).max().unwrap_or(0);
+        for target in targets {
+            println!("{:width$}  {}", target.name(), target.text(fixed), width = width);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,83 @@
+//! just enough csv (rfc 4180) to stream records through the batch command one at a time
+
+use std::borrow::Cow;
+use std::io::{self, BufRead, Write};
+use std::mem;
+
+pub struct Reader<R> {
+    input: R,
+    delimiter: char,
+    line: usize,
+    buffer: Vec<u8>
+}
+
+/// where a record starts and whether all of it was utf-8, the bytes that werent are replaced
+/// with U+FFFD in its fields
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Record {
+    pub line: usize,
+    pub utf8: bool
+}
+
+impl<R: BufRead> Reader<R> {
+    pub fn new(input: R, delimiter: char) -> Reader<R> {
+        Reader { input, delimiter, line: 0, buffer: Vec::new() }
+    }
+
+    /// reads the next record into `fields`, `None` at the end of the input.
+    /// quoted fields can have delimiters, doubled quotes and line breaks in them
+    pub fn read_record(&mut self, fields: &mut Vec<String>) -> io::Result<Option<Record>> {
+        fields.clear();
+        self.buffer.clear();
+        if self.input.read_until(b'\n', &mut self.buffer)? == 0 {
+            return Ok(None);
+        }
+        self.line += 1;
+        let mut record = Record { line: self.line, utf8: true };
+        let mut field = String::new();
+        let mut quoted = false;
+        loop {
+            // quotes, delimiters and line breaks are ascii so the rest of a line that isnt utf-8 still splits
+            let text = String::from_utf8_lossy(&self.buffer);
+            record.utf8 &= matches!(text, Cow::Borrowed(_));
+            let mut chars = text.chars().peekable();
+            while let Some(c) = chars.next() {
+                match c {
+                    '"' if quoted && chars.peek() == Some(&'"') => {
+                        chars.next();
+                        field.push('"');
+                    },
+                    '"' if quoted => quoted = false,
+                    '"' if field.is_empty() => quoted = true,
+                    '\r' | '\n' if !quoted => {},
+                    c if c == self.delimiter && !quoted => fields.push(mem::take(&mut field)),
+                    c => field.push(c)
+                }
+            }
+            self.buffer.clear();
+            // an unclosed quote at the end of the input ends the field
+            if !quoted || self.input.read_until(b'\n', &mut self.buffer)? == 0 {
+                break;
+            }
+            self.line += 1;
+        }
+        fields.push(field);
+        Ok(Some(record))
+    }
+}
+
+/// writes a record, quoting the fields that need it
+pub fn write_record<W: Write, S: AsRef<str>>(output: &mut W, fields: &[S], delimiter: char) -> io::Result<()> {
+    for (i, field) in fields.iter().enumerate() {
+        let field = field.as_ref();
+        if i > 0 {
+            write!(output, "{}", delimiter)?;
+        }
+        if field.contains([delimiter, '"', '\n', '\r']) {
+            write!(output, "\"{}\"", field.replace('"', "\"\""))?;
+        } else {
+            output.write_all(field.as_bytes())?;
+        }
+    }
+    output.write_all(b"\n")
+}
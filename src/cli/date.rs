@@ -0,0 +1,106 @@
+//! reading dates written the way `Date` displays them
+//!
+//! names can go anywhere and the numbers fill the remaining fields in the order they are written
+//! in, and a date of only numbers is read in the order of the fields so `5706-9-7` works too
+
+use calecalc::calendar::{Calendar, Date, value_from_name, normalize_name};
+use cli::json::Json;
+use cli::Failure;
+
+/// the fields of a date and how its written
+pub fn to_json(date: Date) -> Json {
+    let calendar = date.calendar();
+    calendar.fields().iter().zip(date.field_values()).fold(Json::object().with("text", date.to_string().into()), |json, (&field, value)| {
+        if calendar.is_flag(field) {
+            json.with(field, (value != 0).into())
+        } else {
+            json.with(field, value.into())
+        }
+    })
+}
+
+/// splits at spaces, dots, slashes, commas and dashes except a dash that starts a number
+pub fn tokens(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut token = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        let negative = c == '-' && token.is_empty() && chars.peek().is_some_and(|next| next.is_ascii_digit());
+        if c.is_whitespace() || ".,/".contains(c) || (c == '-' && !negative) {
+            if !token.is_empty() {
+                tokens.push(token.clone());
+                token.clear();
+            }
+        } else {
+            token.push(c);
+        }
+    }
+    if !token.is_empty() {
+        tokens.push(token);
+    }
+    tokens
+}
+
+/// a number, or an iso week like W46
+pub fn number(token: &str) -> Option<i32> {
+    token.parse().ok().or_else(|| token.strip_prefix(['W', 'w']).and_then(|week| week.parse().ok()))
+}
+
+/// the field a name is a value of and the value
+fn named_value(calendar: Calendar, name: &str) -> Option<(&'static str, i32)> {
+    calendar.fields().iter()
+        .filter(|&&field| !calendar.is_flag(field))
+        .find_map(|&field| calendar.value_names(field).and_then(|names| value_from_name(names, name)).map(|value| (field, value)))
+}
+
+pub fn parse(calendar: Calendar, text: &str) -> Result<Date, Failure> {
+    let fields = calendar.fields();
+    let mut values: Vec<Option<i32>> = vec![None; fields.len()];
+    let index = |field: &str| fields.iter().position(|&candidate| candidate == field).expect("Names are of fields of the calendar");
+    let mut numbers = Vec::new();
+    let mut named = false;
+
+    let tokens = tokens(text);
+    let mut i = 0;
+    while i < tokens.len() {
+        if let Some(value) = number(&tokens[i]) {
+            numbers.push(value);
+            i += 1;
+            continue;
+        }
+        // names can be more than one word like "Dhu al-Hijja" so the longest run of words that is a name wins
+        let words = tokens[i..].iter().take_while(|token| number(token).is_none()).count();
+        let (length, field, value) = (1..=words).rev()
+            .find_map(|length| {
+                let name = tokens[i..i + length].concat();
+                let flag = fields.iter().find(|&&field| calendar.is_flag(field) && normalize_name(field) == normalize_name(&name));
+                flag.map(|&field| (length, field, 1)).or_else(|| named_value(calendar, &name).map(|(field, value)| (length, field, value)))
+            })
+            .ok_or_else(|| Failure::invalid_date(format!("{} isnt a name in the {} calendar", tokens[i], calendar.name())))?;
+        if values[index(field)].is_some() {
+            return Err(Failure::invalid_date(format!("{} is given twice", field)));
+        }
+        values[index(field)] = Some(value);
+        named = named || !calendar.is_flag(field);
+        i += length;
+    }
+
+    let order = if named { calendar.written_order() } else { fields };
+    let open: Vec<&str> = if !named && numbers.len() == fields.len() {
+        fields.to_vec()
+    } else {
+        order.iter().cloned().filter(|&field| values[index(field)].is_none() && !calendar.is_flag(field)).collect()
+    };
+    if numbers.len() != open.len() {
+        let expected = calendar.written_order().iter().filter(|&&field| !calendar.is_flag(field)).cloned().collect::<Vec<_>>().join(" ");
+        return Err(Failure::invalid_date(format!("expected {} for a {} date but got {}", expected, calendar.name(), text.trim())));
+    }
+    for (field, number) in open.into_iter().zip(numbers) {
+        values[index(field)] = Some(number);
+    }
+
+    let values: Vec<i32> = values.into_iter().map(|value| value.unwrap_or(0)).collect();
+    let date = calendar.date_from_fields(&values).ok_or_else(|| Failure::invalid_date(format!("{} isnt a {} date", text.trim(), calendar.name())))?;
+    date.validate().map_err(|error| Failure::invalid_date(format!("{} isnt a {} date, {}", text.trim(), calendar.name(), error)).reason(error))?;
+    Ok(date)
+}
@@ -0,0 +1,83 @@
+//! `calecalc expand feed.ics 2026 2030` lists the dates of the events of an icalendar file
+
+use calecalc::common::RD;
+use calecalc::arithmetical::gregorian::{fixed_from_gregorian, gregorian_from_fixed, Gregorian};
+use calecalc::ical::{read, VEvent};
+use cli::args::Args;
+use cli::json::Json;
+use cli::{today, Failure};
+use std::fs;
+use std::io::{self, Read};
+
+const HELP: &str = "\
+usage: calecalc expand FILE [YEAR [LAST_YEAR]] [--format text|json]
+
+lists the dates of every event of the icalendar FILE (- for standard input) in the gregorian
+years YEAR to LAST_YEAR, this year by default, as all day events
+
+recurrence rules can be in other calendars like rfc 7529 allows, with RSCALE=HEBREW,
+ISLAMIC-CIVIL, ETHIOPIC, COPTIC or JULIAN and SKIP=OMIT, BACKWARD or FORWARD for dates that
+arent in every year";
+
+fn iso_date(date: RD) -> String {
+    let date = gregorian_from_fixed(date);
+    format!("{}-{:02}-{:02}", date.year, date.month, date.day)
+}
+
+fn summary(event: &VEvent) -> &str {
+    event.summary.as_deref().or(event.uid.as_deref()).unwrap_or("")
+}
+
+pub fn run(args: &[String]) -> Result<(), Failure> {
+    let args = Args::parse(args, &["format"], &[])?;
+    if args.flag("help") {
+        println!("{}", HELP);
+        return Ok(());
+    }
+
+    let year = |text: &String| text.parse::<i32>().map_err(|_| Failure::usage(format!("{} isnt a year", text)));
+    let (path, first, last) = match args.positional.as_slice() {
+        [path] => {
+            let year = gregorian_from_fixed(today()).year;
+            (path, year, year)
+        },
+        [path, only] => (path, year(only)?, year(only)?),
+        [path, first, last] => (path, year(first)?, year(last)?),
+        _ => return Err(Failure::usage(format!("expected a file and a year or a first and last year\n{}", HELP)))
+    };
+    let json = match args.value("format") {
+        None | Some("text") => false,
+        Some("json") => true,
+        Some(format) => return Err(Failure::usage(format!("unknown format {}, expected text or json", format)))
+    };
+
+    let text = if path == "-" {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text).map(|_| text)
+    } else {
+        fs::read_to_string(path)
+    };
+    let text = text.map_err(|error| Failure::usage(format!("cant read {}: {}", path, error)))?;
+    let events = read(&text).map_err(|error| Failure::usage(format!("{}: {}", path, error)))?;
+
+    let start = fixed_from_gregorian(Gregorian { year: first, month: 1, day: 1 });
+    let end = fixed_from_gregorian(Gregorian { year: last, month: 12, day: 31 });
+    let mut dates: Vec<(RD, &VEvent)> = events.iter()
+        .flat_map(|event| event.occurrences_between(start, end).into_iter().map(move |date| (date, event)))
+        .collect();
+    dates.sort_by_key(|&(date, _)| date);
+
+    if json {
+        let dates = dates.iter().map(|&(date, event)| Json::object()
+            .with("date", iso_date(date).into())
+            .with("rd", date.into())
+            .with("summary", summary(event).into())
+            .with("uid", event.uid.clone().into()));
+        println!("{}", Json::Array(dates.collect()));
+    } else {
+        for &(date, event) in &dates {
+            println!("{}  {}", iso_date(date), summary(event));
+        }
+    }
+    Ok(())
+}
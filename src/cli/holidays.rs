@@ -0,0 +1,100 @@
+//! `calecalc holidays 2026 --traditions us,jewish,christian,orthodox`
+
+use calecalc::calendar::{Calendar, Date, WEEKDAY_NAMES, name_from_value, value_from_name, normalize_name};
+use calecalc::common::cycles_of_days::day_of_week_from_fixed;
+use calecalc::arithmetical::gregorian::{gregorian_from_fixed, Gregorian};
+use calecalc::holidays::{holidays_in_gregorian_year, Holiday, Tradition, TRADITIONS};
+use cli::args::Args;
+use cli::date;
+use cli::json::Json;
+use cli::{today, Failure};
+
+const HELP: &str = "\
+usage: calecalc holidays [YEAR] [--traditions TRADITIONS] [--name TEXT] [--month MONTH]
+                         [--format text|json]
+
+lists the holidays of a gregorian year (this year by default) sorted by date
+
+--traditions is a comma separated list of us, christian, orthodox, jewish and islamic (all of
+them by default), --name only keeps holidays with TEXT in their name and --month only the ones
+in a gregorian month given by number or name";
+
+fn weekday(holiday: &Holiday) -> &'static str {
+    name_from_value(WEEKDAY_NAMES, day_of_week_from_fixed(holiday.date)).expect("Every weekday has a name")
+}
+
+fn iso_date(holiday: &Holiday) -> String {
+    let date = gregorian_from_fixed(holiday.date);
+    format!("{}-{:02}-{:02}", date.year, date.month, date.day)
+}
+
+/// a gregorian year whose holidays can be worked out
+pub fn year(text: &str) -> Result<i32, Failure> {
+    let year = text.parse().map_err(|_| Failure::usage(format!("{} isnt a year", text)))?;
+    Date::Gregorian(Gregorian { year, month: 1, day: 1 }).validate()
+        .map_err(|error| Failure::invalid_date(format!("there is no gregorian year {}, {}", year, error)).reason(error))?;
+    Ok(year)
+}
+
+/// the traditions of a comma separated list, all of them for none or all
+pub fn traditions(names: Option<&str>) -> Result<Vec<Tradition>, Failure> {
+    match names {
+        None | Some("all") => Ok(TRADITIONS.to_vec()),
+        Some(names) => names.split(',')
+            .map(|name| Tradition::from_name(name).ok_or_else(|| Failure::usage(format!("unknown tradition {}", name))))
+            .collect()
+    }
+}
+
+pub fn to_json(holidays: &[Holiday]) -> Json {
+    Json::Array(holidays.iter().map(|holiday| Json::object()
+        .with("name", holiday.name.into())
+        .with("tradition", holiday.tradition.name().into())
+        .with("date", iso_date(holiday).into())
+        .with("rd", holiday.date.into())
+        .with("weekday", weekday(holiday).into())).collect())
+}
+
+pub fn run(args: &[String]) -> Result<(), Failure> {
+    let args = Args::parse(args, &["traditions", "name", "month", "format"], &[])?;
+    if args.flag("help") {
+        println!("{}", HELP);
+        return Ok(());
+    }
+
+    let year = match args.positional.as_slice() {
+        [] => gregorian_from_fixed(today()).year,
+        [text] => year(text)?,
+        _ => return Err(Failure::usage(format!("expected a year\n{}", HELP)))
+    };
+    let traditions = traditions(args.value("traditions"))?;
+    let month = match args.value("month") {
+        Some(month) => {
+            let names = Calendar::Gregorian.value_names("month").expect("Gregorian months have names");
+            let number = date::number(month).or_else(|| value_from_name(names, month)).filter(|month| (1..=12).contains(month));
+            Some(number.ok_or_else(|| Failure::usage(format!("{} isnt a month", month)))?)
+        },
+        None => None
+    };
+    let name = args.value("name").map(normalize_name);
+    let json = match args.value("format") {
+        None | Some("text") => false,
+        Some("json") => true,
+        Some(format) => return Err(Failure::usage(format!("unknown format {}, expected text or json", format)))
+    };
+
+    let holidays: Vec<Holiday> = holidays_in_gregorian_year(year, &traditions).into_iter()
+        .filter(|holiday| month.is_none_or(|month| gregorian_from_fixed(holiday.date).month == month))
+        .filter(|holiday| name.as_ref().is_none_or(|name| normalize_name(holiday.name).contains(name.as_str())))
+        .collect();
+
+    if json {
+        println!("{}", to_json(&holidays));
+    } else {
+        let width = holidays.iter().map(|holiday| holiday.name.len()).max().unwrap_or(0);
+        for holiday in &holidays {
+            println!("{}  {}  {:width$}  {}", iso_date(holiday), &weekday(holiday)[..3], holiday.name, holiday.tradition.name(), width = width);
+        }
+    }
+    Ok(())
+}
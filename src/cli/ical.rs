@@ -0,0 +1,76 @@
+//! `calecalc ical 2026 2030 --traditions jewish --yahrtzeit "7 Kislev 5706" > feed.ics`
+
+use calecalc::calendar::{Calendar, Date};
+use calecalc::holidays::{holidays_in_gregorian_year, Tradition, TRADITIONS};
+use calecalc::arithmetical::gregorian::gregorian_from_fixed;
+use calecalc::arithmetical::hebrew::yahrtzeit_in_gregorian;
+use calecalc::ical::{Event, Feed};
+use cli::args::Args;
+use cli::date;
+use cli::{today, Failure};
+
+const HELP: &str = "\
+usage: calecalc ical [YEAR [LAST_YEAR]] [--traditions TRADITIONS] [--yahrtzeit HEBREW_DATE]...
+                     [--alternate CALENDARS] [--name NAME] [--domain DOMAIN]
+
+writes an icalendar feed of all day events for the gregorian years YEAR to LAST_YEAR (this year
+by default) to standard output
+
+--traditions picks the holidays like for calecalc holidays, none when only yahrtzeits are asked
+for, --yahrtzeit adds the yahrtzeits of a date of death and --alternate adds the date in other
+calendars to the description of every event. --name is the name of the feed and --domain ends
+the uids, which stay the same every time the feed is written";
+
+pub fn run(args: &[String]) -> Result<(), Failure> {
+    let args = Args::parse(args, &["traditions", "yahrtzeit", "alternate", "name", "domain"], &[])?;
+    if args.flag("help") {
+        println!("{}", HELP);
+        return Ok(());
+    }
+
+    let year = |text: &String| text.parse::<i32>().map_err(|_| Failure::usage(format!("{} isnt a year", text)));
+    let (first, last) = match args.positional.as_slice() {
+        [] => {
+            let year = gregorian_from_fixed(today()).year;
+            (year, year)
+        },
+        [only] => (year(only)?, year(only)?),
+        [first, last] => (year(first)?, year(last)?),
+        _ => return Err(Failure::usage(format!("expected a year or a first and last year\n{}", HELP)))
+    };
+    let deaths = args.values("yahrtzeit").into_iter()
+        .map(|text| match date::parse(Calendar::Hebrew, text)? {
+            Date::Hebrew(death) => Ok(death),
+            _ => unreachable!("Hebrew dates are parsed as hebrew")
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let traditions = match args.value("traditions") {
+        None if !deaths.is_empty() => Vec::new(),
+        None | Some("all") => TRADITIONS.to_vec(),
+        Some(names) => names.split(',')
+            .map(|name| Tradition::from_name(name).ok_or_else(|| Failure::usage(format!("unknown tradition {}", name))))
+            .collect::<Result<Vec<_>, _>>()?
+    };
+    let alternates = match args.value("alternate") {
+        Some(names) => names.split(',')
+            .map(|name| Calendar::from_name(name).ok_or_else(|| Failure::usage(format!("unknown calendar {}", name))))
+            .collect::<Result<Vec<_>, _>>()?,
+        None => Vec::new()
+    };
+
+    let mut feed = Feed::new(args.value("name").unwrap_or("calecalc"), today()).alternates(&alternates);
+    if let Some(domain) = args.value("domain") {
+        feed = feed.domain(domain);
+    }
+    for year in first..=last {
+        feed.add_holidays(&holidays_in_gregorian_year(year, &traditions));
+        for &death in &deaths {
+            let death_date = Date::Hebrew(death);
+            for date in yahrtzeit_in_gregorian(death, year) {
+                feed.add(Event::new(date, "Yahrtzeit").key(format!("yahrtzeit {}", death_date)).description(format!("yahrtzeit of {}", death_date)));
+            }
+        }
+    }
+    print!("{}", feed);
+    Ok(())
+}
@@ -0,0 +1,111 @@
+//! the subcommands of the calecalc binary
+
+mod args;
+mod batch;
+mod cal;
+mod convert;
+mod csv;
+mod date;
+mod expand;
+mod holidays;
+mod ical;
+mod json;
+mod repl;
+mod serve;
+mod verify;
+
+use calecalc::calendar::DateError;
+use calecalc::common::RD;
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// exit codes, 1 is for commands that ran but found something wrong
+pub const EXIT_OK: i32 = 0;
+pub const EXIT_FAILED: i32 = 1;
+pub const EXIT_USAGE: i32 = 2;
+pub const EXIT_INVALID_DATE: i32 = 3;
+
+const USAGE: &str = "\
+usage: calecalc <command> [options]
+
+commands:
+    batch     converts a column of dates in a csv file, see calecalc batch --help
+    cal       prints a month or year, see calecalc cal --help
+    convert   converts a date between calendars, see calecalc convert --help
+    expand    lists the dates of the events of an icalendar file, see calecalc expand --help
+    holidays  lists the holidays of a year, see calecalc holidays --help
+    ical      writes an icalendar feed of holidays and yahrtzeits, see calecalc ical --help
+    repl      reads commands that move a date around and shows it, see calecalc repl --help
+    serve     answers conversions as json over http, see calecalc serve --help
+    verify    checks every calendar against the sample dates
+
+exit codes: 0 ok, 1 a check failed, 2 bad arguments, 3 invalid date";
+
+/// why a command stopped, printed to stderr with its exit code
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Failure {
+    pub code: i32,
+    pub message: String,
+    /// why the calendar rejected a date, for dates that were read but arent in the calendar
+    pub reason: Option<DateError>
+}
+
+impl Failure {
+    pub fn failed<S: Into<String>>(message: S) -> Failure {
+        Failure { code: EXIT_FAILED, message: message.into(), reason: None }
+    }
+
+    pub fn usage<S: Into<String>>(message: S) -> Failure {
+        Failure { code: EXIT_USAGE, message: message.into(), reason: None }
+    }
+
+    pub fn invalid_date<S: Into<String>>(message: S) -> Failure {
+        Failure { code: EXIT_INVALID_DATE, message: message.into(), reason: None }
+    }
+
+    pub fn reason(mut self, reason: DateError) -> Failure {
+        self.reason = Some(reason);
+        self
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "calecalc: {}", self.message)
+    }
+}
+
+/// the R.D. date of today in utc
+pub fn today() -> RD {
+    // 1970-01-01 is R.D. 719163
+    let seconds = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.as_secs());
+    719163 + (seconds / 86400) as RD
+}
+
+/// runs the command the arguments name and returns the exit code
+pub fn run(args: &[String]) -> i32 {
+    let result = match args.first().map(String::as_str) {
+        Some("batch") => batch::run(&args[1..]),
+        Some("cal") => cal::run(&args[1..]),
+        Some("convert") => convert::run(&args[1..]),
+        Some("expand") => expand::run(&args[1..]),
+        Some("holidays") => holidays::run(&args[1..]),
+        Some("ical") => ical::run(&args[1..]),
+        Some("repl") => repl::run(&args[1..]),
+        Some("serve") => serve::run(&args[1..]),
+        Some("verify") => verify::run(&args[1..]),
+        Some("help") | Some("--help") | Some("-h") => {
+            println!("{}", USAGE);
+            Ok(())
+        },
+        Some(command) => Err(Failure::usage(format!("unknown command {}\n{}", command, USAGE))),
+        None => Err(Failure::usage(USAGE))
+    };
+    match result {
+        Ok(()) => EXIT_OK,
+        Err(failure) => {
+            eprintln!("{}", failure);
+            failure.code
+        }
+    }
+}
@@ -0,0 +1,273 @@
+//! `calecalc repl` reads commands a line at a time, each one moving the current date or changing
+//! how it is shown, and prints the date in every calendar asked for after each step
+
+use calecalc::calendar::{Calendar, FIXED_RANGE, WEEKDAY_NAMES, value_from_name, name_from_value, normalize_name};
+use calecalc::common::{floor, Moment, RD};
+use calecalc::common::cycles_of_days::{kday_after, kday_before, nth_kday};
+use calecalc::common::cycles_of_days::day_of_week_from_fixed;
+use calecalc::arithmetical::gregorian::{fixed_from_gregorian, gregorian_from_fixed, Gregorian};
+use calecalc::arithmetical::hebrew::{hebrew_from_fixed, molad};
+use calecalc::astronomical::lunar::molad_and_conjunction;
+use calecalc::holidays::{holidays_in_gregorian_year, TRADITIONS};
+use cli::args::Args;
+use cli::convert::{self, Target};
+use cli::{today, Failure};
+use std::io::{self, BufRead, IsTerminal, Write};
+
+const HELP: &str = "\
+usage: calecalc repl [--to CALENDARS]
+
+reads commands from standard input and prints the date in the calendars of --to (rd, weekday,
+gregorian, julian, hebrew and islamic by default) after each one";
+
+const COMMANDS: &str = "\
+    CALENDAR DATE          sets the date, like julian 1752-09-02, hebrew 7 Kislev 5706 or rd 639797,
+                           dates that repeat like tzolkin ones are the last one on or before the date
+    DATE                   sets a gregorian date
+    today                  sets the date to today
+    + N, - N               moves N days, or N weeks with + N weeks
+    next WEEKDAY           the next weekday after the date, previous WEEKDAY the last one before it
+    next HOLIDAY           the next holiday with HOLIDAY in its name like next easter, or previous
+    nth N WEEKDAY          the nth weekday of the gregorian month, from its end when N is negative
+    molad                  the molad of the hebrew month of the date and the true new moon nearest it
+    as CALENDARS           the calendars to show, or as all
+    let NAME [= COMMAND]   names the date, or the date after the command
+    NAME, $N               goes back to a named date or to the date of step N
+    since NAME             the days from a named date to the date
+    history, vars          lists the dates of every step or the named dates
+    help, quit";
+
+/// how far holidays are looked for, birkath hahama only comes every 28 years
+const HOLIDAY_YEARS: i32 = 30;
+
+const DEFAULT_TARGETS: &str = "rd,weekday,gregorian,julian,hebrew,islamic";
+
+enum Outcome {
+    /// a new current date
+    Date(RD),
+    /// the current date again, in other calendars
+    Show,
+    Print(String),
+    Nothing,
+    Quit
+}
+
+struct Session {
+    date: Option<RD>,
+    shown: Vec<Target>,
+    history: Vec<RD>,
+    variables: Vec<(String, RD)>
+}
+
+fn weekday(name: &str) -> Option<i32> {
+    value_from_name(WEEKDAY_NAMES, name)
+}
+
+fn count(text: &str) -> Result<i32, Failure> {
+    text.trim_start_matches('+').parse().map_err(|_| Failure::usage(format!("{} isnt a whole number", text)))
+}
+
+/// variable names start with a letter and arent commands, calendars or weekdays
+fn is_variable_name(name: &str) -> bool {
+    let commands = ["today", "next", "previous", "prev", "nth", "molad", "as", "let", "since", "history", "vars", "help", "quit", "exit"];
+    name.starts_with(|c: char| c.is_alphabetic())
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_')
+        && !commands.contains(&name)
+        && Target::from_name(name).is_none()
+        && weekday(name).is_none()
+}
+
+/// the weekday and gregorian date of a day
+fn day_text(date: RD) -> String {
+    let weekday = name_from_value(WEEKDAY_NAMES, day_of_week_from_fixed(date)).expect("Every weekday has a name");
+    format!("{}, {}", weekday, Calendar::Gregorian.from_fixed(date))
+}
+
+/// a moment to the minute
+fn moment_text(moment: Moment) -> String {
+    let minutes = floor((moment - floor(moment)) * 1440.0 + 0.5) as i32;
+    let (date, minutes) = if minutes == 1440 { (floor(moment) as RD + 1, 0) } else { (floor(moment) as RD, minutes) };
+    format!("{} {:02}:{:02}", day_text(date), minutes / 60, minutes % 60)
+}
+
+impl Session {
+    fn date(&self) -> Result<RD, Failure> {
+        self.date.ok_or_else(|| Failure::usage("there is no date yet, give one like gregorian 2026-10-19 or today"))
+    }
+
+    /// the date moved by `days`, if that is still a date calecalc can convert
+    fn moved(&self, days: Option<i32>) -> Result<RD, Failure> {
+        let date = self.date()?;
+        days.and_then(|days| date.checked_add(days)).filter(|fixed| FIXED_RANGE.contains(fixed))
+            .ok_or_else(|| Failure::invalid_date(format!("that is outside R.D. {} to {}, the dates calecalc can convert", FIXED_RANGE.start(), FIXED_RANGE.end())))
+    }
+
+    /// a named date or the date of a step
+    fn recall(&self, name: &str) -> Option<RD> {
+        match name.strip_prefix('$') {
+            Some(step) => step.parse::<usize>().ok().and_then(|step| step.checked_sub(1)).and_then(|step| self.history.get(step).cloned()),
+            None => self.variables.iter().find(|variable| variable.0 == name).map(|variable| variable.1)
+        }
+    }
+
+    /// the closest holiday after or before the date whose name has `name` in it
+    fn holiday(&self, name: &str, forward: bool) -> Result<RD, Failure> {
+        let date = self.date()?;
+        let wanted = normalize_name(name);
+        let year = gregorian_from_fixed(date).year;
+        for offset in 0..HOLIDAY_YEARS {
+            let holidays = holidays_in_gregorian_year(if forward { year + offset } else { year - offset }, &TRADITIONS);
+            let mut matching = holidays.iter().filter(|holiday| normalize_name(holiday.name).contains(wanted.as_str())).map(|holiday| holiday.date);
+            let found = if forward { matching.find(|&holiday| holiday > date) } else { matching.rfind(|&holiday| holiday < date) };
+            if let Some(found) = found {
+                return Ok(found);
+            }
+        }
+        Err(Failure::usage(format!("{} isnt a weekday or a holiday", name)))
+    }
+
+    fn nth(&self, n: i32, weekday: i32) -> Result<RD, Failure> {
+        let date = gregorian_from_fixed(self.date()?);
+        let first = fixed_from_gregorian(Gregorian { day: 1, ..date });
+        let next_month = if date.month == 12 { Gregorian { year: date.year + 1, month: 1, day: 1 } } else { Gregorian { month: date.month + 1, day: 1, ..date } };
+        let after = fixed_from_gregorian(next_month);
+        let found = match n {
+            0 => return Err(Failure::usage("nth counts from 1, or from -1 at the end of the month")),
+            n if n > 0 => nth_kday(first - 1, n, weekday),
+            n => nth_kday(after, n, weekday)
+        };
+        if found < first || found >= after {
+            return Err(Failure::usage(format!("there is no {} {} in the month", n, WEEKDAY_NAMES[weekday as usize].0)));
+        }
+        Ok(found)
+    }
+
+    fn eval(&mut self, line: &str) -> Result<Outcome, Failure> {
+        let words: Vec<&str> = line.split_whitespace().collect();
+        let rest = |from: usize| words[from..].join(" ");
+        match words.as_slice() {
+            [] => Ok(Outcome::Nothing),
+            ["quit"] | ["exit"] => Ok(Outcome::Quit),
+            ["help"] => Ok(Outcome::Print(COMMANDS.to_string())),
+            ["today"] => Ok(Outcome::Date(today())),
+            ["history"] => Ok(Outcome::Print(self.history.iter().enumerate()
+                .map(|(i, &date)| format!("${}  {}", i + 1, self.shown.iter().map(|target| target.text(date)).collect::<Vec<_>>().join("  ")))
+                .collect::<Vec<_>>().join("\n"))),
+            ["vars"] => Ok(Outcome::Print(self.variables.iter()
+                .map(|(name, date)| format!("{}  {}", name, self.shown.iter().map(|target| target.text(*date)).collect::<Vec<_>>().join("  ")))
+                .collect::<Vec<_>>().join("\n"))),
+            ["as", ..] if words.len() > 1 => {
+                self.shown = convert::targets(Some(&words[1..].join(",")))?;
+                Ok(if self.date.is_some() { Outcome::Show } else { Outcome::Nothing })
+            },
+            [sign @ ("+" | "-"), n, unit @ ..] => {
+                let days = match unit {
+                    [] | ["day"] | ["days"] => 1,
+                    ["week"] | ["weeks"] => 7,
+                    _ => return Err(Failure::usage(format!("{} isnt days or weeks", rest(2))))
+                };
+                let n = count(n)?.checked_mul(days).and_then(|n| if *sign == "+" { Some(n) } else { n.checked_neg() });
+                Ok(Outcome::Date(self.moved(n)?))
+            },
+            [moved] if moved.len() > 1 && (moved.starts_with('+') || moved.starts_with('-')) && moved[1..].parse::<i32>().is_ok() => {
+                Ok(Outcome::Date(self.moved(Some(count(moved)?))?))
+            },
+            [direction @ ("next" | "previous" | "prev"), ..] if words.len() > 1 => {
+                let forward = *direction == "next";
+                match (words.len(), weekday(words[1])) {
+                    (2, Some(weekday)) => Ok(Outcome::Date(if forward { kday_after(self.date()?, weekday) } else { kday_before(self.date()?, weekday) })),
+                    _ => Ok(Outcome::Date(self.holiday(&rest(1), forward)?))
+                }
+            },
+            ["nth", n, name] => {
+                let weekday = weekday(name).ok_or_else(|| Failure::usage(format!("{} isnt a weekday", name)))?;
+                Ok(Outcome::Date(self.nth(count(n)?, weekday)?))
+            },
+            ["molad"] => {
+                let hebrew = hebrew_from_fixed(self.date()?);
+                let mean = molad(hebrew.month, hebrew.year);
+                let (_, conjunction) = molad_and_conjunction(hebrew.month, hebrew.year);
+                let month = Calendar::Hebrew.from_fixed(self.date()?).value_name("month").expect("Hebrew months have names");
+                Ok(Outcome::Print(format!("molad of {} {}  {} {:02}:{:02} and {} chalakim, jerusalem mean time\nconjunction  {} universal time",
+                    month, hebrew.year, day_text(mean.date), mean.hour, mean.minute, mean.chalakim, moment_text(conjunction))))
+            },
+            ["since", name] => {
+                let other = self.recall(name).ok_or_else(|| Failure::usage(format!("there is no date called {}", name)))?;
+                let days = self.date()? - other;
+                Ok(Outcome::Print(format!("{} day{} since {}", days, if days.abs() == 1 { "" } else { "s" }, name)))
+            },
+            ["let", name, ..] => {
+                if !is_variable_name(name) {
+                    return Err(Failure::usage(format!("{} cant be a name, names start with a letter and arent commands or calendars", name)));
+                }
+                let date = match &words[2..] {
+                    [] => self.date()?,
+                    ["=", ..] if words.len() > 3 => match self.eval(&rest(3))? {
+                        Outcome::Date(date) => date,
+                        _ => return Err(Failure::usage(format!("{} isnt a date", rest(3))))
+                    },
+                    _ => return Err(Failure::usage("expected let NAME or let NAME = COMMAND"))
+                };
+                self.variables.retain(|variable| variable.0 != *name);
+                self.variables.push((name.to_string(), date));
+                Ok(Outcome::Date(date))
+            },
+            [name] if self.recall(name).is_some() => Ok(Outcome::Date(self.recall(name).expect("The name was just found"))),
+            [calendar, ..] if words.len() > 1 && Target::from_name(calendar).is_some() => {
+                let on_or_before = self.date.unwrap_or_else(today).to_string();
+                Ok(Outcome::Date(convert::fixed_from_text(calendar, &rest(1), Some(&on_or_before))?))
+            },
+            _ => Ok(Outcome::Date(convert::fixed_from_text("gregorian", line, None)?))
+        }
+    }
+
+    fn show(&self) {
+        let date = self.date.expect("Only dates are shown");
+        let width = self.shown.iter().map(|target| target.name().len()).max().unwrap_or(0);
+        for target in &self.shown {
+            println!("  {:width$}  {}", target.name(), target.text(date), width = width);
+        }
+    }
+}
+
+pub fn run(args: &[String]) -> Result<(), Failure> {
+    let args = Args::parse(args, &["to"], &[])?;
+    if args.flag("help") {
+        println!("{}\n\ncommands:\n{}", HELP, COMMANDS);
+        return Ok(());
+    }
+
+    let mut session = Session {
+        date: None,
+        shown: convert::targets(Some(args.value("to").unwrap_or(DEFAULT_TARGETS)))?,
+        history: Vec::new(),
+        variables: Vec::new()
+    };
+    let stdin = io::stdin();
+    // the prompt would only get in the way of input piped in
+    let interactive = stdin.is_terminal();
+    let mut line = String::new();
+    loop {
+        if interactive {
+            print!("calecalc> ");
+            io::stdout().flush().map_err(|error| Failure::failed(error.to_string()))?;
+        }
+        line.clear();
+        if stdin.lock().read_line(&mut line).map_err(|error| Failure::failed(error.to_string()))? == 0 {
+            return Ok(());
+        }
+        match session.eval(line.trim()) {
+            Ok(Outcome::Date(date)) => {
+                session.date = Some(date);
+                session.history.push(date);
+                println!("${}", session.history.len());
+                session.show();
+            },
+            Ok(Outcome::Show) => session.show(),
+            Ok(Outcome::Print(text)) => println!("{}", text),
+            Ok(Outcome::Nothing) => {},
+            Ok(Outcome::Quit) => return Ok(()),
+            Err(failure) => eprintln!("{}", failure)
+        }
+    }
+}
@@ -0,0 +1,203 @@
+//! `calecalc serve --port 8080` answers conversions with json over http
+//!
+//! `GET /convert?from=hebrew&date=7+Kislev+5706&to=gregorian,islamic` takes the same calendars and
+//! dates as calecalc convert, `GET /holidays?year=2026&traditions=jewish` the same traditions as
+//! calecalc holidays and `GET /today?to=hebrew` converts today. every connection gets one response
+
+use calecalc::calendar::DateError;
+use calecalc::arithmetical::gregorian::gregorian_from_fixed;
+use calecalc::holidays::holidays_in_gregorian_year;
+use cli::args::Args;
+use cli::json::Json;
+use cli::{convert, holidays, today, Failure, EXIT_INVALID_DATE};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::thread;
+use std::time::Duration;
+
+const HELP: &str = "\
+usage: calecalc serve [--port PORT] [--host HOST]
+
+answers http requests on HOST (127.0.0.1 by default) and PORT (8080 by default, 0 picks a free
+one) with json until it is stopped, printing the address it listens on first
+
+    GET /convert?from=CALENDAR&date=DATE&to=CALENDARS&on-or-before=RD
+    GET /holidays?year=YEAR&traditions=TRADITIONS
+    GET /today?to=CALENDARS
+
+the parameters work like the options of calecalc convert and calecalc holidays. errors are json
+too, 400 for a bad request and 422 for a date that isnt in its calendar";
+
+/// the longest request line and headers read, far more than any of the queries need
+const MAX_HEAD: u64 = 16 * 1024;
+
+struct Response {
+    status: u16,
+    body: Json
+}
+
+/// the object under "error" in the body of an error
+fn error(status: u16, code: &str, message: &str) -> Json {
+    Json::object().with("status", (status as i32).into()).with("code", code.into()).with("message", message.into())
+}
+
+impl Response {
+    fn ok(body: Json) -> Response {
+        Response { status: 200, body }
+    }
+
+    fn error(status: u16, code: &str, message: &str) -> Response {
+        Response { status, body: Json::object().with("error", error(status, code, message)) }
+    }
+}
+
+/// usage failures are bad requests and dates that arent in their calendar say why
+impl From<Failure> for Response {
+    fn from(failure: Failure) -> Response {
+        if failure.code != EXIT_INVALID_DATE {
+            return Response::error(400, "bad_request", &failure.message);
+        }
+        let body = error(422, "invalid_date", &failure.message);
+        let body = match failure.reason {
+            Some(DateError::InvalidMonth) => body.with("reason", "invalid_month".into()),
+            Some(DateError::InvalidDay) => body.with("reason", "invalid_day".into()),
+            Some(DateError::InvalidField(field)) => body.with("reason", "invalid_field".into()).with("field", field.into()),
+            None => body
+        };
+        Response { status: 422, body: Json::object().with("error", body) }
+    }
+}
+
+fn status_text(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        422 => "Unprocessable Entity",
+        _ => "Internal Server Error"
+    }
+}
+
+/// decodes %XX escapes and + for spaces
+fn percent_decode(text: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.bytes();
+    while let Some(byte) = rest.next() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex = [rest.next()?, rest.next()?];
+                bytes.push(u8::from_str_radix(std::str::from_utf8(&hex).ok()?, 16).ok()?);
+            },
+            byte => bytes.push(byte)
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+/// the parameters of a query string, a parameter given twice is the last one
+struct Query(Vec<(String, String)>);
+
+impl Query {
+    fn parse(text: &str) -> Result<Query, Failure> {
+        let invalid = || Failure::usage(format!("{} isnt a valid query", text));
+        text.split('&').filter(|pair| !pair.is_empty()).map(|pair| {
+            let (name, value) = pair.split_once('=').unwrap_or((pair, ""));
+            Ok((percent_decode(name).ok_or_else(invalid)?, percent_decode(value).ok_or_else(invalid)?))
+        }).collect::<Result<_, _>>().map(Query)
+    }
+
+    fn get(&self, name: &str) -> Option<&str> {
+        self.0.iter().rev().find(|pair| pair.0 == name).map(|pair| pair.1.as_str())
+    }
+}
+
+fn route(path: &str, query: &Query) -> Result<Response, Failure> {
+    match path {
+        "/convert" => {
+            let date = query.get("date").ok_or_else(|| Failure::usage("date is needed"))?;
+            let fixed = convert::fixed_from_text(query.get("from").unwrap_or("gregorian"), date, query.get("on-or-before"))?;
+            Ok(Response::ok(convert::to_json(fixed, &convert::targets(query.get("to"))?)))
+        },
+        "/holidays" => {
+            let year = match query.get("year") {
+                Some(year) => holidays::year(year)?,
+                None => gregorian_from_fixed(today()).year
+            };
+            let traditions = holidays::traditions(query.get("traditions"))?;
+            Ok(Response::ok(holidays::to_json(&holidays_in_gregorian_year(year, &traditions))))
+        },
+        "/today" => Ok(Response::ok(convert::to_json(today(), &convert::targets(query.get("to"))?))),
+        _ => Ok(Response::error(404, "not_found", &format!("there is nothing at {}", path)))
+    }
+}
+
+/// the response to a request line like `GET /today?to=hebrew HTTP/1.1`
+fn respond(request_line: &str) -> Response {
+    let mut parts = request_line.split_whitespace();
+    let (method, target) = match (parts.next(), parts.next(), parts.next()) {
+        (Some(method), Some(target), Some(version)) if version.starts_with("HTTP/") => (method, target),
+        _ => return Response::error(400, "bad_request", "the request line isnt http")
+    };
+    if method != "GET" && method != "HEAD" {
+        return Response::error(405, "method_not_allowed", &format!("{} isnt allowed, only GET", method));
+    }
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    Query::parse(query).and_then(|query| route(path, &query)).unwrap_or_else(Response::from)
+}
+
+fn handle(stream: TcpStream) -> io::Result<()> {
+    stream.set_read_timeout(Some(Duration::from_secs(10)))?;
+    let mut reader = BufReader::new((&stream).take(MAX_HEAD));
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // the headers dont change the response but are read so the client sees its request was taken
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 0 && !header.trim().is_empty() {
+        header.clear();
+    }
+
+    let response = respond(&request_line);
+    let body = response.body.to_string();
+    let mut stream = &stream;
+    write!(stream, "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+        response.status, status_text(response.status), body.len())?;
+    if !request_line.starts_with("HEAD ") {
+        stream.write_all(body.as_bytes())?;
+    }
+    stream.flush()
+}
+
+pub fn run(args: &[String]) -> Result<(), Failure> {
+    let args = Args::parse(args, &["port", "host"], &[])?;
+    if args.flag("help") {
+        println!("{}", HELP);
+        return Ok(());
+    }
+    if !args.positional.is_empty() {
+        return Err(Failure::usage(format!("unexpected {}\n{}", args.positional.join(" "), HELP)));
+    }
+
+    let port = args.value("port").unwrap_or("8080");
+    let port: u16 = port.parse().map_err(|_| Failure::usage(format!("{} isnt a port", port)))?;
+    let host = args.value("host").unwrap_or("127.0.0.1");
+    let listener = TcpListener::bind((host, port)).map_err(|error| Failure::failed(format!("cant listen on {}:{}: {}", host, port, error)))?;
+    let address = listener.local_addr().map_err(|error| Failure::failed(error.to_string()))?;
+    println!("listening on http://{}", address);
+    io::stdout().flush().map_err(|error| Failure::failed(error.to_string()))?;
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                thread::spawn(move || {
+                    if let Err(error) = handle(stream) {
+                        eprintln!("calecalc: {}", error);
+                    }
+                });
+            },
+            Err(error) => eprintln!("calecalc: {}", error)
+        }
+    }
+    Ok(())
+}
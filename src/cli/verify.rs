@@ -0,0 +1,41 @@
+//! `calecalc verify` checks every calendar against the sample dates
+
+use calecalc::calendar::ALL;
+use calecalc::conformance;
+use cli::args::Args;
+use cli::Failure;
+
+const HELP: &str = "\
+usage: calecalc verify
+
+converts the sample dates to and from every calendar and lists what doesnt match";
+
+pub fn run(args: &[String]) -> Result<(), Failure> {
+    let args = Args::parse(args, &[], &[])?;
+    if args.flag("help") {
+        println!("{}", HELP);
+        return Ok(());
+    }
+    if !args.positional.is_empty() {
+        return Err(Failure::usage(format!("unexpected {}\n{}", args.positional.join(" "), HELP)));
+    }
+
+    let report = conformance::verify();
+    for &calendar in ALL.iter() {
+        let mismatches = report.mismatches_in(calendar);
+        if mismatches.is_empty() {
+            println!("{}: ok", calendar.name());
+        } else {
+            println!("{}: {} mismatches", calendar.name(), mismatches.len());
+            for mismatch in mismatches {
+                println!("    {}", mismatch);
+            }
+        }
+    }
+    if report.is_ok() {
+        println!("all {} checks passed", report.checked);
+        Ok(())
+    } else {
+        Err(Failure::failed(format!("{} mismatches in {} checks", report.mismatches.len(), report.checked)))
+    }
+}
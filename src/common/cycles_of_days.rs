@@ -1,30 +1,32 @@
 use super::*;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
-pub fn day_of_week_from_fixed(date: RD) -> i32 {
+pub const fn day_of_week_from_fixed(date: RD) -> i32 {
     divide(date, 7).1
 }
 
-pub fn kday_on_or_before(date: RD, k: i32) -> RD {
+pub const fn kday_on_or_before(date: RD, k: i32) -> RD {
     date - day_of_week_from_fixed(date - k)
 }
 
-pub fn kday_on_or_after(date: RD, k: i32) -> RD {
+pub const fn kday_on_or_after(date: RD, k: i32) -> RD {
     kday_on_or_before(date + 6, k)
 }
 
-pub fn kday_nearest(date: RD, k: i32) -> RD {
+pub const fn kday_nearest(date: RD, k: i32) -> RD {
     kday_on_or_before(date + 3, k)
 }
 
-pub fn kday_before(date: RD, k: i32) -> RD {
+pub const fn kday_before(date: RD, k: i32) -> RD {
     kday_on_or_before(date - 1, k)
 }
 
-pub fn kday_after(date: RD, k: i32) -> RD {
+pub const fn kday_after(date: RD, k: i32) -> RD {
     kday_on_or_before(date + 7, k)
 }
 
-pub fn nth_kday(date: RD, n: i32, k: i32) -> RD {
+pub const fn nth_kday(date: RD, n: i32, k: i32) -> RD {
     7 * n + if n > 0 {
         kday_before(date, k)
     } else {
@@ -34,6 +36,7 @@ pub fn nth_kday(date: RD, n: i32, k: i32) -> RD {
 
 /// gives a list of occurences of day_number in a cycle of length cycle_length in the date range [start, end]
 /// while allowing an offset of RD = 0 in the applicable cycle to be included in the calculation
+#[cfg(feature = "alloc")]
 pub fn positions_in_cycle(day_number: i32, cycle_length: i32, delta: i32, start: RD, end: RD) -> Vec<RD> {
     let pos = start + divide(day_number - start - delta - 1, cycle_length).1;
 
@@ -8,7 +8,7 @@ pub type Moment = f64;
 /// (-x) mod y = y - (x mod y) also az = (xz mod yz) this way
 /// also 0 <= signum(y) * (x mod y) < |y|
 pub fn divide_f(x: f64, y: f64) -> (f64, f64) {
-    let division = (x / y).floor();
+    let division = floor(x / y);
     (division, x - y * division)
 }
 
@@ -22,20 +22,39 @@ pub fn alternate_divide_f(x: f64, y: f64) -> (f64, f64) {
     })
 }
 
-pub fn divide(x: i32, y: i32) -> (i32, i32) {
-    let result = divide_f(x as f64, y as f64);
-    (result.0 as i32, result.1 as i32)
+/// the same as divide_f but only with integers so it works in constants
+pub const fn divide(x: i32, y: i32) -> (i32, i32) {
+    let quotient = x / y;
+    // integer division rounds towards 0 so it is one too high when the signs differ and it isnt exact
+    let division = if x % y != 0 && (x < 0) != (y < 0) { quotient - 1 } else { quotient };
+    (division, x - y * division)
 }
 
-pub fn alternate_divide(x: i32, y: i32) -> (i32, i32) {
-    let result = alternate_divide_f(x as f64, y as f64);
-    (result.0 as i32, result.1 as i32)
+pub const fn alternate_divide(x: i32, y: i32) -> (i32, i32) {
+    let result = divide(x, y);
+    (result.0, if result.1 == 0 { y } else { result.1 })
 }
 
 /// special round that always floors when
 /// rounding instead of approaching 0 from both directions
 pub fn round(x: f64) -> f64 {
-    (x + 0.5).floor()
+    floor(x + 0.5)
+}
+
+/// f64::floor without needing std, anything from 2^52 up is already a whole number
+pub fn floor(x: f64) -> f64 {
+    const WHOLE: f64 = 4503599627370496.0;
+    // this is also false for NaN which is given back as is
+    if !(-WHOLE < x && x < WHOLE) {
+        return x;
+    }
+    let truncated = x as i64 as f64;
+    if truncated > x { truncated - 1.0 } else { truncated }
+}
+
+/// f64::ceil without needing std
+pub fn ceil(x: f64) -> f64 {
+    -floor(-x)
 }
 
 pub struct Ratio {
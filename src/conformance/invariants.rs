@@ -0,0 +1,244 @@
+//! checks that the conversions of a calendar agree with each other and with its rules
+//!
+//! every day in the range is converted to the calendar and back, compared with the day after it,
+//! and every month and year that lies entirely in the range is counted and compared with the
+//! length the calendar says it should have
+
+use calendar::{Calendar, Date, ALL};
+use common::RD;
+use arithmetical::gregorian::Gregorian;
+use arithmetical::julian::Julian;
+use arithmetical::julian::roman::Roman;
+use arithmetical::coptic_ethiopic::CopticEthiopic;
+use arithmetical::iso::Iso;
+use arithmetical::islamic::Islamic;
+use arithmetical::hebrew::{Hebrew, is_hebrew_leap_year};
+use arithmetical::hindu::solar::HinduSolar;
+use arithmetical::hindu::lunisolar::HinduLunisolar;
+use arithmetical::mayan::long_count::LongCount;
+use arithmetical::mayan::haab::Haab;
+use arithmetical::mayan::tzolkin::Tzolkin;
+use arithmetical::balinese_powukon::BaliPowukon;
+use core::fmt;
+use alloc::vec::Vec;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Violation {
+    /// `date` is what `fixed` converts to but it converts back to `back`
+    RoundTrip { fixed: RD, date: Date, back: RD },
+    /// the day after `fixed` converts to `next` which doesnt follow `date`
+    Succession { fixed: RD, date: Date, next: Date },
+    /// the month of `date`, which starts on `start`, has `found` days
+    MonthLength { start: RD, date: Date, expected: i32, found: i32 },
+    /// the year of `date`, which starts on `start`, has `found` days
+    YearLength { start: RD, date: Date, expected: i32, found: i32 }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Violation::RoundTrip { fixed, date, back } =>
+                write!(f, "R.D. {} converts to {:?} which converts back to R.D. {}", fixed, date, back),
+            Violation::Succession { fixed, date, next } =>
+                write!(f, "R.D. {} is {:?} but the day after it is {:?}", fixed, date, next),
+            Violation::MonthLength { start, date, expected, found } =>
+                write!(f, "the month of {:?} starting on R.D. {} has {} days instead of {}", date, start, found, expected),
+            Violation::YearLength { start, date, expected, found } =>
+                write!(f, "the year of {:?} starting on R.D. {} has {} days instead of {}", date, start, found, expected)
+        }
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Report {
+    pub calendar: Calendar,
+    pub start: RD,
+    pub end: RD,
+    pub violations: Vec<Violation>
+}
+
+impl Report {
+    pub fn is_ok(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// a month or year being counted, `start` is `None` when it began before the range
+#[derive(Clone, Copy, Debug)]
+struct Run {
+    key: (i32, i32),
+    start: Option<RD>,
+    date: Date
+}
+
+impl Run {
+    /// the run ends the day before `date`, returns its start and length if it was entirely in the range
+    fn end(&mut self, key: (i32, i32), date: RD, converted: Date) -> Option<(RD, Date, i32)> {
+        if key == self.key {
+            return None;
+        }
+        let ended = self.start.map(|start| (start, self.date, date - start));
+        *self = Run { key, start: Some(date), date: converted };
+        ended
+    }
+}
+
+/// the length of the month of a date, with the weeks of iso standing in for months
+fn days_in_month(date: Date) -> Option<i32> {
+    match date {
+        Date::Iso(_) => Some(7),
+        date => date.days_in_month()
+    }
+}
+
+/// the year and month of a date for the calendars whose months have a length
+fn month_of(date: Date) -> Option<(i32, i32)> {
+    match date {
+        Date::Haab(date) => Some((0, date.month)),
+        date => days_in_month(date).map(|_| (date.field_values()[0], date.field_values()[1]))
+    }
+}
+
+fn year_of(date: Date) -> Option<(i32, i32)> {
+    date.days_in_year().map(|_| (date.field_values()[0], 0))
+}
+
+/// the year after `year`, which skips 0 in the julian calendar
+fn next_year(calendar: Calendar, year: i32) -> i32 {
+    if calendar == Calendar::Julian && year == -1 { 1 } else { year + 1 }
+}
+
+/// the year and month after a month of a calendar with a year, months and days
+fn next_month(calendar: Calendar, year: i32, month: i32) -> (i32, i32) {
+    let last = match calendar {
+        Calendar::Coptic | Calendar::Ethiopic => 13,
+        Calendar::Hebrew if is_hebrew_leap_year(year) => 13,
+        _ => 12
+    };
+    match calendar {
+        // the hebrew year starts with tishri, the 7th month
+        Calendar::Hebrew if month == 6 => (year + 1, 7),
+        Calendar::Hebrew if month == last => (year, 1),
+        _ if month == last => (next_year(calendar, year), 1),
+        _ => (year, month + 1)
+    }
+}
+
+/// the value after `value` in a cycle that goes from 1 to `length`
+fn next_in_cycle(value: i32, length: i32) -> i32 {
+    value % length + 1
+}
+
+/// whether `next` is the date after `date`
+fn follows(date: Date, next: Date) -> bool {
+    let calendar = date.calendar();
+    match (date, next) {
+        (Date::Gregorian(Gregorian { year, month, day }), Date::Gregorian(Gregorian { year: y, month: m, day: d }))
+            | (Date::Julian(Julian { year, month, day }), Date::Julian(Julian { year: y, month: m, day: d }))
+            | (Date::Coptic(CopticEthiopic { year, month, day }), Date::Coptic(CopticEthiopic { year: y, month: m, day: d }))
+            | (Date::Ethiopic(CopticEthiopic { year, month, day }), Date::Ethiopic(CopticEthiopic { year: y, month: m, day: d }))
+            | (Date::Islamic(Islamic { year, month, day }), Date::Islamic(Islamic { year: y, month: m, day: d }))
+            | (Date::Hebrew(Hebrew { year, month, day }), Date::Hebrew(Hebrew { year: y, month: m, day: d }))
+            | (Date::HinduSolar(HinduSolar { year, month, day }), Date::HinduSolar(HinduSolar { year: y, month: m, day: d })) =>
+            (y, m, d) == (year, month, day + 1) || (d == 1 && (y, m) == next_month(calendar, year, month)),
+        (Date::Roman(Roman { year, month, event, count, leap }), Date::Roman(next)) => {
+            // the count goes down to 1 on the day of the event, which is followed by the count to the next one,
+            // and the leap day repeats the count of the day before it
+            if count == 1 {
+                (next.year, next.month, next.event) != (year, month, event) && next.count > 1
+            } else {
+                (next.year, next.month, next.event) == (year, month, event)
+                    && ((next.count, next.leap) == (count - 1, false) || (next.count == count && !leap && next.leap))
+            }
+        },
+        (Date::Iso(Iso { year, week, day }), Date::Iso(next)) =>
+            (next.year, next.week, next.day) == (year, week, day + 1)
+                || (day == 7 && next.day == 1 && ((next.year, next.week) == (year, week + 1) || (next.year, next.week) == (year + 1, 1))),
+        (Date::HinduLunisolar(HinduLunisolar { year, month, leap_month, day }), Date::HinduLunisolar(next)) => {
+            // lunar days are shorter than solar days so every so often one is skipped
+            let same_month = (next.year, next.month, next.leap_month) == (year, month, leap_month);
+            let new_month = if leap_month {
+                (next.year, next.month, next.leap_month) == (year, month, false)
+            } else {
+                next.month == next_in_cycle(month, 12) && (next.year == year || (next.month == 1 && next.year == year + 1))
+            };
+            (same_month && (next.day == day + 1 || next.day == day + 2)) || (new_month && (next.day == 1 || next.day == 2))
+        },
+        (Date::LongCount(date), Date::LongCount(next)) => {
+            let mut expected = LongCount { kin: date.kin + 1, ..date };
+            if expected.kin == 20 {
+                expected = LongCount { kin: 0, uinal: expected.uinal + 1, ..expected };
+            }
+            if expected.uinal == 18 {
+                expected = LongCount { uinal: 0, tun: expected.tun + 1, ..expected };
+            }
+            if expected.tun == 20 {
+                expected = LongCount { tun: 0, katun: expected.katun + 1, ..expected };
+            }
+            if expected.katun == 20 {
+                expected = LongCount { katun: 0, baktun: expected.baktun + 1, ..expected };
+            }
+            next == expected
+        },
+        (Date::Haab(Haab { month, day }), Date::Haab(next)) =>
+            (next.month, next.day) == (month, day + 1) || (next.day == 0 && next.month == next_in_cycle(month, 19)),
+        (Date::Tzolkin(Tzolkin { number, name }), Date::Tzolkin(next)) =>
+            (next.number, next.name) == (next_in_cycle(number, 13), next_in_cycle(name, 20)),
+        // only the weeks that are plain cycles, the others have days that repeat or depend on each other
+        (Date::BaliPowukon(BaliPowukon { triwara, pancawara, sadwara, saptawara, .. }), Date::BaliPowukon(next)) =>
+            (next.triwara, next.pancawara, next.sadwara, next.saptawara)
+                == (next_in_cycle(triwara, 3), next_in_cycle(pancawara, 5), next_in_cycle(sadwara, 6), next_in_cycle(saptawara, 7)),
+        _ => false
+    }
+}
+
+/// checks every day in [start, end] of a calendar
+pub fn check(calendar: Calendar, start: RD, end: RD) -> Report {
+    check_with(calendar, start, end, |fixed| calendar.from_fixed(fixed))
+}
+
+/// checks every day in [start, end] converted with `from_fixed` instead of the calendars own
+/// conversion, like one written in another language, against the rest of the calendar
+pub fn check_with<F: Fn(RD) -> Date>(calendar: Calendar, start: RD, end: RD, from_fixed: F) -> Report {
+    let mut violations = Vec::new();
+    let first = from_fixed(start);
+    let mut month = month_of(first).map(|key| Run { key, start: None, date: first });
+    let mut year = year_of(first).map(|key| Run { key, start: None, date: first });
+    let mut date = first;
+    for fixed in start..=end {
+        let back = date.on_or_before(fixed);
+        if back != fixed {
+            violations.push(Violation::RoundTrip { fixed, date, back });
+        }
+
+        let next = from_fixed(fixed + 1);
+        if !follows(date, next) {
+            violations.push(Violation::Succession { fixed, date, next });
+        }
+
+        // months and years are counted when the day after the last one comes, so that day can be after the range
+        if let (Some(run), Some(key)) = (month.as_mut(), month_of(next)) {
+            if let Some((start, date, found)) = run.end(key, fixed + 1, next) {
+                let expected = days_in_month(date).expect("Months are only counted when they have a length");
+                if found != expected {
+                    violations.push(Violation::MonthLength { start, date, expected, found });
+                }
+            }
+        }
+        if let (Some(run), Some(key)) = (year.as_mut(), year_of(next)) {
+            if let Some((start, date, found)) = run.end(key, fixed + 1, next) {
+                let expected = date.days_in_year().expect("Years are only counted when they have a length");
+                if found != expected {
+                    violations.push(Violation::YearLength { start, date, expected, found });
+                }
+            }
+        }
+        date = next;
+    }
+    Report { calendar, start, end, violations }
+}
+
+/// checks every day in [start, end] of every calendar
+pub fn check_all(start: RD, end: RD) -> Vec<Report> {
+    ALL.iter().map(|&calendar| check(calendar, start, end)).collect()
+}
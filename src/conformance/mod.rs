@@ -0,0 +1,107 @@
+//! checks every calendar against the published sample dates
+
+pub mod invariants;
+pub mod sample_dates;
+
+use calendar::{Calendar, Date, ALL};
+use common::RD;
+use core::fmt;
+use alloc::vec::Vec;
+
+pub use self::sample_dates::{SampleDate, SAMPLE_DATES};
+
+impl SampleDate {
+    /// the date of the row in a calendar
+    pub fn date(&self, calendar: Calendar) -> Date {
+        match calendar {
+            Calendar::Gregorian => Date::Gregorian(self.gregorian),
+            Calendar::Julian => Date::Julian(self.julian),
+            Calendar::Roman => Date::Roman(self.roman),
+            Calendar::Coptic => Date::Coptic(self.coptic),
+            Calendar::Ethiopic => Date::Ethiopic(self.ethiopic),
+            Calendar::Iso => Date::Iso(self.iso),
+            Calendar::Islamic => Date::Islamic(self.islamic),
+            Calendar::Hebrew => Date::Hebrew(self.hebrew),
+            Calendar::HinduSolar => Date::HinduSolar(self.hindu_solar),
+            Calendar::HinduLunisolar => Date::HinduLunisolar(self.hindu_lunisolar),
+            Calendar::LongCount => Date::LongCount(self.long_count),
+            Calendar::Haab => Date::Haab(self.haab),
+            Calendar::Tzolkin => Date::Tzolkin(self.tzolkin),
+            Calendar::BaliPowukon => Date::BaliPowukon(self.bali_powukon)
+        }
+    }
+}
+
+/// which way a conversion that went wrong went
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Direction {
+    /// from the R.D. date to the calendar
+    FromFixed,
+    /// from the calendar back to the R.D. date, or the last one on or before it for the cyclic calendars
+    ToFixed
+}
+
+/// a field that didnt come out as the table has it, the field is "rd" when converting to R.D.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Mismatch {
+    pub fixed: RD,
+    pub calendar: Calendar,
+    pub direction: Direction,
+    pub field: &'static str,
+    pub expected: i32,
+    pub found: i32
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let direction = match self.direction {
+            Direction::FromFixed => "from R.D.",
+            Direction::ToFixed => "to R.D."
+        };
+        write!(f, "{} {} {}: {} should be {} but is {}", self.calendar.name(), direction, self.fixed, self.field, self.expected, self.found)
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Report {
+    /// how many conversions were checked, two for every calendar in every row
+    pub checked: usize,
+    pub mismatches: Vec<Mismatch>
+}
+
+impl Report {
+    pub fn is_ok(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    pub fn mismatches_in(&self, calendar: Calendar) -> Vec<Mismatch> {
+        self.mismatches.iter().filter(|mismatch| mismatch.calendar == calendar).cloned().collect()
+    }
+}
+
+/// converts every sample date to and from a calendar
+pub fn verify_calendar(calendar: Calendar) -> Vec<Mismatch> {
+    let mut mismatches = Vec::new();
+    for sample in SAMPLE_DATES.iter() {
+        let expected = sample.date(calendar);
+        let found = calendar.from_fixed(sample.fixed);
+        let fields = calendar.fields().iter().zip(expected.field_values().into_iter().zip(found.field_values()));
+        for (&field, (expected, found)) in fields.filter(|&(_, (expected, found))| expected != found) {
+            mismatches.push(Mismatch { fixed: sample.fixed, calendar, direction: Direction::FromFixed, field, expected, found });
+        }
+
+        let back = expected.on_or_before(sample.fixed);
+        if back != sample.fixed {
+            mismatches.push(Mismatch { fixed: sample.fixed, calendar, direction: Direction::ToFixed, field: "rd", expected: sample.fixed, found: back });
+        }
+    }
+    mismatches
+}
+
+/// converts every sample date to and from every calendar
+pub fn verify() -> Report {
+    Report {
+        checked: 2 * ALL.len() * SAMPLE_DATES.len(),
+        mismatches: ALL.iter().flat_map(|&calendar| verify_calendar(calendar)).collect()
+    }
+}
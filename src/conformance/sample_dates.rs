@@ -0,0 +1,601 @@
+//! the sample dates from appendix c of calendrical calculations for the calendars in `arithmetical`
+
+use common::RD;
+use arithmetical::gregorian::Gregorian;
+use arithmetical::julian::Julian;
+use arithmetical::julian::roman::{Roman, Event};
+use arithmetical::coptic_ethiopic::CopticEthiopic;
+use arithmetical::iso::Iso;
+use arithmetical::islamic::Islamic;
+use arithmetical::hebrew::Hebrew;
+use arithmetical::hindu::solar::HinduSolar;
+use arithmetical::hindu::lunisolar::HinduLunisolar;
+use arithmetical::mayan::long_count::LongCount;
+use arithmetical::mayan::haab::Haab;
+use arithmetical::mayan::tzolkin::Tzolkin;
+use arithmetical::balinese_powukon::BaliPowukon;
+
+/// a row of the table, an R.D. date and what it is in every calendar
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SampleDate {
+    pub fixed: RD,
+    pub gregorian: Gregorian,
+    pub julian: Julian,
+    pub roman: Roman,
+    pub coptic: CopticEthiopic,
+    pub ethiopic: CopticEthiopic,
+    pub iso: Iso,
+    pub islamic: Islamic,
+    pub hebrew: Hebrew,
+    pub hindu_solar: HinduSolar,
+    pub hindu_lunisolar: HinduLunisolar,
+    pub long_count: LongCount,
+    pub haab: Haab,
+    pub tzolkin: Tzolkin,
+    pub bali_powukon: BaliPowukon
+}
+
+/// from gregorian -586-07-24 to 2094-07-18
+pub const SAMPLE_DATES: [SampleDate; 33] = [
+    SampleDate {
+        fixed: -214193,
+        gregorian: Gregorian { year: -586, month: 7, day: 24 },
+        julian: Julian { year: -587, month: 7, day: 30 },
+        roman: Roman { year: -587, month: 8, event: Event::Kalends, count: 3, leap: false },
+        coptic: CopticEthiopic { year: -870, month: 12, day: 6 },
+        ethiopic: CopticEthiopic { year: -594, month: 12, day: 6 },
+        iso: Iso { year: -586, week: 29, day: 7 },
+        islamic: Islamic { year: -1245, month: 12, day: 9 },
+        hebrew: Hebrew { year: 3174, month: 5, day: 10 },
+        hindu_solar: HinduSolar { year: 2515, month: 5, day: 19 },
+        hindu_lunisolar: HinduLunisolar { year: 2515, month: 6, leap_month: false, day: 11 },
+        long_count: LongCount { baktun: 6, katun: 8, tun: 3, uinal: 13, kin: 9 },
+        haab: Haab { month: 11, day: 12 },
+        tzolkin: Tzolkin { number: 5, name: 9 },
+        bali_powukon: BaliPowukon { luang: false, dwiwara: 1, triwara: 1, caturwara: 1, pancawara: 3, sadwara: 1, saptawara: 1, asatawara: 5, sangawara: 7, dasawara: 3 }
+    },
+    SampleDate {
+        fixed: -61387,
+        gregorian: Gregorian { year: -168, month: 12, day: 5 },
+        julian: Julian { year: -169, month: 12, day: 8 },
+        roman: Roman { year: -169, month: 12, event: Event::Ides, count: 6, leap: false },
+        coptic: CopticEthiopic { year: -451, month: 4, day: 12 },
+        ethiopic: CopticEthiopic { year: -175, month: 4, day: 12 },
+        iso: Iso { year: -168, week: 49, day: 3 },
+        islamic: Islamic { year: -813, month: 2, day: 23 },
+        hebrew: Hebrew { year: 3593, month: 9, day: 25 },
+        hindu_solar: HinduSolar { year: 2933, month: 9, day: 26 },
+        hindu_lunisolar: HinduLunisolar { year: 2933, month: 9, leap_month: false, day: 26 },
+        long_count: LongCount { baktun: 7, katun: 9, tun: 8, uinal: 3, kin: 15 },
+        haab: Haab { month: 5, day: 3 },
+        tzolkin: Tzolkin { number: 9, name: 15 },
+        bali_powukon: BaliPowukon { luang: true, dwiwara: 2, triwara: 2, caturwara: 1, pancawara: 4, sadwara: 5, saptawara: 4, asatawara: 5, sangawara: 5, dasawara: 2 }
+    },
+    SampleDate {
+        fixed: 25469,
+        gregorian: Gregorian { year: 70, month: 9, day: 24 },
+        julian: Julian { year: 70, month: 9, day: 26 },
+        roman: Roman { year: 70, month: 10, event: Event::Kalends, count: 6, leap: false },
+        coptic: CopticEthiopic { year: -213, month: 1, day: 29 },
+        ethiopic: CopticEthiopic { year: 63, month: 1, day: 29 },
+        iso: Iso { year: 70, week: 39, day: 3 },
+        islamic: Islamic { year: -568, month: 4, day: 1 },
+        hebrew: Hebrew { year: 3831, month: 7, day: 3 },
+        hindu_solar: HinduSolar { year: 3171, month: 7, day: 11 },
+        hindu_lunisolar: HinduLunisolar { year: 3171, month: 8, leap_month: false, day: 3 },
+        long_count: LongCount { baktun: 8, katun: 1, tun: 9, uinal: 8, kin: 11 },
+        haab: Haab { month: 4, day: 9 },
+        tzolkin: Tzolkin { number: 12, name: 11 },
+        bali_powukon: BaliPowukon { luang: true, dwiwara: 2, triwara: 2, caturwara: 1, pancawara: 5, sadwara: 5, saptawara: 4, asatawara: 1, sangawara: 5, dasawara: 6 }
+    },
+    SampleDate {
+        fixed: 49217,
+        gregorian: Gregorian { year: 135, month: 10, day: 2 },
+        julian: Julian { year: 135, month: 10, day: 3 },
+        roman: Roman { year: 135, month: 10, event: Event::Nones, count: 5, leap: false },
+        coptic: CopticEthiopic { year: -148, month: 2, day: 5 },
+        ethiopic: CopticEthiopic { year: 128, month: 2, day: 5 },
+        iso: Iso { year: 135, week: 39, day: 7 },
+        islamic: Islamic { year: -501, month: 4, day: 6 },
+        hebrew: Hebrew { year: 3896, month: 7, day: 9 },
+        hindu_solar: HinduSolar { year: 3236, month: 7, day: 17 },
+        hindu_lunisolar: HinduLunisolar { year: 3236, month: 8, leap_month: false, day: 9 },
+        long_count: LongCount { baktun: 8, katun: 4, tun: 15, uinal: 7, kin: 19 },
+        haab: Haab { month: 5, day: 12 },
+        tzolkin: Tzolkin { number: 9, name: 19 },
+        bali_powukon: BaliPowukon { luang: false, dwiwara: 1, triwara: 2, caturwara: 3, pancawara: 3, sadwara: 5, saptawara: 1, asatawara: 3, sangawara: 5, dasawara: 3 }
+    },
+    SampleDate {
+        fixed: 171307,
+        gregorian: Gregorian { year: 470, month: 1, day: 8 },
+        julian: Julian { year: 470, month: 1, day: 7 },
+        roman: Roman { year: 470, month: 1, event: Event::Ides, count: 7, leap: false },
+        coptic: CopticEthiopic { year: 186, month: 5, day: 12 },
+        ethiopic: CopticEthiopic { year: 462, month: 5, day: 12 },
+        iso: Iso { year: 470, week: 2, day: 3 },
+        islamic: Islamic { year: -157, month: 10, day: 17 },
+        hebrew: Hebrew { year: 4230, month: 10, day: 18 },
+        hindu_solar: HinduSolar { year: 3570, month: 10, day: 19 },
+        hindu_lunisolar: HinduLunisolar { year: 3570, month: 11, leap_month: true, day: 19 },
+        long_count: LongCount { baktun: 9, katun: 1, tun: 14, uinal: 10, kin: 9 },
+        haab: Haab { month: 14, day: 12 },
+        tzolkin: Tzolkin { number: 3, name: 9 },
+        bali_powukon: BaliPowukon { luang: false, dwiwara: 1, triwara: 1, caturwara: 3, pancawara: 3, sadwara: 1, saptawara: 4, asatawara: 3, sangawara: 1, dasawara: 5 }
+    },
+    SampleDate {
+        fixed: 210155,
+        gregorian: Gregorian { year: 576, month: 5, day: 20 },
+        julian: Julian { year: 576, month: 5, day: 18 },
+        roman: Roman { year: 576, month: 6, event: Event::Kalends, count: 15, leap: false },
+        coptic: CopticEthiopic { year: 292, month: 9, day: 23 },
+        ethiopic: CopticEthiopic { year: 568, month: 9, day: 23 },
+        iso: Iso { year: 576, week: 21, day: 1 },
+        islamic: Islamic { year: -47, month: 6, day: 3 },
+        hebrew: Hebrew { year: 4336, month: 3, day: 4 },
+        hindu_solar: HinduSolar { year: 3677, month: 2, day: 28 },
+        hindu_lunisolar: HinduLunisolar { year: 3677, month: 3, leap_month: false, day: 5 },
+        long_count: LongCount { baktun: 9, katun: 7, tun: 2, uinal: 8, kin: 17 },
+        haab: Haab { month: 4, day: 5 },
+        tzolkin: Tzolkin { number: 7, name: 17 },
+        bali_powukon: BaliPowukon { luang: true, dwiwara: 2, triwara: 2, caturwara: 1, pancawara: 1, sadwara: 5, saptawara: 2, asatawara: 1, sangawara: 8, dasawara: 0 }
+    },
+    SampleDate {
+        fixed: 253427,
+        gregorian: Gregorian { year: 694, month: 11, day: 10 },
+        julian: Julian { year: 694, month: 11, day: 7 },
+        roman: Roman { year: 694, month: 11, event: Event::Ides, count: 7, leap: false },
+        coptic: CopticEthiopic { year: 411, month: 3, day: 11 },
+        ethiopic: CopticEthiopic { year: 687, month: 3, day: 11 },
+        iso: Iso { year: 694, week: 45, day: 6 },
+        islamic: Islamic { year: 75, month: 7, day: 13 },
+        hebrew: Hebrew { year: 4455, month: 8, day: 13 },
+        hindu_solar: HinduSolar { year: 3795, month: 8, day: 17 },
+        hindu_lunisolar: HinduLunisolar { year: 3795, month: 9, leap_month: false, day: 15 },
+        long_count: LongCount { baktun: 9, katun: 13, tun: 2, uinal: 12, kin: 9 },
+        haab: Haab { month: 14, day: 7 },
+        tzolkin: Tzolkin { number: 2, name: 9 },
+        bali_powukon: BaliPowukon { luang: false, dwiwara: 1, triwara: 2, caturwara: 3, pancawara: 3, sadwara: 5, saptawara: 7, asatawara: 3, sangawara: 2, dasawara: 7 }
+    },
+    SampleDate {
+        fixed: 369740,
+        gregorian: Gregorian { year: 1013, month: 4, day: 25 },
+        julian: Julian { year: 1013, month: 4, day: 19 },
+        roman: Roman { year: 1013, month: 5, event: Event::Kalends, count: 13, leap: false },
+        coptic: CopticEthiopic { year: 729, month: 8, day: 24 },
+        ethiopic: CopticEthiopic { year: 1005, month: 8, day: 24 },
+        iso: Iso { year: 1013, week: 16, day: 7 },
+        islamic: Islamic { year: 403, month: 10, day: 5 },
+        hebrew: Hebrew { year: 4773, month: 2, day: 6 },
+        hindu_solar: HinduSolar { year: 4114, month: 1, day: 26 },
+        hindu_lunisolar: HinduLunisolar { year: 4114, month: 2, leap_month: false, day: 7 },
+        long_count: LongCount { baktun: 10, katun: 9, tun: 5, uinal: 14, kin: 2 },
+        haab: Haab { month: 8, day: 5 },
+        tzolkin: Tzolkin { number: 4, name: 2 },
+        bali_powukon: BaliPowukon { luang: false, dwiwara: 1, triwara: 2, caturwara: 2, pancawara: 1, sadwara: 2, saptawara: 1, asatawara: 2, sangawara: 2, dasawara: 1 }
+    },
+    SampleDate {
+        fixed: 400085,
+        gregorian: Gregorian { year: 1096, month: 5, day: 24 },
+        julian: Julian { year: 1096, month: 5, day: 18 },
+        roman: Roman { year: 1096, month: 6, event: Event::Kalends, count: 15, leap: false },
+        coptic: CopticEthiopic { year: 812, month: 9, day: 23 },
+        ethiopic: CopticEthiopic { year: 1088, month: 9, day: 23 },
+        iso: Iso { year: 1096, week: 21, day: 7 },
+        islamic: Islamic { year: 489, month: 5, day: 22 },
+        hebrew: Hebrew { year: 4856, month: 2, day: 23 },
+        hindu_solar: HinduSolar { year: 4197, month: 2, day: 24 },
+        hindu_lunisolar: HinduLunisolar { year: 4197, month: 2, leap_month: false, day: 24 },
+        long_count: LongCount { baktun: 10, katun: 13, tun: 10, uinal: 1, kin: 7 },
+        haab: Haab { month: 10, day: 15 },
+        tzolkin: Tzolkin { number: 7, name: 7 },
+        bali_powukon: BaliPowukon { luang: false, dwiwara: 1, triwara: 2, caturwara: 1, pancawara: 1, sadwara: 5, saptawara: 1, asatawara: 1, sangawara: 8, dasawara: 1 }
+    },
+    SampleDate {
+        fixed: 434355,
+        gregorian: Gregorian { year: 1190, month: 3, day: 23 },
+        julian: Julian { year: 1190, month: 3, day: 16 },
+        roman: Roman { year: 1190, month: 4, event: Event::Kalends, count: 17, leap: false },
+        coptic: CopticEthiopic { year: 906, month: 7, day: 20 },
+        ethiopic: CopticEthiopic { year: 1182, month: 7, day: 20 },
+        iso: Iso { year: 1190, week: 12, day: 5 },
+        islamic: Islamic { year: 586, month: 2, day: 7 },
+        hebrew: Hebrew { year: 4950, month: 1, day: 7 },
+        hindu_solar: HinduSolar { year: 4290, month: 12, day: 20 },
+        hindu_lunisolar: HinduLunisolar { year: 4291, month: 1, leap_month: false, day: 9 },
+        long_count: LongCount { baktun: 10, katun: 18, tun: 5, uinal: 4, kin: 17 },
+        haab: Haab { month: 8, day: 15 },
+        tzolkin: Tzolkin { number: 9, name: 17 },
+        bali_powukon: BaliPowukon { luang: true, dwiwara: 2, triwara: 3, caturwara: 1, pancawara: 1, sadwara: 3, saptawara: 6, asatawara: 1, sangawara: 3, dasawara: 2 }
+    },
+    SampleDate {
+        fixed: 452605,
+        gregorian: Gregorian { year: 1240, month: 3, day: 10 },
+        julian: Julian { year: 1240, month: 3, day: 3 },
+        roman: Roman { year: 1240, month: 3, event: Event::Nones, count: 5, leap: false },
+        coptic: CopticEthiopic { year: 956, month: 7, day: 7 },
+        ethiopic: CopticEthiopic { year: 1232, month: 7, day: 7 },
+        iso: Iso { year: 1240, week: 10, day: 6 },
+        islamic: Islamic { year: 637, month: 8, day: 7 },
+        hebrew: Hebrew { year: 5000, month: 13, day: 8 },
+        hindu_solar: HinduSolar { year: 4340, month: 12, day: 7 },
+        hindu_lunisolar: HinduLunisolar { year: 4340, month: 12, leap_month: false, day: 9 },
+        long_count: LongCount { baktun: 11, katun: 0, tun: 15, uinal: 17, kin: 7 },
+        haab: Haab { month: 8, day: 15 },
+        tzolkin: Tzolkin { number: 7, name: 7 },
+        bali_powukon: BaliPowukon { luang: false, dwiwara: 1, triwara: 1, caturwara: 1, pancawara: 1, sadwara: 1, saptawara: 7, asatawara: 5, sangawara: 1, dasawara: 5 }
+    },
+    SampleDate {
+        fixed: 470160,
+        gregorian: Gregorian { year: 1288, month: 4, day: 2 },
+        julian: Julian { year: 1288, month: 3, day: 26 },
+        roman: Roman { year: 1288, month: 4, event: Event::Kalends, count: 7, leap: false },
+        coptic: CopticEthiopic { year: 1004, month: 7, day: 30 },
+        ethiopic: CopticEthiopic { year: 1280, month: 7, day: 30 },
+        iso: Iso { year: 1288, week: 14, day: 5 },
+        islamic: Islamic { year: 687, month: 2, day: 20 },
+        hebrew: Hebrew { year: 5048, month: 1, day: 21 },
+        hindu_solar: HinduSolar { year: 4388, month: 12, day: 30 },
+        hindu_lunisolar: HinduLunisolar { year: 4389, month: 1, leap_month: false, day: 23 },
+        long_count: LongCount { baktun: 11, katun: 3, tun: 4, uinal: 13, kin: 2 },
+        haab: Haab { month: 10, day: 10 },
+        tzolkin: Tzolkin { number: 12, name: 2 },
+        bali_powukon: BaliPowukon { luang: true, dwiwara: 2, triwara: 3, caturwara: 4, pancawara: 1, sadwara: 6, saptawara: 6, asatawara: 8, sangawara: 6, dasawara: 2 }
+    },
+    SampleDate {
+        fixed: 473837,
+        gregorian: Gregorian { year: 1298, month: 4, day: 27 },
+        julian: Julian { year: 1298, month: 4, day: 20 },
+        roman: Roman { year: 1298, month: 5, event: Event::Kalends, count: 12, leap: false },
+        coptic: CopticEthiopic { year: 1014, month: 8, day: 25 },
+        ethiopic: CopticEthiopic { year: 1290, month: 8, day: 25 },
+        iso: Iso { year: 1298, week: 17, day: 7 },
+        islamic: Islamic { year: 697, month: 7, day: 7 },
+        hebrew: Hebrew { year: 5058, month: 2, day: 7 },
+        hindu_solar: HinduSolar { year: 4399, month: 1, day: 24 },
+        hindu_lunisolar: HinduLunisolar { year: 4399, month: 2, leap_month: false, day: 8 },
+        long_count: LongCount { baktun: 11, katun: 3, tun: 14, uinal: 16, kin: 19 },
+        haab: Haab { month: 11, day: 17 },
+        tzolkin: Tzolkin { number: 10, name: 19 },
+        bali_powukon: BaliPowukon { luang: false, dwiwara: 1, triwara: 2, caturwara: 3, pancawara: 3, sadwara: 5, saptawara: 1, asatawara: 3, sangawara: 5, dasawara: 3 }
+    },
+    SampleDate {
+        fixed: 507850,
+        gregorian: Gregorian { year: 1391, month: 6, day: 12 },
+        julian: Julian { year: 1391, month: 6, day: 4 },
+        roman: Roman { year: 1391, month: 6, event: Event::Nones, count: 2, leap: false },
+        coptic: CopticEthiopic { year: 1107, month: 10, day: 10 },
+        ethiopic: CopticEthiopic { year: 1383, month: 10, day: 10 },
+        iso: Iso { year: 1391, week: 23, day: 7 },
+        islamic: Islamic { year: 793, month: 7, day: 1 },
+        hebrew: Hebrew { year: 5151, month: 4, day: 1 },
+        hindu_solar: HinduSolar { year: 4492, month: 3, day: 7 },
+        hindu_lunisolar: HinduLunisolar { year: 4492, month: 4, leap_month: false, day: 2 },
+        long_count: LongCount { baktun: 11, katun: 8, tun: 9, uinal: 7, kin: 12 },
+        haab: Haab { month: 15, day: 5 },
+        tzolkin: Tzolkin { number: 2, name: 12 },
+        bali_powukon: BaliPowukon { luang: false, dwiwara: 1, triwara: 1, caturwara: 4, pancawara: 1, sadwara: 4, saptawara: 1, asatawara: 4, sangawara: 7, dasawara: 1 }
+    },
+    SampleDate {
+        fixed: 524156,
+        gregorian: Gregorian { year: 1436, month: 2, day: 3 },
+        julian: Julian { year: 1436, month: 1, day: 25 },
+        roman: Roman { year: 1436, month: 2, event: Event::Kalends, count: 8, leap: false },
+        coptic: CopticEthiopic { year: 1152, month: 5, day: 29 },
+        ethiopic: CopticEthiopic { year: 1428, month: 5, day: 29 },
+        iso: Iso { year: 1436, week: 5, day: 3 },
+        islamic: Islamic { year: 839, month: 7, day: 6 },
+        hebrew: Hebrew { year: 5196, month: 11, day: 7 },
+        hindu_solar: HinduSolar { year: 4536, month: 10, day: 28 },
+        hindu_lunisolar: HinduLunisolar { year: 4536, month: 11, leap_month: false, day: 7 },
+        long_count: LongCount { baktun: 11, katun: 10, tun: 14, uinal: 12, kin: 18 },
+        haab: Haab { month: 9, day: 6 },
+        tzolkin: Tzolkin { number: 6, name: 18 },
+        bali_powukon: BaliPowukon { luang: false, dwiwara: 1, triwara: 2, caturwara: 2, pancawara: 2, sadwara: 2, saptawara: 4, asatawara: 2, sangawara: 5, dasawara: 7 }
+    },
+    SampleDate {
+        fixed: 544676,
+        gregorian: Gregorian { year: 1492, month: 4, day: 9 },
+        julian: Julian { year: 1492, month: 3, day: 31 },
+        roman: Roman { year: 1492, month: 4, event: Event::Kalends, count: 2, leap: false },
+        coptic: CopticEthiopic { year: 1208, month: 8, day: 5 },
+        ethiopic: CopticEthiopic { year: 1484, month: 8, day: 5 },
+        iso: Iso { year: 1492, week: 14, day: 6 },
+        islamic: Islamic { year: 897, month: 6, day: 1 },
+        hebrew: Hebrew { year: 5252, month: 1, day: 3 },
+        hindu_solar: HinduSolar { year: 4593, month: 1, day: 3 },
+        hindu_lunisolar: HinduLunisolar { year: 4593, month: 1, leap_month: false, day: 3 },
+        long_count: LongCount { baktun: 11, katun: 13, tun: 11, uinal: 12, kin: 18 },
+        haab: Haab { month: 13, day: 6 },
+        tzolkin: Tzolkin { number: 12, name: 18 },
+        bali_powukon: BaliPowukon { luang: false, dwiwara: 1, triwara: 2, caturwara: 4, pancawara: 2, sadwara: 2, saptawara: 7, asatawara: 8, sangawara: 8, dasawara: 9 }
+    },
+    SampleDate {
+        fixed: 567118,
+        gregorian: Gregorian { year: 1553, month: 9, day: 19 },
+        julian: Julian { year: 1553, month: 9, day: 9 },
+        roman: Roman { year: 1553, month: 9, event: Event::Ides, count: 5, leap: false },
+        coptic: CopticEthiopic { year: 1270, month: 1, day: 12 },
+        ethiopic: CopticEthiopic { year: 1546, month: 1, day: 12 },
+        iso: Iso { year: 1553, week: 38, day: 6 },
+        islamic: Islamic { year: 960, month: 9, day: 30 },
+        hebrew: Hebrew { year: 5314, month: 7, day: 1 },
+        hindu_solar: HinduSolar { year: 4654, month: 6, day: 12 },
+        hindu_lunisolar: HinduLunisolar { year: 4654, month: 7, leap_month: false, day: 2 },
+        long_count: LongCount { baktun: 11, katun: 16, tun: 14, uinal: 1, kin: 0 },
+        haab: Haab { month: 3, day: 18 },
+        tzolkin: Tzolkin { number: 3, name: 20 },
+        bali_powukon: BaliPowukon { luang: true, dwiwara: 2, triwara: 1, caturwara: 4, pancawara: 4, sadwara: 4, saptawara: 7, asatawara: 4, sangawara: 7, dasawara: 4 }
+    },
+    SampleDate {
+        fixed: 569477,
+        gregorian: Gregorian { year: 1560, month: 3, day: 5 },
+        julian: Julian { year: 1560, month: 2, day: 24 },
+        roman: Roman { year: 1560, month: 3, event: Event::Kalends, count: 6, leap: false },
+        coptic: CopticEthiopic { year: 1276, month: 6, day: 29 },
+        ethiopic: CopticEthiopic { year: 1552, month: 6, day: 29 },
+        iso: Iso { year: 1560, week: 9, day: 6 },
+        islamic: Islamic { year: 967, month: 5, day: 27 },
+        hebrew: Hebrew { year: 5320, month: 12, day: 27 },
+        hindu_solar: HinduSolar { year: 4660, month: 11, day: 27 },
+        hindu_lunisolar: HinduLunisolar { year: 4660, month: 11, leap_month: false, day: 29 },
+        long_count: LongCount { baktun: 11, katun: 17, tun: 0, uinal: 10, kin: 19 },
+        haab: Haab { month: 12, day: 7 },
+        tzolkin: Tzolkin { number: 9, name: 19 },
+        bali_powukon: BaliPowukon { luang: false, dwiwara: 1, triwara: 2, caturwara: 3, pancawara: 3, sadwara: 5, saptawara: 7, asatawara: 3, sangawara: 2, dasawara: 7 }
+    },
+    SampleDate {
+        fixed: 601716,
+        gregorian: Gregorian { year: 1648, month: 6, day: 10 },
+        julian: Julian { year: 1648, month: 5, day: 31 },
+        roman: Roman { year: 1648, month: 6, event: Event::Kalends, count: 2, leap: false },
+        coptic: CopticEthiopic { year: 1364, month: 10, day: 6 },
+        ethiopic: CopticEthiopic { year: 1640, month: 10, day: 6 },
+        iso: Iso { year: 1648, week: 24, day: 3 },
+        islamic: Islamic { year: 1058, month: 5, day: 18 },
+        hebrew: Hebrew { year: 5408, month: 3, day: 20 },
+        hindu_solar: HinduSolar { year: 4749, month: 3, day: 1 },
+        hindu_lunisolar: HinduLunisolar { year: 4749, month: 3, leap_month: false, day: 20 },
+        long_count: LongCount { baktun: 12, katun: 1, tun: 10, uinal: 2, kin: 18 },
+        haab: Haab { month: 18, day: 6 },
+        tzolkin: Tzolkin { number: 8, name: 18 },
+        bali_powukon: BaliPowukon { luang: false, dwiwara: 1, triwara: 3, caturwara: 4, pancawara: 2, sadwara: 6, saptawara: 4, asatawara: 8, sangawara: 3, dasawara: 7 }
+    },
+    SampleDate {
+        fixed: 613424,
+        gregorian: Gregorian { year: 1680, month: 6, day: 30 },
+        julian: Julian { year: 1680, month: 6, day: 20 },
+        roman: Roman { year: 1680, month: 7, event: Event::Kalends, count: 12, leap: false },
+        coptic: CopticEthiopic { year: 1396, month: 10, day: 26 },
+        ethiopic: CopticEthiopic { year: 1672, month: 10, day: 26 },
+        iso: Iso { year: 1680, week: 26, day: 7 },
+        islamic: Islamic { year: 1091, month: 6, day: 2 },
+        hebrew: Hebrew { year: 5440, month: 4, day: 3 },
+        hindu_solar: HinduSolar { year: 4781, month: 3, day: 21 },
+        hindu_lunisolar: HinduLunisolar { year: 4781, month: 4, leap_month: false, day: 4 },
+        long_count: LongCount { baktun: 12, katun: 3, tun: 2, uinal: 12, kin: 6 },
+        haab: Haab { month: 1, day: 9 },
+        tzolkin: Tzolkin { number: 3, name: 6 },
+        bali_powukon: BaliPowukon { luang: true, dwiwara: 2, triwara: 2, caturwara: 4, pancawara: 5, sadwara: 2, saptawara: 1, asatawara: 4, sangawara: 5, dasawara: 4 }
+    },
+    SampleDate {
+        fixed: 626596,
+        gregorian: Gregorian { year: 1716, month: 7, day: 24 },
+        julian: Julian { year: 1716, month: 7, day: 13 },
+        roman: Roman { year: 1716, month: 7, event: Event::Ides, count: 3, leap: false },
+        coptic: CopticEthiopic { year: 1432, month: 11, day: 19 },
+        ethiopic: CopticEthiopic { year: 1708, month: 11, day: 19 },
+        iso: Iso { year: 1716, week: 30, day: 5 },
+        islamic: Islamic { year: 1128, month: 8, day: 4 },
+        hebrew: Hebrew { year: 5476, month: 5, day: 5 },
+        hindu_solar: HinduSolar { year: 4817, month: 4, day: 13 },
+        hindu_lunisolar: HinduLunisolar { year: 4817, month: 5, leap_month: false, day: 6 },
+        long_count: LongCount { baktun: 12, katun: 4, tun: 19, uinal: 4, kin: 18 },
+        haab: Haab { month: 3, day: 1 },
+        tzolkin: Tzolkin { number: 6, name: 18 },
+        bali_powukon: BaliPowukon { luang: true, dwiwara: 2, triwara: 1, caturwara: 2, pancawara: 2, sadwara: 4, saptawara: 6, asatawara: 2, sangawara: 1, dasawara: 6 }
+    },
+    SampleDate {
+        fixed: 645554,
+        gregorian: Gregorian { year: 1768, month: 6, day: 19 },
+        julian: Julian { year: 1768, month: 6, day: 8 },
+        roman: Roman { year: 1768, month: 6, event: Event::Ides, count: 6, leap: false },
+        coptic: CopticEthiopic { year: 1484, month: 10, day: 14 },
+        ethiopic: CopticEthiopic { year: 1760, month: 10, day: 14 },
+        iso: Iso { year: 1768, week: 24, day: 7 },
+        islamic: Islamic { year: 1182, month: 2, day: 3 },
+        hebrew: Hebrew { year: 5528, month: 4, day: 4 },
+        hindu_solar: HinduSolar { year: 4869, month: 3, day: 8 },
+        hindu_lunisolar: HinduLunisolar { year: 4869, month: 4, leap_month: false, day: 5 },
+        long_count: LongCount { baktun: 12, katun: 7, tun: 11, uinal: 16, kin: 16 },
+        haab: Haab { month: 1, day: 19 },
+        tzolkin: Tzolkin { number: 10, name: 16 },
+        bali_powukon: BaliPowukon { luang: true, dwiwara: 2, triwara: 2, caturwara: 4, pancawara: 5, sadwara: 2, saptawara: 1, asatawara: 4, sangawara: 5, dasawara: 4 }
+    },
+    SampleDate {
+        fixed: 664224,
+        gregorian: Gregorian { year: 1819, month: 8, day: 2 },
+        julian: Julian { year: 1819, month: 7, day: 21 },
+        roman: Roman { year: 1819, month: 8, event: Event::Kalends, count: 12, leap: false },
+        coptic: CopticEthiopic { year: 1535, month: 11, day: 27 },
+        ethiopic: CopticEthiopic { year: 1811, month: 11, day: 27 },
+        iso: Iso { year: 1819, week: 31, day: 1 },
+        islamic: Islamic { year: 1234, month: 10, day: 10 },
+        hebrew: Hebrew { year: 5579, month: 5, day: 11 },
+        hindu_solar: HinduSolar { year: 4920, month: 4, day: 20 },
+        hindu_lunisolar: HinduLunisolar { year: 4920, month: 5, leap_month: false, day: 12 },
+        long_count: LongCount { baktun: 12, katun: 10, tun: 3, uinal: 14, kin: 6 },
+        haab: Haab { month: 4, day: 14 },
+        tzolkin: Tzolkin { number: 12, name: 6 },
+        bali_powukon: BaliPowukon { luang: false, dwiwara: 1, triwara: 3, caturwara: 4, pancawara: 5, sadwara: 6, saptawara: 2, asatawara: 8, sangawara: 3, dasawara: 3 }
+    },
+    SampleDate {
+        fixed: 671401,
+        gregorian: Gregorian { year: 1839, month: 3, day: 27 },
+        julian: Julian { year: 1839, month: 3, day: 15 },
+        roman: Roman { year: 1839, month: 3, event: Event::Ides, count: 1, leap: false },
+        coptic: CopticEthiopic { year: 1555, month: 7, day: 19 },
+        ethiopic: CopticEthiopic { year: 1831, month: 7, day: 19 },
+        iso: Iso { year: 1839, week: 13, day: 3 },
+        islamic: Islamic { year: 1255, month: 1, day: 11 },
+        hebrew: Hebrew { year: 5599, month: 1, day: 12 },
+        hindu_solar: HinduSolar { year: 4939, month: 12, day: 13 },
+        hindu_lunisolar: HinduLunisolar { year: 4940, month: 1, leap_month: true, day: 13 },
+        long_count: LongCount { baktun: 12, katun: 11, tun: 3, uinal: 13, kin: 3 },
+        haab: Haab { month: 16, day: 16 },
+        tzolkin: Tzolkin { number: 13, name: 3 },
+        bali_powukon: BaliPowukon { luang: false, dwiwara: 1, triwara: 1, caturwara: 1, pancawara: 2, sadwara: 1, saptawara: 4, asatawara: 5, sangawara: 4, dasawara: 7 }
+    },
+    SampleDate {
+        fixed: 694799,
+        gregorian: Gregorian { year: 1903, month: 4, day: 19 },
+        julian: Julian { year: 1903, month: 4, day: 6 },
+        roman: Roman { year: 1903, month: 4, event: Event::Ides, count: 8, leap: false },
+        coptic: CopticEthiopic { year: 1619, month: 8, day: 11 },
+        ethiopic: CopticEthiopic { year: 1895, month: 8, day: 11 },
+        iso: Iso { year: 1903, week: 16, day: 7 },
+        islamic: Islamic { year: 1321, month: 1, day: 21 },
+        hebrew: Hebrew { year: 5663, month: 1, day: 22 },
+        hindu_solar: HinduSolar { year: 5004, month: 1, day: 4 },
+        hindu_lunisolar: HinduLunisolar { year: 5004, month: 1, leap_month: false, day: 23 },
+        long_count: LongCount { baktun: 12, katun: 14, tun: 8, uinal: 13, kin: 1 },
+        haab: Haab { month: 18, day: 14 },
+        tzolkin: Tzolkin { number: 11, name: 1 },
+        bali_powukon: BaliPowukon { luang: true, dwiwara: 2, triwara: 2, caturwara: 1, pancawara: 5, sadwara: 5, saptawara: 1, asatawara: 5, sangawara: 8, dasawara: 4 }
+    },
+    SampleDate {
+        fixed: 704424,
+        gregorian: Gregorian { year: 1929, month: 8, day: 25 },
+        julian: Julian { year: 1929, month: 8, day: 12 },
+        roman: Roman { year: 1929, month: 8, event: Event::Ides, count: 2, leap: false },
+        coptic: CopticEthiopic { year: 1645, month: 12, day: 19 },
+        ethiopic: CopticEthiopic { year: 1921, month: 12, day: 19 },
+        iso: Iso { year: 1929, week: 34, day: 7 },
+        islamic: Islamic { year: 1348, month: 3, day: 19 },
+        hebrew: Hebrew { year: 5689, month: 5, day: 19 },
+        hindu_solar: HinduSolar { year: 5030, month: 5, day: 11 },
+        hindu_lunisolar: HinduLunisolar { year: 5030, month: 5, leap_month: false, day: 21 },
+        long_count: LongCount { baktun: 12, katun: 15, tun: 15, uinal: 8, kin: 6 },
+        haab: Haab { month: 7, day: 4 },
+        tzolkin: Tzolkin { number: 3, name: 6 },
+        bali_powukon: BaliPowukon { luang: true, dwiwara: 2, triwara: 3, caturwara: 2, pancawara: 5, sadwara: 6, saptawara: 1, asatawara: 2, sangawara: 3, dasawara: 4 }
+    },
+    SampleDate {
+        fixed: 708842,
+        gregorian: Gregorian { year: 1941, month: 9, day: 29 },
+        julian: Julian { year: 1941, month: 9, day: 16 },
+        roman: Roman { year: 1941, month: 10, event: Event::Kalends, count: 16, leap: false },
+        coptic: CopticEthiopic { year: 1658, month: 1, day: 19 },
+        ethiopic: CopticEthiopic { year: 1934, month: 1, day: 19 },
+        iso: Iso { year: 1941, week: 40, day: 1 },
+        islamic: Islamic { year: 1360, month: 9, day: 8 },
+        hebrew: Hebrew { year: 5702, month: 7, day: 8 },
+        hindu_solar: HinduSolar { year: 5042, month: 6, day: 15 },
+        hindu_lunisolar: HinduLunisolar { year: 5042, month: 7, leap_month: false, day: 9 },
+        long_count: LongCount { baktun: 12, katun: 16, tun: 7, uinal: 13, kin: 4 },
+        haab: Haab { month: 9, day: 2 },
+        tzolkin: Tzolkin { number: 1, name: 4 },
+        bali_powukon: BaliPowukon { luang: true, dwiwara: 2, triwara: 2, caturwara: 2, pancawara: 3, sadwara: 2, saptawara: 2, asatawara: 2, sangawara: 1, dasawara: 2 }
+    },
+    SampleDate {
+        fixed: 709409,
+        gregorian: Gregorian { year: 1943, month: 4, day: 19 },
+        julian: Julian { year: 1943, month: 4, day: 6 },
+        roman: Roman { year: 1943, month: 4, event: Event::Ides, count: 8, leap: false },
+        coptic: CopticEthiopic { year: 1659, month: 8, day: 11 },
+        ethiopic: CopticEthiopic { year: 1935, month: 8, day: 11 },
+        iso: Iso { year: 1943, week: 16, day: 1 },
+        islamic: Islamic { year: 1362, month: 4, day: 13 },
+        hebrew: Hebrew { year: 5703, month: 1, day: 14 },
+        hindu_solar: HinduSolar { year: 5044, month: 1, day: 4 },
+        hindu_lunisolar: HinduLunisolar { year: 5044, month: 1, leap_month: false, day: 15 },
+        long_count: LongCount { baktun: 12, katun: 16, tun: 9, uinal: 5, kin: 11 },
+        haab: Haab { month: 19, day: 4 },
+        tzolkin: Tzolkin { number: 9, name: 11 },
+        bali_powukon: BaliPowukon { luang: false, dwiwara: 1, triwara: 2, caturwara: 3, pancawara: 5, sadwara: 5, saptawara: 2, asatawara: 3, sangawara: 2, dasawara: 3 }
+    },
+    SampleDate {
+        fixed: 709580,
+        gregorian: Gregorian { year: 1943, month: 10, day: 7 },
+        julian: Julian { year: 1943, month: 9, day: 24 },
+        roman: Roman { year: 1943, month: 10, event: Event::Kalends, count: 8, leap: false },
+        coptic: CopticEthiopic { year: 1660, month: 1, day: 26 },
+        ethiopic: CopticEthiopic { year: 1936, month: 1, day: 26 },
+        iso: Iso { year: 1943, week: 40, day: 4 },
+        islamic: Islamic { year: 1362, month: 10, day: 7 },
+        hebrew: Hebrew { year: 5704, month: 7, day: 8 },
+        hindu_solar: HinduSolar { year: 5044, month: 6, day: 23 },
+        hindu_lunisolar: HinduLunisolar { year: 5044, month: 7, leap_month: false, day: 9 },
+        long_count: LongCount { baktun: 12, katun: 16, tun: 9, uinal: 14, kin: 2 },
+        haab: Haab { month: 9, day: 10 },
+        tzolkin: Tzolkin { number: 11, name: 2 },
+        bali_powukon: BaliPowukon { luang: true, dwiwara: 2, triwara: 2, caturwara: 4, pancawara: 1, sadwara: 2, saptawara: 5, asatawara: 4, sangawara: 8, dasawara: 4 }
+    },
+    SampleDate {
+        fixed: 727274,
+        gregorian: Gregorian { year: 1992, month: 3, day: 17 },
+        julian: Julian { year: 1992, month: 3, day: 4 },
+        roman: Roman { year: 1992, month: 3, event: Event::Nones, count: 4, leap: false },
+        coptic: CopticEthiopic { year: 1708, month: 7, day: 8 },
+        ethiopic: CopticEthiopic { year: 1984, month: 7, day: 8 },
+        iso: Iso { year: 1992, week: 12, day: 2 },
+        islamic: Islamic { year: 1412, month: 9, day: 13 },
+        hebrew: Hebrew { year: 5752, month: 13, day: 12 },
+        hindu_solar: HinduSolar { year: 5092, month: 12, day: 2 },
+        hindu_lunisolar: HinduLunisolar { year: 5092, month: 12, leap_month: false, day: 14 },
+        long_count: LongCount { baktun: 12, katun: 18, tun: 18, uinal: 16, kin: 16 },
+        haab: Haab { month: 18, day: 4 },
+        tzolkin: Tzolkin { number: 12, name: 16 },
+        bali_powukon: BaliPowukon { luang: true, dwiwara: 2, triwara: 2, caturwara: 2, pancawara: 5, sadwara: 2, saptawara: 3, asatawara: 2, sangawara: 8, dasawara: 2 }
+    },
+    SampleDate {
+        fixed: 728714,
+        gregorian: Gregorian { year: 1996, month: 2, day: 25 },
+        julian: Julian { year: 1996, month: 2, day: 12 },
+        roman: Roman { year: 1996, month: 2, event: Event::Ides, count: 2, leap: false },
+        coptic: CopticEthiopic { year: 1712, month: 6, day: 17 },
+        ethiopic: CopticEthiopic { year: 1988, month: 6, day: 17 },
+        iso: Iso { year: 1996, week: 8, day: 7 },
+        islamic: Islamic { year: 1416, month: 10, day: 5 },
+        hebrew: Hebrew { year: 5756, month: 12, day: 5 },
+        hindu_solar: HinduSolar { year: 5096, month: 11, day: 11 },
+        hindu_lunisolar: HinduLunisolar { year: 5096, month: 12, leap_month: false, day: 7 },
+        long_count: LongCount { baktun: 12, katun: 19, tun: 2, uinal: 16, kin: 16 },
+        haab: Haab { month: 17, day: 4 },
+        tzolkin: Tzolkin { number: 9, name: 16 },
+        bali_powukon: BaliPowukon { luang: true, dwiwara: 2, triwara: 2, caturwara: 4, pancawara: 5, sadwara: 2, saptawara: 1, asatawara: 4, sangawara: 5, dasawara: 4 }
+    },
+    SampleDate {
+        fixed: 744313,
+        gregorian: Gregorian { year: 2038, month: 11, day: 10 },
+        julian: Julian { year: 2038, month: 10, day: 28 },
+        roman: Roman { year: 2038, month: 11, event: Event::Kalends, count: 5, leap: false },
+        coptic: CopticEthiopic { year: 1755, month: 3, day: 1 },
+        ethiopic: CopticEthiopic { year: 2031, month: 3, day: 1 },
+        iso: Iso { year: 2038, week: 45, day: 3 },
+        islamic: Islamic { year: 1460, month: 10, day: 12 },
+        hebrew: Hebrew { year: 5799, month: 8, day: 12 },
+        hindu_solar: HinduSolar { year: 5139, month: 7, day: 26 },
+        hindu_lunisolar: HinduLunisolar { year: 5139, month: 8, leap_month: false, day: 14 },
+        long_count: LongCount { baktun: 13, katun: 1, tun: 6, uinal: 4, kin: 15 },
+        haab: Haab { month: 12, day: 8 },
+        tzolkin: Tzolkin { number: 8, name: 15 },
+        bali_powukon: BaliPowukon { luang: true, dwiwara: 2, triwara: 1, caturwara: 3, pancawara: 4, sadwara: 1, saptawara: 4, asatawara: 7, sangawara: 1, dasawara: 2 }
+    },
+    SampleDate {
+        fixed: 764652,
+        gregorian: Gregorian { year: 2094, month: 7, day: 18 },
+        julian: Julian { year: 2094, month: 7, day: 5 },
+        roman: Roman { year: 2094, month: 7, event: Event::Nones, count: 3, leap: false },
+        coptic: CopticEthiopic { year: 1810, month: 11, day: 11 },
+        ethiopic: CopticEthiopic { year: 2086, month: 11, day: 11 },
+        iso: Iso { year: 2094, week: 28, day: 7 },
+        islamic: Islamic { year: 1518, month: 3, day: 5 },
+        hebrew: Hebrew { year: 5854, month: 5, day: 5 },
+        hindu_solar: HinduSolar { year: 5195, month: 4, day: 2 },
+        hindu_lunisolar: HinduLunisolar { year: 5195, month: 4, leap_month: false, day: 6 },
+        long_count: LongCount { baktun: 13, katun: 4, tun: 2, uinal: 13, kin: 14 },
+        haab: Haab { month: 7, day: 7 },
+        tzolkin: Tzolkin { number: 2, name: 14 },
+        bali_powukon: BaliPowukon { luang: false, dwiwara: 1, triwara: 3, caturwara: 4, pancawara: 3, sadwara: 6, saptawara: 1, asatawara: 8, sangawara: 6, dasawara: 3 }
+    }
+];
@@ -0,0 +1,165 @@
+//! packs dates of any calendar into a u64 for storing lots of them
+//!
+//! the top byte is a tag for the calendar and the fields of the date follow it, most significant
+//! first. the tags and the layout of every calendar never change so stored dates stay readable.
+//! within a calendar the fields are stored so that the order of the codes, or of their big endian
+//! bytes, is the order of the dates: years are offset so negative ones come first, hebrew months
+//! count from tishri, roman counts down to their event and leap months come before the month
+//! they are named after. the cyclic calendars are ordered by their fields as they have no years
+//!
+//! encoding only checks that every field fits and is in `Calendar::field_range`, decoding also
+//! checks that the date is one of its calendar so a corrupted code doesnt turn into a date
+
+use calendar::{Calendar, Date, DateError, ALL};
+use core::error::Error;
+use core::fmt;
+
+/// bits below the tag that the fields can use
+const FIELD_BITS: u32 = 56;
+
+/// why a date cant be encoded or a code cant be decoded
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EncodingError {
+    /// the top byte isnt the tag of any calendar
+    UnknownTag(u8),
+    /// the field doesnt fit in the bits the calendar has for it or is outside its field range
+    FieldOutOfRange(&'static str),
+    /// bits between the tag and the fields are set
+    UnusedBits,
+    /// the fields decode to a date that isnt in its calendar
+    InvalidDate(DateError)
+}
+
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EncodingError::UnknownTag(tag) => write!(f, "{} is not the tag of a calendar", tag),
+            EncodingError::FieldOutOfRange(field) => write!(f, "{} does not fit in its bits", field),
+            EncodingError::UnusedBits => write!(f, "bits no field uses are set"),
+            EncodingError::InvalidDate(error) => write!(f, "the date is invalid: {}", error)
+        }
+    }
+}
+
+impl Error for EncodingError {}
+
+impl From<DateError> for EncodingError {
+    fn from(error: DateError) -> EncodingError {
+        EncodingError::InvalidDate(error)
+    }
+}
+
+/// the tag of a calendar, 0 is never one so a zeroed code is never a date
+pub fn tag(calendar: Calendar) -> u8 {
+    match calendar {
+        Calendar::Gregorian => 1,
+        Calendar::Julian => 2,
+        Calendar::Roman => 3,
+        Calendar::Coptic => 4,
+        Calendar::Ethiopic => 5,
+        Calendar::Iso => 6,
+        Calendar::Islamic => 7,
+        Calendar::Hebrew => 8,
+        Calendar::HinduSolar => 9,
+        Calendar::HinduLunisolar => 10,
+        Calendar::LongCount => 11,
+        Calendar::Haab => 12,
+        Calendar::Tzolkin => 13,
+        Calendar::BaliPowukon => 14
+    }
+}
+
+pub fn calendar_from_tag(tag: u8) -> Option<Calendar> {
+    ALL.iter().cloned().find(|&calendar| self::tag(calendar) == tag)
+}
+
+/// the bits of each field in the order of `Calendar::fields`
+fn widths(calendar: Calendar) -> &'static [u32] {
+    match calendar {
+        Calendar::Gregorian | Calendar::Julian | Calendar::Coptic | Calendar::Ethiopic
+            | Calendar::Islamic | Calendar::Hebrew | Calendar::HinduSolar => &[32, 4, 5],
+        Calendar::Roman => &[32, 4, 2, 5, 1],
+        Calendar::Iso => &[32, 6, 3],
+        Calendar::HinduLunisolar => &[32, 4, 1, 5],
+        Calendar::LongCount => &[32, 5, 5, 5, 5],
+        Calendar::Haab => &[5, 5],
+        Calendar::Tzolkin => &[4, 5],
+        Calendar::BaliPowukon => &[1, 2, 2, 3, 3, 3, 3, 4, 4, 4]
+    }
+}
+
+/// the stored value of a field, chosen so stored values sort like the dates
+fn key(calendar: Calendar, field: &str, value: i32, bits: u32) -> Option<u64> {
+    let key = match (calendar, field) {
+        (_, "year") | (Calendar::LongCount, "baktun") => return Some((value as u32 ^ 0x8000_0000) as u64),
+        // tishri starts the year so it is 0 and elul, the month before it, is 12
+        (Calendar::Hebrew, "month") if (1..=13).contains(&value) => (value + 6) % 13,
+        // the higher the count the further before its kalends, nones or ides the day is
+        (Calendar::Roman, "count") if (1..32).contains(&value) => 31 - value,
+        (Calendar::HinduLunisolar, "leap_month") => 1 - value,
+        (Calendar::Hebrew, "month") | (Calendar::Roman, "count") => return None,
+        _ => value
+    };
+    if key < 0 || key as u64 >= 1 << bits {
+        return None;
+    }
+    Some(key as u64)
+}
+
+/// undoes `key`
+fn value(calendar: Calendar, field: &str, key: u64) -> Option<i32> {
+    Some(match (calendar, field) {
+        (_, "year") | (Calendar::LongCount, "baktun") => (key as u32 ^ 0x8000_0000) as i32,
+        (Calendar::Hebrew, "month") if key < 13 => (key as i32 + 6) % 13 + 1,
+        (Calendar::Hebrew, "month") => return None,
+        (Calendar::Roman, "count") => 31 - key as i32,
+        (Calendar::HinduLunisolar, "leap_month") => 1 - key as i32,
+        _ => key as i32
+    })
+}
+
+/// packs a date into the calendars tag followed by its fields
+pub fn encode(date: &Date) -> Result<u64, EncodingError> {
+    let calendar = date.calendar();
+    let mut code = tag(calendar) as u64;
+    let mut used = 0;
+    for ((&field, &bits), value) in calendar.fields().iter().zip(widths(calendar)).zip(date.field_values()) {
+        let key = Some(value).filter(|value| calendar.field_range(field).contains(value)).and_then(|value| key(calendar, field, value, bits));
+        code = code << bits | key.ok_or(EncodingError::FieldOutOfRange(field))?;
+        used += bits;
+    }
+    Ok(code << (FIELD_BITS - used))
+}
+
+/// unpacks a code of `encode` and checks the date is one of its calendar
+pub fn decode(code: u64) -> Result<Date, EncodingError> {
+    let tag = (code >> FIELD_BITS) as u8;
+    let calendar = calendar_from_tag(tag).ok_or(EncodingError::UnknownTag(tag))?;
+    let fields = calendar.fields();
+    let widths = widths(calendar);
+    let mut shift = FIELD_BITS;
+    let mut values = [0; 10];
+    for (i, (&field, &bits)) in fields.iter().zip(widths).enumerate() {
+        shift -= bits;
+        // a year far enough out could overflow when the date is checked
+        values[i] = value(calendar, field, code >> shift & ((1 << bits) - 1))
+            .filter(|value| calendar.field_range(field).contains(value))
+            .ok_or(EncodingError::FieldOutOfRange(field))?;
+    }
+    if code & ((1 << shift) - 1) != 0 {
+        return Err(EncodingError::UnusedBits);
+    }
+    // only a roman event of 0 cant be made into a date at all
+    let date = calendar.date_from_fields(&values[..fields.len()]).ok_or(EncodingError::FieldOutOfRange("event"))?;
+    date.validate()?;
+    Ok(date)
+}
+
+/// the code as big endian bytes, which compare like the dates
+pub fn to_bytes(date: &Date) -> Result<[u8; 8], EncodingError> {
+    encode(date).map(u64::to_be_bytes)
+}
+
+pub fn from_bytes(bytes: [u8; 8]) -> Result<Date, EncodingError> {
+    decode(u64::from_be_bytes(bytes))
+}
@@ -0,0 +1,130 @@
+//! the holidays of a gregorian year from the holiday functions of every calendar
+
+use common::RD;
+use calendar::normalize_name;
+use arithmetical::gregorian::{self, fixed_from_gregorian, Gregorian};
+use arithmetical::julian::julian_in_gregorian;
+use arithmetical::ecclesiastical::{easter, orthodox_easter};
+use arithmetical::hebrew::{self, hebrew_in_gregorian};
+use arithmetical::islamic::islamic_in_gregorian;
+use alloc::vec::Vec;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum Tradition {
+    Us,
+    Christian,
+    Orthodox,
+    Jewish,
+    Islamic
+}
+
+pub const TRADITIONS: [Tradition; 5] = [Tradition::Us, Tradition::Christian, Tradition::Orthodox, Tradition::Jewish, Tradition::Islamic];
+
+impl Tradition {
+    pub fn name(self) -> &'static str {
+        match self {
+            Tradition::Us => "us",
+            Tradition::Christian => "christian",
+            Tradition::Orthodox => "orthodox",
+            Tradition::Jewish => "jewish",
+            Tradition::Islamic => "islamic"
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Tradition> {
+        let name = normalize_name(name);
+        let alias = match name.as_str() {
+            "american" | "usa" => Some(Tradition::Us),
+            "western" | "catholic" | "protestant" => Some(Tradition::Christian),
+            "hebrew" => Some(Tradition::Jewish),
+            "muslim" => Some(Tradition::Islamic),
+            _ => None
+        };
+        TRADITIONS.iter().cloned().find(|tradition| tradition.name() == name).or(alias)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Holiday {
+    pub name: &'static str,
+    pub tradition: Tradition,
+    pub date: RD
+}
+
+/// the holidays of a tradition in a gregorian year in no particular order
+fn holidays_of(tradition: Tradition, year: i32) -> Vec<(&'static str, RD)> {
+    let gregorian = |month: i32, day: i32| fixed_from_gregorian(Gregorian { year, month, day });
+    let mut holidays = Vec::new();
+    match tradition {
+        Tradition::Us => holidays.extend_from_slice(&[
+            ("New Year's Day", gregorian(1, 1)),
+            ("Memorial Day", gregorian::memorial_day(year)),
+            ("Independence Day", gregorian::independence_day(year)),
+            ("Labor Day", gregorian::labor_day(year)),
+            ("Election Day", gregorian::election_day(year)),
+            ("Thanksgiving", gregorian::thanksgiving(year))
+        ]),
+        Tradition::Christian => {
+            let easter = easter(year);
+            holidays.extend_from_slice(&[
+                ("Ash Wednesday", easter - 46),
+                ("Palm Sunday", easter - 7),
+                ("Good Friday", easter - 2),
+                ("Easter", easter),
+                ("Ascension", easter + 39),
+                ("Pentecost", easter + 49),
+                ("Christmas", gregorian(12, 25))
+            ]);
+        },
+        Tradition::Orthodox => {
+            let easter = orthodox_easter(year);
+            holidays.extend_from_slice(&[
+                ("Orthodox Good Friday", easter - 2),
+                ("Orthodox Easter", easter),
+                ("Orthodox Pentecost", easter + 49)
+            ]);
+            holidays.extend(julian_in_gregorian(12, 25, year).map(|date| ("Orthodox Christmas", date)));
+        },
+        Tradition::Jewish => {
+            holidays.extend_from_slice(&[
+                ("Purim", hebrew::purim(year)),
+                ("Ta'anit Esther", hebrew::taanit_esther(year)),
+                ("Passover", hebrew::passover(year)),
+                ("Yom HaShoah", hebrew::yom_hashoa(year)),
+                ("Yom HaZikaron", hebrew::yom_hazikaron(year)),
+                ("Tisha B'Av", hebrew::tisha_bav(year)),
+                ("Yom Kippur", hebrew::yom_kippur(year)),
+                ("Hanukkah", hebrew::hanukkah(year)),
+                ("Sh'eilah", hebrew::sheela(year))
+            ]);
+            holidays.extend(hebrew_in_gregorian(3, 6, year).into_iter().map(|date| ("Shavuot", date)));
+            holidays.extend(hebrew_in_gregorian(7, 1, year).into_iter().map(|date| ("Rosh Hashanah", date)));
+            holidays.extend(hebrew_in_gregorian(7, 15, year).into_iter().map(|date| ("Sukkot", date)));
+            holidays.extend(hebrew::tzom_tevet(year).into_iter().map(|date| ("Tzom Tevet", date)));
+            holidays.extend(hebrew::birkath_hachama(year).map(|date| ("Birkath HaHama", date)));
+        },
+        Tradition::Islamic => {
+            // islamic years are shorter than gregorian ones so these can come twice in a year
+            let dates = [
+                ("Islamic New Year", 1, 1),
+                ("Mawlid", 3, 12),
+                ("Ramadan", 9, 1),
+                ("Eid al-Fitr", 10, 1),
+                ("Eid al-Adha", 12, 10)
+            ];
+            for &(name, month, day) in dates.iter() {
+                holidays.extend(islamic_in_gregorian(month, day, year).into_iter().map(|date| (name, date)));
+            }
+        }
+    }
+    holidays
+}
+
+/// the holidays of the traditions in a gregorian year sorted by date
+pub fn holidays_in_gregorian_year(year: i32, traditions: &[Tradition]) -> Vec<Holiday> {
+    let mut holidays: Vec<Holiday> = traditions.iter()
+        .flat_map(|&tradition| holidays_of(tradition, year).into_iter().map(move |(name, date)| Holiday { name, tradition, date }))
+        .collect();
+    holidays.sort_by_key(|holiday| holiday.date);
+    holidays
+}
@@ -0,0 +1,84 @@
+//! icalendar (rfc 5545) feeds of computed dates, and reading the events of a feed with
+//! recurrence rules in other calendars (rfc 7529)
+
+pub mod reader;
+pub mod rrule;
+pub mod writer;
+
+pub use self::reader::{read, ReadError, VEvent};
+pub use self::rrule::{Rule, Skip};
+pub use self::writer::{Event, Feed};
+
+use arithmetical::gregorian::{fixed_from_gregorian, gregorian_from_fixed, Gregorian};
+use calendar::Date;
+use common::RD;
+use alloc::string::String;
+
+/// a date as an icalendar DATE value, yyyymmdd
+pub fn date_value(date: RD) -> String {
+    let Gregorian { year, month, day } = gregorian_from_fixed(date);
+    format!("{:04}{:02}{:02}", year, month, day)
+}
+
+/// the date of a DATE or DATE-TIME value, the time and time zone of a DATE-TIME are ignored
+pub fn fixed_from_date_value(value: &str) -> Option<RD> {
+    let digits = value.split('T').next().unwrap_or("");
+    if digits.len() != 8 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let number = |range: core::ops::Range<usize>| digits[range].parse::<i32>().ok();
+    let date = Gregorian { year: number(0..4)?, month: number(4..6)?, day: number(6..8)? };
+    Date::Gregorian(date).validate().ok()?;
+    Some(fixed_from_gregorian(date))
+}
+
+/// escapes a TEXT value
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {},
+            c => escaped.push(c)
+        }
+    }
+    escaped
+}
+
+/// the text of an escaped TEXT value
+pub fn unescape(text: &str) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') | Some('N') => unescaped.push('\n'),
+            Some(c) => unescaped.push(c),
+            None => unescaped.push('\\')
+        }
+    }
+    unescaped
+}
+
+/// folds a content line so no line is longer than 75 octets, without splitting a character,
+/// and ends it with CRLF
+pub fn fold(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / 70 * 3 + 2);
+    let mut length = 0;
+    for c in line.chars() {
+        if length + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            length = 1;
+        }
+        folded.push(c);
+        length += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
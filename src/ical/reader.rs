@@ -0,0 +1,216 @@
+//! reads the events of a VCALENDAR as all day events
+//!
+//! only the uid, summary and description of an event are kept with the dates of its DTSTART,
+//! RRULE, RDATE and EXDATE, times and time zones are dropped. other components like VTODO and
+//! VTIMEZONE and the ones inside events like VALARM are skipped
+
+use common::RD;
+use recurrence::occurrences_between;
+use ical::{fixed_from_date_value, unescape};
+use ical::rrule::Rule;
+use core::error::Error;
+use core::fmt;
+use alloc::vec::Vec;
+use alloc::string::{String, ToString};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReadError {
+    pub line: usize,
+    pub message: String
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl Error for ReadError {}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VEvent {
+    pub uid: Option<String>,
+    pub summary: Option<String>,
+    pub description: Option<String>,
+    pub start: RD,
+    pub rule: Option<Rule>,
+    /// the RDATE dates
+    pub dates: Vec<RD>,
+    /// the EXDATE dates
+    pub exceptions: Vec<RD>
+}
+
+impl VEvent {
+    /// the dates of the event in [start, end] sorted, its start is always one like in rfc 5545
+    pub fn occurrences_between(&self, start: RD, end: RD) -> Vec<RD> {
+        let mut dates = match self.rule {
+            Some(ref rule) => {
+                let recurrence = rule.recurrence(self.start).expect("Rules are checked when read").except(&self.exceptions);
+                occurrences_between(&recurrence, start, end)
+            },
+            None => Vec::new()
+        };
+        dates.push(self.start);
+        dates.extend_from_slice(&self.dates);
+        dates.retain(|&date| start <= date && date <= end && !self.exceptions.contains(&date));
+        dates.sort();
+        dates.dedup();
+        dates
+    }
+}
+
+/// a content line, `NAME;PARAM=VALUE:value`
+struct Property<'a> {
+    name: String,
+    params: Vec<(String, String)>,
+    value: &'a str
+}
+
+impl<'a> Property<'a> {
+    fn parse(line: &'a str) -> Option<Property<'a>> {
+        // colons and semicolons inside quoted parameter values dont count
+        let mut quoted = false;
+        let mut parts = Vec::new();
+        let mut part_start = 0;
+        for (i, c) in line.char_indices() {
+            match c {
+                '"' => quoted = !quoted,
+                ';' if !quoted => {
+                    parts.push(&line[part_start..i]);
+                    part_start = i + 1;
+                },
+                ':' if !quoted => {
+                    parts.push(&line[part_start..i]);
+                    let name = parts[0].to_ascii_uppercase();
+                    let params = parts[1..].iter().map(|param| {
+                        let (name, value) = param.split_once('=').unwrap_or((param, ""));
+                        (name.to_ascii_uppercase(), value.trim_matches('"').to_string())
+                    }).collect();
+                    return if name.is_empty() { None } else { Some(Property { name, params, value: &line[i + 1..] }) };
+                },
+                _ => {}
+            }
+        }
+        None
+    }
+
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params.iter().find(|param| param.0 == name).map(|param| param.1.as_str())
+    }
+}
+
+/// the logical lines of a feed with the line they start on, continuation lines starting with
+/// a space or tab are joined to the line before
+fn unfold(text: &str) -> Vec<(usize, String)> {
+    let mut lines: Vec<(usize, String)> = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        match (line.strip_prefix([' ', '\t']), lines.last_mut()) {
+            (Some(rest), Some(last)) => last.1.push_str(rest),
+            _ if line.trim().is_empty() => {},
+            _ => lines.push((i + 1, line.to_string()))
+        }
+    }
+    lines
+}
+
+/// an event being read, with the lines of its BEGIN and RRULE for errors
+struct Partial {
+    line: usize,
+    uid: Option<String>,
+    summary: Option<String>,
+    description: Option<String>,
+    start: Option<RD>,
+    rule: Option<(usize, Rule)>,
+    dates: Vec<RD>,
+    exceptions: Vec<RD>
+}
+
+impl Partial {
+    fn new(line: usize) -> Partial {
+        Partial { line, uid: None, summary: None, description: None, start: None, rule: None, dates: Vec::new(), exceptions: Vec::new() }
+    }
+
+    fn set(&mut self, line: usize, property: &Property) -> Result<(), ReadError> {
+        let error = |message: String| ReadError { line, message };
+        let dates = || property.value.split(',')
+            .map(|value| fixed_from_date_value(value).ok_or_else(|| error(format!("{} isnt a date in {}", value, property.name))))
+            .collect::<Result<Vec<_>, _>>();
+        match property.name.as_str() {
+            "UID" => self.uid = Some(property.value.to_string()),
+            "SUMMARY" => self.summary = Some(unescape(property.value)),
+            "DESCRIPTION" => self.description = Some(unescape(property.value)),
+            "DTSTART" => self.start = Some(fixed_from_date_value(property.value).ok_or_else(|| error(format!("{} isnt a date", property.value)))?),
+            "RRULE" if self.rule.is_some() => return Err(error("only one RRULE in an event is supported".to_string())),
+            "RRULE" => self.rule = Some((line, Rule::parse(property.value).map_err(error)?)),
+            "RDATE" if property.param("VALUE").is_some_and(|value| value.eq_ignore_ascii_case("PERIOD")) => {
+                // a period starts on its first date
+                for period in property.value.split(',') {
+                    let start = period.split('/').next().unwrap_or("");
+                    self.dates.push(fixed_from_date_value(start).ok_or_else(|| error(format!("{} isnt a period", period)))?);
+                }
+            },
+            "RDATE" => self.dates.extend(dates()?),
+            "EXDATE" => self.exceptions.extend(dates()?),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<VEvent, ReadError> {
+        let start = self.start.ok_or_else(|| ReadError { line: self.line, message: "the event has no DTSTART".to_string() })?;
+        let rule = match self.rule {
+            Some((line, rule)) => {
+                rule.recurrence(start).map_err(|message| ReadError { line, message })?;
+                Some(rule)
+            },
+            None => None
+        };
+        Ok(VEvent {
+            uid: self.uid,
+            summary: self.summary,
+            description: self.description,
+            start,
+            rule,
+            dates: self.dates,
+            exceptions: self.exceptions
+        })
+    }
+}
+
+/// the events of an icalendar feed in the order they are in
+pub fn read(text: &str) -> Result<Vec<VEvent>, ReadError> {
+    let mut events = Vec::new();
+    let mut components: Vec<String> = Vec::new();
+    let mut event: Option<Partial> = None;
+    let mut last_line = 0;
+    for (line, text) in unfold(text) {
+        last_line = line;
+        let property = Property::parse(&text).ok_or_else(|| ReadError { line, message: format!("{} isnt a content line", text) })?;
+        match property.name.as_str() {
+            "BEGIN" => {
+                let component = property.value.to_ascii_uppercase();
+                if component == "VEVENT" && event.is_none() {
+                    event = Some(Partial::new(line));
+                }
+                components.push(component);
+            },
+            "END" => {
+                let component = property.value.to_ascii_uppercase();
+                if components.pop().as_ref() != Some(&component) {
+                    return Err(ReadError { line, message: format!("END:{} without BEGIN:{}", property.value, property.value) });
+                }
+                if component == "VEVENT" && !components.iter().any(|component| component == "VEVENT") {
+                    events.push(event.take().expect("Events are begun").finish()?);
+                }
+            },
+            _ => match (components.last(), event.as_mut()) {
+                (Some(component), Some(event)) if component == "VEVENT" => event.set(line, &property)?,
+                _ => {}
+            }
+        }
+    }
+    match components.last() {
+        Some(component) => Err(ReadError { line: last_line, message: format!("BEGIN:{} without END:{}", component, component) }),
+        None => Ok(events)
+    }
+}
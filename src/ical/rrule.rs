@@ -0,0 +1,239 @@
+//! RRULE values, with the RSCALE and SKIP parts of rfc 7529 for rules in other calendars
+//!
+//! `RSCALE=HEBREW;FREQ=YEARLY;BYMONTH=5L;BYMONTHDAY=8;SKIP=FORWARD` is 8 adar i in leap years
+//! and 8 adar in the others. like in rfc 7529 hebrew months are numbered from tishri, with
+//! adar i the leap month 5L and adar (adar ii in leap years) month 6
+
+use calendar::{Calendar, Date};
+use common::{divide, RD};
+use arithmetical::hebrew::is_hebrew_leap_year;
+use recurrence::{Frequency, Recurrence, WeekdayRule};
+use ical::fixed_from_date_value;
+use alloc::vec::Vec;
+use alloc::string::String;
+
+/// what a yearly rule does with a date that doesnt exist in some years, like 30 heshvan or 29 february
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Skip {
+    /// leaves those years out
+    Omit,
+    /// the last day of the month, or the month before a missing leap month
+    Backward,
+    /// the first day of the next month, or the month after a missing leap month
+    Forward
+}
+
+/// a month of a BYMONTH rule, `leap` for a leap month like 5L
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MonthRule {
+    pub month: i32,
+    pub leap: bool
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rule {
+    pub scale: Calendar,
+    pub frequency: Frequency,
+    pub interval: i32,
+    pub count: Option<usize>,
+    pub until: Option<RD>,
+    pub by_month: Vec<MonthRule>,
+    pub by_month_day: Vec<i32>,
+    pub by_day: Vec<WeekdayRule>,
+    pub skip: Skip
+}
+
+/// the calendar an RSCALE names, of the ones that have conversions here
+pub fn scale_from_name(name: &str) -> Option<Calendar> {
+    match name.to_ascii_uppercase().as_str() {
+        "GREGORIAN" => Some(Calendar::Gregorian),
+        "JULIAN" => Some(Calendar::Julian),
+        "HEBREW" => Some(Calendar::Hebrew),
+        // the arithmetical islamic calendar is the civil one
+        "ISLAMIC-CIVIL" => Some(Calendar::Islamic),
+        "COPTIC" => Some(Calendar::Coptic),
+        "ETHIOPIC" => Some(Calendar::Ethiopic),
+        _ => None
+    }
+}
+
+const WEEKDAYS: [&str; 7] = ["SU", "MO", "TU", "WE", "TH", "FR", "SA"];
+
+fn weekday_rule(text: &str) -> Option<WeekdayRule> {
+    let split = text.len().checked_sub(2)?;
+    let weekday = WEEKDAYS.iter().position(|&name| text[split..].eq_ignore_ascii_case(name))? as i32;
+    let nth = match &text[..split] {
+        "" => None,
+        // the nth weekday is of the month, and no month has a 6th monday
+        nth => Some(nth.trim_start_matches('+').parse::<i32>().ok().filter(|&nth| nth != 0 && nth.abs() <= 5)?)
+    };
+    Some(WeekdayRule { nth, weekday })
+}
+
+/// the rfc 7529 month of a date
+fn month_rule(date: Date) -> MonthRule {
+    let month = date.field("month").expect("Scales have months");
+    match date {
+        Date::Hebrew(hebrew) if hebrew.month == 12 && is_hebrew_leap_year(hebrew.year) => MonthRule { month: 5, leap: true },
+        Date::Hebrew(hebrew) if hebrew.month >= 12 => MonthRule { month: 6, leap: false },
+        Date::Hebrew(hebrew) if hebrew.month >= 7 => MonthRule { month: month - 6, leap: false },
+        Date::Hebrew(_) => MonthRule { month: month + 6, leap: false },
+        _ => MonthRule { month, leap: false }
+    }
+}
+
+/// the month number of the calendar for an rfc 7529 month, if there is that month in the year
+fn month_in_year(scale: Calendar, year: i32, month: MonthRule) -> Option<i32> {
+    if scale == Calendar::Hebrew {
+        let leap_year = is_hebrew_leap_year(year);
+        return match (month.month, month.leap) {
+            (5, true) if leap_year => Some(12),
+            (6, false) if leap_year => Some(13),
+            (6, false) => Some(12),
+            (1..=5, false) => Some(month.month + 6),
+            (7..=12, false) => Some(month.month - 6),
+            _ => None
+        };
+    }
+    if month.leap {
+        return None;
+    }
+    scale.date_from_fields(&[year, month.month, 1]).filter(|date| date.validate().is_ok()).map(|_| month.month)
+}
+
+/// the date of a month and day in a year after skipping, negative days counting back from the end of the month
+fn date_in_year(scale: Calendar, year: i32, month: MonthRule, day: i32, skip: Skip) -> Option<RD> {
+    let number = match (month_in_year(scale, year, month), skip) {
+        (Some(number), _) => number,
+        (None, Skip::Backward) if month.leap => return date_in_year(scale, year, MonthRule { month: month.month, leap: false }, day, skip),
+        (None, Skip::Forward) if month.leap => return date_in_year(scale, year, MonthRule { month: month.month + 1, leap: false }, day, skip),
+        (None, _) => return None
+    };
+    let first = scale.date_from_fields(&[year, number, 1]).expect("Scales have a year, month and day");
+    let start = first.to_fixed().expect("Scales have R.D. dates");
+    let length = first.days_in_month().expect("Scales have month lengths");
+    let day = if day < 0 { length + 1 + day } else { day };
+    if day >= 1 && day <= length {
+        return Some(start + day - 1);
+    }
+    match skip {
+        _ if day < 1 => None,
+        Skip::Omit => None,
+        Skip::Backward => Some(start + length - 1),
+        Skip::Forward => Some(start + length)
+    }
+}
+
+impl Rule {
+    pub fn parse(value: &str) -> Result<Rule, String> {
+        let mut frequency = None;
+        let mut rule = Rule {
+            scale: Calendar::Gregorian,
+            frequency: Frequency::Yearly,
+            interval: 1,
+            count: None,
+            until: None,
+            by_month: Vec::new(),
+            by_month_day: Vec::new(),
+            by_day: Vec::new(),
+            skip: Skip::Omit
+        };
+        for part in value.split(';') {
+            let (name, value) = part.split_once('=').ok_or_else(|| format!("{} isnt a NAME=VALUE part", part))?;
+            let name = name.to_ascii_uppercase();
+            let invalid = || format!("{} isnt a valid {}", value, name);
+            let number = |text: &str| text.trim_start_matches('+').parse::<i32>().map_err(|_| invalid());
+            match name.as_str() {
+                "FREQ" => frequency = Some(match value.to_ascii_uppercase().as_str() {
+                    "DAILY" => Frequency::Daily,
+                    "WEEKLY" => Frequency::Weekly,
+                    "MONTHLY" => Frequency::Monthly,
+                    "YEARLY" => Frequency::Yearly,
+                    _ => return Err(format!("FREQ={} isnt supported", value))
+                }),
+                "RSCALE" => rule.scale = scale_from_name(value).ok_or_else(|| format!("RSCALE={} isnt supported", value))?,
+                "SKIP" => rule.skip = match value.to_ascii_uppercase().as_str() {
+                    "OMIT" => Skip::Omit,
+                    "BACKWARD" => Skip::Backward,
+                    "FORWARD" => Skip::Forward,
+                    _ => return Err(invalid())
+                },
+                "INTERVAL" => rule.interval = Some(number(value)?).filter(|&interval| interval > 0).ok_or_else(invalid)?,
+                "COUNT" => rule.count = Some(value.parse().map_err(|_| invalid())?),
+                "UNTIL" => rule.until = Some(fixed_from_date_value(value).ok_or_else(invalid)?),
+                "BYMONTH" => for month in value.split(',') {
+                    let (month, leap) = match month.strip_suffix(['L', 'l']) {
+                        Some(month) => (month, true),
+                        None => (month, false)
+                    };
+                    rule.by_month.push(MonthRule { month: Some(number(month)?).filter(|&month| month > 0).ok_or_else(invalid)?, leap });
+                },
+                "BYMONTHDAY" => for day in value.split(',') {
+                    rule.by_month_day.push(Some(number(day)?).filter(|&day| day != 0 && day.abs() <= 31).ok_or_else(invalid)?);
+                },
+                "BYDAY" => for day in value.split(',') {
+                    rule.by_day.push(weekday_rule(day).ok_or_else(invalid)?);
+                },
+                // the week start only matters to BYWEEKNO
+                "WKST" => {},
+                _ => return Err(format!("{} isnt supported", name))
+            }
+        }
+        rule.frequency = frequency.ok_or("an RRULE needs a FREQ")?;
+        // the nth weekday is only of a month or a year
+        if rule.by_day.iter().any(|day| day.nth.is_some()) && !matches!(rule.frequency, Frequency::Monthly | Frequency::Yearly) {
+            return Err("BYDAY can only have an nth weekday with a MONTHLY or YEARLY RRULE".into());
+        }
+        Ok(rule)
+    }
+
+    /// the recurrence of the rule for an event starting on `start`, which is only one of its
+    /// dates if it matches the rule
+    pub fn recurrence(&self, start: RD) -> Result<Recurrence, String> {
+        let scale = self.scale;
+        let mut recurrence = if self.frequency == Frequency::Yearly && self.by_day.is_empty() {
+            // the dates of a year are computed directly so missing ones can be skipped to another day
+            let start_date = scale.from_fixed(start);
+            let start_year = start_date.field("year").expect("Scales have years");
+            let months = if self.by_month.is_empty() { vec![month_rule(start_date)] } else { self.by_month.clone() };
+            let days = if self.by_month_day.is_empty() { vec![start_date.field("day").expect("Scales have days")] } else { self.by_month_day.clone() };
+            let (interval, skip) = (self.interval, self.skip);
+            // a date skipped forward can fall in the next year so the interval is checked here
+            Recurrence::each_year_dates(scale, start, move |year| {
+                if divide(year - start_year, interval).1 != 0 {
+                    return Vec::new();
+                }
+                months.iter()
+                    .flat_map(|&month| days.iter().filter_map(move |&day| date_in_year(scale, year, month, day, skip)))
+                    .collect()
+            })
+        } else {
+            if self.skip != Skip::Omit && !self.by_month_day.is_empty() {
+                return Err("SKIP only works with a yearly RRULE without BYDAY".into());
+            }
+            let by_month = self.by_month.iter().map(|&month| match (scale, month.leap, month.month) {
+                (Calendar::Hebrew, false, 1..=5) => Ok(month.month + 6),
+                (Calendar::Hebrew, false, 7..=12) => Ok(month.month - 6),
+                // adar is a different month number in leap years
+                (Calendar::Hebrew, _, _) => Err(String::from("hebrew BYMONTH=5L or 6 only works with a yearly RRULE without BYDAY")),
+                // a leap month that no year has
+                (_, true, _) => Ok(0),
+                _ => Ok(month.month)
+            }).collect::<Result<Vec<_>, _>>()?;
+            Recurrence::new(scale, self.frequency, start)
+                .interval(self.interval)
+                .by_month(&by_month)
+                .by_month_day(&self.by_month_day)
+                .by_day(&self.by_day)
+        };
+        if let Some(count) = self.count {
+            // the start is the first of the count even when it isnt one of the rules dates
+            let start_matches = recurrence.occurrences().next() == Some(start);
+            recurrence = recurrence.count(if start_matches { count } else { count.saturating_sub(1) });
+        }
+        if let Some(until) = self.until {
+            recurrence = recurrence.until(until);
+        }
+        Ok(recurrence)
+    }
+}
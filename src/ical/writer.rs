@@ -0,0 +1,147 @@
+//! writes all day events for computed dates as a VCALENDAR
+//!
+//! uids are made from a key and the date so a feed written again gives every event the same uid
+//! and calendars subscribed to it update events instead of duplicating them
+
+use calendar::{Calendar, normalize_name};
+use common::RD;
+use arithmetical::gregorian::gregorian_from_fixed;
+use holidays::Holiday;
+use ical::{date_value, escape, fold};
+use core::fmt;
+use alloc::vec::Vec;
+use alloc::string::{String, ToString};
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Event {
+    pub date: RD,
+    pub summary: String,
+    pub description: Option<String>,
+    pub categories: Vec<String>,
+    /// what the event is apart from its date, the summary unless it is set
+    pub key: String
+}
+
+impl Event {
+    pub fn new<S: Into<String>>(date: RD, summary: S) -> Event {
+        let summary = summary.into();
+        Event { date, key: summary.clone(), summary, description: None, categories: Vec::new() }
+    }
+
+    pub fn description<S: Into<String>>(mut self, description: S) -> Event {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn category<S: Into<String>>(mut self, category: S) -> Event {
+        self.categories.push(category.into());
+        self
+    }
+
+    pub fn key<S: Into<String>>(mut self, key: S) -> Event {
+        self.key = key.into();
+        self
+    }
+
+    /// `key-yyyymmdd@domain` with the key only letters and digits
+    pub fn uid(&self, domain: &str) -> String {
+        format!("{}-{}@{}", normalize_name(&self.key), date_value(self.date), domain)
+    }
+}
+
+pub struct Feed {
+    name: String,
+    domain: String,
+    stamp: RD,
+    alternates: Vec<Calendar>,
+    events: Vec<Event>
+}
+
+impl Feed {
+    /// `stamp` is the date the feed is written, which icalendar wants with every event
+    pub fn new<S: Into<String>>(name: S, stamp: RD) -> Feed {
+        Feed { name: name.into(), domain: "calecalc".to_string(), stamp, alternates: Vec::new(), events: Vec::new() }
+    }
+
+    /// the domain at the end of the uids
+    pub fn domain<S: Into<String>>(mut self, domain: S) -> Feed {
+        self.domain = domain.into();
+        self
+    }
+
+    /// adds the date in these calendars to the description of every event
+    pub fn alternates(mut self, calendars: &[Calendar]) -> Feed {
+        self.alternates.extend_from_slice(calendars);
+        self
+    }
+
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// adds an event unless it has the uid of one already added, or its date or the day after
+    /// it, which ends the event, cant be written as an icalendar date which only has years 0 to 9999
+    pub fn add(&mut self, event: Event) -> bool {
+        let writable = |date: RD| (0..=9999).contains(&gregorian_from_fixed(date).year);
+        let uid = event.uid(&self.domain);
+        if !writable(event.date) || !writable(event.date + 1) || self.events.iter().any(|added| added.uid(&self.domain) == uid) {
+            return false;
+        }
+        self.events.push(event);
+        true
+    }
+
+    /// an event with the same summary on every date, like the occurrences of a recurrence
+    /// or the yahrtzeits from `hebrew::yahrtzeit_in_gregorian`
+    pub fn add_dates<S, I>(&mut self, summary: S, dates: I) where S: Into<String>, I: IntoIterator<Item = RD> {
+        let summary = summary.into();
+        for date in dates {
+            self.add(Event::new(date, summary.clone()));
+        }
+    }
+
+    /// holidays with their tradition as the category
+    pub fn add_holidays(&mut self, holidays: &[Holiday]) {
+        for holiday in holidays {
+            let tradition = holiday.tradition.name();
+            self.add(Event::new(holiday.date, holiday.name).category(tradition).key(format!("{} {}", tradition, holiday.name)));
+        }
+    }
+
+    fn description(&self, event: &Event) -> Option<String> {
+        let mut lines: Vec<String> = event.description.iter().cloned().collect();
+        lines.extend(self.alternates.iter().map(|calendar| format!("{}: {}", calendar.name(), calendar.from_fixed(event.date))));
+        if lines.is_empty() { None } else { Some(lines.join("\n")) }
+    }
+}
+
+impl fmt::Display for Feed {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut line = |text: String| f.write_str(&fold(&text));
+        line("BEGIN:VCALENDAR".to_string())?;
+        line("VERSION:2.0".to_string())?;
+        line("PRODID:-//calecalc//calecalc//EN".to_string())?;
+        line("CALSCALE:GREGORIAN".to_string())?;
+        line(format!("X-WR-CALNAME:{}", escape(&self.name)))?;
+        let stamp = format!("{}T000000Z", date_value(self.stamp));
+        for event in &self.events {
+            line("BEGIN:VEVENT".to_string())?;
+            line(format!("UID:{}", event.uid(&self.domain)))?;
+            line(format!("DTSTAMP:{}", stamp))?;
+            line(format!("DTSTART;VALUE=DATE:{}", date_value(event.date)))?;
+            line(format!("DTEND;VALUE=DATE:{}", date_value(event.date + 1)))?;
+            line(format!("SUMMARY:{}", escape(&event.summary)))?;
+            if let Some(description) = self.description(event) {
+                line(format!("DESCRIPTION:{}", escape(&description)))?;
+            }
+            if !event.categories.is_empty() {
+                let categories: Vec<String> = event.categories.iter().map(|category| escape(category)).collect();
+                line(format!("CATEGORIES:{}", categories.join(",")))?;
+            }
+            // all day events shouldnt show as busy
+            line("TRANSP:TRANSPARENT".to_string())?;
+            line("END:VEVENT".to_string())?;
+        }
+        line("END:VCALENDAR".to_string())
+    }
+}
@@ -0,0 +1,33 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+// the older conversion modules are written in a style these lints would change
+#![allow(clippy::match_like_matches_macro, clippy::filter_next, clippy::from_over_into, clippy::manual_range_contains, clippy::needless_return)]
+
+#[cfg(feature = "std")]
+extern crate core;
+#[cfg(feature = "alloc")]
+#[cfg_attr(not(feature = "std"), macro_use)]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+pub mod anniversary;
+pub mod arithmetical;
+pub mod astronomical;
+#[cfg(feature = "alloc")]
+pub mod batch;
+#[cfg(feature = "alloc")]
+pub mod calendar;
+pub mod common;
+#[cfg(feature = "alloc")]
+pub mod conformance;
+#[cfg(feature = "alloc")]
+pub mod encoding;
+#[cfg(feature = "alloc")]
+pub mod holidays;
+#[cfg(feature = "alloc")]
+pub mod ical;
+#[cfg(feature = "alloc")]
+pub mod recurrence;
+#[cfg(feature = "alloc")]
+pub mod render;
+#[cfg(feature = "alloc")]
+pub mod search;
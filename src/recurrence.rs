@@ -0,0 +1,395 @@
+//! rrule like recurrences whose frequency and by rules are in any calendar
+//!
+//! a recurrence with no by rules repeats on the same position as its start date, so a yearly
+//! hebrew recurrence starting on 7 kislev is every 7 kislev and a monthly one is every 7th
+//! of the month. by rules of the same kind are alternatives and different kinds must all hold
+
+use calendar::{Calendar, Date};
+use common::{divide, RD, cycles_of_days::kday_on_or_before};
+use search::query::{Query, Matches, Expr, Operand, Op, Field};
+use arithmetical::hebrew::{self, Hebrew};
+use arithmetical::{mayan, balinese_powukon};
+use alloc::vec::Vec;
+use alloc::boxed::Box;
+
+/// how far an open ended recurrence is expanded, about ten thousand years
+pub const HORIZON: i32 = 3652425;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Frequency {
+    Daily,
+    Weekly,
+    /// for calendars without months a month is the same as a year
+    Monthly,
+    /// for the cyclic calendars a year is one run through the cycle
+    Yearly
+}
+
+/// a weekday (sunday is 0) optionally limited to the nth one in the month, negative n counting from the end
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct WeekdayRule {
+    pub nth: Option<i32>,
+    pub weekday: i32
+}
+
+pub struct Recurrence {
+    calendar: Calendar,
+    frequency: Frequency,
+    interval: i32,
+    start: RD,
+    by_month: Vec<i32>,
+    by_month_day: Vec<i32>,
+    by_day: Vec<WeekdayRule>,
+    by_field: Vec<(&'static str, i32)>,
+    filter: Option<Query>,
+    each_year: Option<Box<dyn Fn(i32) -> Vec<RD>>>,
+    exceptions: Vec<RD>,
+    count: Option<usize>,
+    until: Option<RD>
+}
+
+impl Recurrence {
+    pub fn new(calendar: Calendar, frequency: Frequency, start: RD) -> Recurrence {
+        Recurrence {
+            calendar,
+            frequency,
+            interval: 1,
+            start,
+            by_month: Vec::new(),
+            by_month_day: Vec::new(),
+            by_day: Vec::new(),
+            by_field: Vec::new(),
+            filter: None,
+            each_year: None,
+            exceptions: Vec::new(),
+            count: None,
+            until: None
+        }
+    }
+
+    /// every year on the date computed for each year of the calendar, for rules like
+    /// `hebrew::yahrtzeit` that move a date that doesnt exist every year
+    pub fn each_year<F>(calendar: Calendar, start: RD, date: F) -> Recurrence where F: Fn(i32) -> Option<RD> + 'static {
+        Recurrence::each_year_dates(calendar, start, move |year| date(year).into_iter().collect())
+    }
+
+    /// like `each_year` for rules with any number of dates in a year, in any order
+    pub fn each_year_dates<F>(calendar: Calendar, start: RD, dates: F) -> Recurrence where F: Fn(i32) -> Vec<RD> + 'static {
+        let mut recurrence = Recurrence::new(calendar, Frequency::Yearly, start);
+        recurrence.each_year = Some(Box::new(dates));
+        recurrence
+    }
+
+    /// every yahrtzeit from the year after the death
+    pub fn yahrtzeit(death_date: Hebrew) -> Recurrence {
+        let start = hebrew::fixed_from_hebrew(death_date) + 1;
+        Recurrence::each_year(Calendar::Hebrew, start, move |year| Some(hebrew::yahrtzeit(death_date, year)))
+    }
+
+    pub fn interval(mut self, interval: i32) -> Recurrence {
+        self.interval = interval.max(1);
+        self
+    }
+
+    pub fn by_month(mut self, months: &[i32]) -> Recurrence {
+        self.by_month.extend_from_slice(months);
+        self
+    }
+
+    /// days of the month, negative days counting back from the last day of the month
+    pub fn by_month_day(mut self, days: &[i32]) -> Recurrence {
+        self.by_month_day.extend_from_slice(days);
+        self
+    }
+
+    pub fn by_day(mut self, days: &[WeekdayRule]) -> Recurrence {
+        self.by_day.extend_from_slice(days);
+        self
+    }
+
+    /// any other field of the calendars date, like the tzolkin name or a pawukon wara
+    pub fn by_field(mut self, field: &'static str, value: i32) -> Recurrence {
+        self.by_field.push((field, value));
+        self
+    }
+
+    /// a query every occurrence has to match as well
+    pub fn filter(mut self, query: Query) -> Recurrence {
+        self.filter = Some(query);
+        self
+    }
+
+    pub fn except(mut self, dates: &[RD]) -> Recurrence {
+        self.exceptions.extend_from_slice(dates);
+        self
+    }
+
+    pub fn count(mut self, count: usize) -> Recurrence {
+        self.count = Some(count);
+        self
+    }
+
+    pub fn until(mut self, until: RD) -> Recurrence {
+        self.until = Some(until);
+        self
+    }
+
+    pub fn occurrences(&self) -> Occurrences<'_> {
+        let end = self.until.unwrap_or(self.start + HORIZON);
+        let source = match self.each_year {
+            Some(_) => Source::EachYear {
+                year: year_of(self.calendar, self.start),
+                last: year_of(self.calendar, end),
+                pending: Vec::new(),
+                previous: None
+            },
+            None => Source::Days(Query::from_expr(self.expr()).into_search(self.start, end))
+        };
+        Occurrences { recurrence: self, source, end, found: 0, month: None }
+    }
+
+    /// whether the recurrence has no by rules and repeats on the position of its start
+    fn is_implicit(&self) -> bool {
+        self.by_month.is_empty() && self.by_month_day.is_empty() && self.by_day.is_empty() && self.by_field.is_empty()
+    }
+
+    /// the by rules that only need the date itself as a query
+    fn expr(&self) -> Expr {
+        let calendar = self.calendar;
+        let field = |name: &'static str| Operand::Field(Field::Calendar(calendar, name));
+        // a by rule on a field the calendar doesnt have can never match
+        let equals = |name: &'static str, value: i32| if calendar.fields().contains(&name) {
+            Expr::Compare(field(name), Op::Eq, Operand::Value(value))
+        } else {
+            Expr::Or(Vec::new())
+        };
+        let start = calendar.from_fixed(self.start);
+        let mut exprs = Vec::new();
+
+        if self.is_implicit() {
+            let names: &[&'static str] = match (self.frequency, calendar) {
+                (Frequency::Daily, _) => &[],
+                (Frequency::Weekly, _) => {
+                    exprs.push(Expr::Compare(Operand::Field(Field::Weekday), Op::Eq, Operand::Value(divide(self.start, 7).1)));
+                    &[]
+                },
+                (Frequency::Monthly, Calendar::LongCount) => &["kin"],
+                (Frequency::Yearly, Calendar::LongCount) => &["uinal", "kin"],
+                (Frequency::Monthly, _) if has_months(calendar) => &["day"],
+                _ => calendar.fields()
+            };
+            exprs.extend(names.iter()
+                .filter(|&&name| name != "year")
+                .map(|&name| equals(name, start.field(name).expect("Field names come from the calendar"))));
+        }
+        if !self.by_month.is_empty() {
+            exprs.push(Expr::Or(self.by_month.iter().map(|&month| equals("month", month)).collect()));
+        }
+        if !self.by_month_day.is_empty() && self.by_month_day.iter().all(|&day| day > 0) {
+            exprs.push(Expr::Or(self.by_month_day.iter().map(|&day| equals("day", day)).collect()));
+        }
+        if !self.by_day.is_empty() {
+            exprs.push(Expr::Or(self.by_day.iter().map(|rule| {
+                let weekday = Expr::Compare(Operand::Field(Field::Weekday), Op::Eq, Operand::Value(rule.weekday));
+                match rule.nth {
+                    Some(nth) if nth > 0 && has_months(calendar) => Expr::And(vec![
+                        weekday,
+                        Expr::Compare(field("day"), Op::Gt, Operand::Value(7 * (nth - 1))),
+                        Expr::Compare(field("day"), Op::Le, Operand::Value(7 * nth))
+                    ]),
+                    _ => weekday
+                }
+            }).collect()));
+        }
+        for &(name, value) in &self.by_field {
+            exprs.push(equals(name, value));
+        }
+        if let Some(ref filter) = self.filter {
+            exprs.push(filter.expr().clone());
+        }
+
+        Expr::And(exprs)
+    }
+
+    /// the by rules that count back from the end of the month
+    fn matches_month_end(&self, date: RD) -> bool {
+        let same_month = |other: RD| month_of(self.calendar, date) == month_of(self.calendar, other);
+        let month_day = self.by_month_day.iter().all(|&day| day > 0) || self.by_month_day.iter().any(|&day| if day > 0 {
+            self.calendar.from_fixed(date).field("day") == Some(day)
+        } else {
+            same_month(date - day - 1) && !same_month(date - day)
+        });
+        let weekday = self.by_day.iter().all(|rule| rule.nth.is_none_or(|nth| nth > 0)) || self.by_day.iter().any(|rule| match rule.nth {
+            Some(nth) if nth < 0 => divide(date, 7).1 == rule.weekday && same_month(date - 7 * (nth + 1)) && !same_month(date - 7 * nth),
+            _ => divide(date, 7).1 == rule.weekday && self.calendar.from_fixed(date).field("day").is_none_or(|day| {
+                rule.nth.is_none_or(|nth| 7 * (nth - 1) < day && day <= 7 * nth)
+            })
+        });
+        month_day && weekday
+    }
+}
+
+/// which year a date falls in, for the long count a year is a tun and
+/// for the cyclic calendars it is how many cycles since their epoch
+fn year_of(calendar: Calendar, date: RD) -> i32 {
+    match calendar {
+        Calendar::LongCount => divide(date - mayan::EPOCH, 360).0,
+        Calendar::Haab => divide(date - mayan::haab::EPOCH, 365).0,
+        Calendar::Tzolkin => divide(date - mayan::tzolkin::EPOCH, 260).0,
+        Calendar::BaliPowukon => divide(date - balinese_powukon::EPOCH, 210).0,
+        _ => calendar.from_fixed(date).field("year").expect("Only cyclic calendars have no year")
+    }
+}
+
+fn has_months(calendar: Calendar) -> bool {
+    calendar.fields().contains(&"month") && calendar.fields().contains(&"day") && calendar != Calendar::Roman
+}
+
+/// identifies the month a date is in, for calendars without months it identifies the year
+fn month_of(calendar: Calendar, date: RD) -> (i32, i32, i32) {
+    let fields = calendar.from_fixed(date);
+    match calendar {
+        Calendar::LongCount => (0, divide(date - mayan::EPOCH, 20).0, 0),
+        _ if has_months(calendar) => (
+            fields.field("year").unwrap_or(0),
+            fields.field("month").unwrap_or(0),
+            fields.field("leap_month").unwrap_or(0)
+        ),
+        _ => (year_of(calendar, date), 0, 0)
+    }
+}
+
+/// the first day of the month after the one `date` is in, for calendars with months
+fn next_month_start(calendar: Calendar, date: RD) -> RD {
+    let fields = calendar.from_fixed(date);
+    if let (Some(day), Some(length)) = (fields.field("day"), fields.days_in_month()) {
+        return date + length - day + 1;
+    }
+    // hindu months have no rule for their length but none is shorter than 29 days
+    let month = month_of(calendar, date);
+    let mut next = if month_of(calendar, date - 1) != month { date + 29 } else { date + 1 };
+    while month_of(calendar, next) == month {
+        next += 1;
+    }
+    next
+}
+
+enum Source {
+    Days(Matches<Query>),
+    EachYear {
+        /// the next year to compute dates for
+        year: i32,
+        /// the last year there can be one in
+        last: i32,
+        /// the dates of the year not returned yet, latest first
+        pending: Vec<RD>,
+        /// a date moved to the next year can also be one of that years dates
+        previous: Option<RD>
+    }
+}
+
+/// lazily expands a recurrence into R.D. dates
+pub struct Occurrences<'a> {
+    recurrence: &'a Recurrence,
+    source: Source,
+    end: RD,
+    found: usize,
+    /// the start of the last month seen and how many months it is after the start when counting monthly intervals
+    month: Option<(RD, i32)>
+}
+
+impl<'a> Occurrences<'a> {
+    fn period(&mut self, date: RD) -> i32 {
+        let recurrence = self.recurrence;
+        let calendar = recurrence.calendar;
+        match recurrence.frequency {
+            Frequency::Daily => date - recurrence.start,
+            Frequency::Weekly => divide(kday_on_or_before(date, 1) - kday_on_or_before(recurrence.start, 1), 7).0,
+            Frequency::Yearly => year_of(calendar, date) - year_of(calendar, recurrence.start),
+            Frequency::Monthly if calendar == Calendar::LongCount => divide(date - mayan::EPOCH, 20).0 - divide(recurrence.start - mayan::EPOCH, 20).0,
+            Frequency::Monthly if !has_months(calendar) => year_of(calendar, date) - year_of(calendar, recurrence.start),
+            Frequency::Monthly => {
+                let (mut day, mut count) = self.month.unwrap_or((recurrence.start, 0));
+                loop {
+                    let next = next_month_start(calendar, day);
+                    if next > date {
+                        break;
+                    }
+                    day = next;
+                    count += 1;
+                }
+                self.month = Some((day, count));
+                count
+            }
+        }
+    }
+
+    fn next_candidate(&mut self) -> Option<RD> {
+        match self.source {
+            Source::Days(ref mut matches) => matches.next(),
+            Source::EachYear { ref mut year, last, ref mut pending, ref mut previous } => {
+                let dates_for_year = self.recurrence.each_year.as_ref().expect("Yearly source needs a date function");
+                loop {
+                    match pending.pop() {
+                        Some(date) if date > self.end => return None,
+                        Some(date) if date >= self.recurrence.start && previous.is_none_or(|previous| date > previous) => {
+                            *previous = Some(date);
+                            return Some(date);
+                        },
+                        Some(_) => {},
+                        None if *year > last => return None,
+                        None => {
+                            *pending = dates_for_year(*year);
+                            pending.sort_by(|a, b| b.cmp(a));
+                            *year += 1;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Iterator for Occurrences<'a> {
+    type Item = RD;
+
+    fn next(&mut self) -> Option<RD> {
+        if self.recurrence.count.is_some_and(|count| self.found >= count) {
+            return None;
+        }
+        loop {
+            let date = self.next_candidate()?;
+            let recurrence = self.recurrence;
+            if recurrence.each_year.is_none() && !recurrence.matches_month_end(date) {
+                continue;
+            }
+            if divide(self.period(date), recurrence.interval).1 != 0 {
+                continue;
+            }
+            // excluded dates still count towards the count like in rfc 5545
+            self.found += 1;
+            if recurrence.exceptions.contains(&date) {
+                if recurrence.count.is_some_and(|count| self.found >= count) {
+                    return None;
+                }
+                continue;
+            }
+            return Some(date);
+        }
+    }
+}
+
+/// all occurrences of `recurrence` in [start, end]
+pub fn occurrences_between(recurrence: &Recurrence, start: RD, end: RD) -> Vec<RD> {
+    recurrence.occurrences().skip_while(|&date| date < start).take_while(|&date| date <= end).collect()
+}
+
+/// a date of a cyclic calendar (haab, tzolkin or pawukon) every time it comes around
+pub fn every(date: Date, start: RD) -> Recurrence {
+    let calendar = date.calendar();
+    calendar.fields().iter()
+        .filter(|&&name| name != "year")
+        .fold(Recurrence::new(calendar, Frequency::Yearly, start), |recurrence, &name| {
+            recurrence.by_field(name, date.field(name).expect("Field names come from the calendar"))
+        })
+}
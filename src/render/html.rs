@@ -0,0 +1,84 @@
+//! a page as a standalone html document with a table for every month, styled to print a month
+//! or a quarter of a year to a sheet
+
+use render::{escape, Moon, Month, Page};
+use core::fmt::{self, Write};
+use alloc::string::String;
+
+const STYLE: &str = "\
+body { font-family: sans-serif; margin: 1em; }
+h1 { text-align: center; }
+.months { display: flex; flex-wrap: wrap; gap: 1em; justify-content: center; }
+.month { break-inside: avoid; page-break-inside: avoid; }
+.year .month { width: 30%; min-width: 20em; }
+table { border-collapse: collapse; width: 100%; table-layout: fixed; }
+th { font-size: 0.8em; }
+td { border: 1px solid #999; vertical-align: top; height: 4.5em; padding: 0.2em; font-size: 0.7em; }
+.month-page td { height: 7em; font-size: 1em; }
+td.outside { border: none; }
+.day { font-weight: bold; font-size: 1.3em; }
+.moon { float: right; }
+.secondary { display: block; color: #555; }
+.holiday { display: block; color: #a00; }
+.legend { text-align: center; color: #555; }
+@page { size: landscape; margin: 1cm; }";
+
+fn moon(moon: Moon) -> &'static str {
+    match moon {
+        Moon::New => "<span class=\"moon\" title=\"new moon\">\u{25cf}</span>",
+        Moon::Full => "<span class=\"moon\" title=\"full moon\">\u{25cb}</span>"
+    }
+}
+
+fn write_month(html: &mut String, page: &Page, month: &Month) -> fmt::Result {
+    write!(html, "<section class=\"month\">\n<h2>{}</h2>\n<table>\n<tr>", escape(&month.title()))?;
+    for name in page.weekday_names() {
+        write!(html, "<th>{}</th>", name)?;
+    }
+    html.push_str("</tr>\n");
+    // rows after the end of the month are left out to save paper
+    for row in month.rows(page.week_start).iter().filter(|&&row| row < month.start + month.length) {
+        html.push_str("<tr>");
+        for date in *row..row + 7 {
+            if !month.contains(date) {
+                html.push_str("<td class=\"outside\"></td>");
+                continue;
+            }
+            let day = page.day(date);
+            write!(html, "<td>{}<span class=\"day\">{}</span>", day.moon.map_or("", moon), day.number)?;
+            for (calendar, text) in page.secondary.iter().zip(day.secondary.iter()) {
+                write!(html, "<span class=\"secondary\" title=\"{}\">{}</span>", calendar.name(), escape(text))?;
+            }
+            for holiday in &day.holidays {
+                write!(html, "<span class=\"holiday\">{}</span>", escape(holiday))?;
+            }
+            html.push_str("</td>");
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</table>\n</section>\n");
+    Ok(())
+}
+
+fn write_page(html: &mut String, page: &Page) -> fmt::Result {
+    let kind = if page.months.len() == 1 { "month-page" } else { "year" };
+    write!(html, "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>\n{}\n</style>\n</head>\n<body class=\"{}\">\n<h1>{}</h1>\n<div class=\"months\">\n",
+        escape(&page.title), STYLE, kind, escape(&page.title))?;
+    for month in &page.months {
+        write_month(html, page, month)?;
+    }
+    html.push_str("</div>\n");
+    let legend = page.legend();
+    if !legend.is_empty() {
+        writeln!(html, "<p class=\"legend\">{}</p>", legend.join(" &middot; "))?;
+    }
+    html.push_str("</body>\n</html>\n");
+    Ok(())
+}
+
+/// the page as an html document
+pub fn render(page: &Page) -> String {
+    let mut html = String::new();
+    write_page(&mut html, page).expect("Writing to a string cant fail");
+    html
+}
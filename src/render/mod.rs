@@ -0,0 +1,247 @@
+//! printable month and year calendars as html or svg
+//!
+//! a page is a grid of the days of a calendar with months, and under each day its day of the
+//! month in other calendars, the holidays on it and the mean new and full moons from the molad.
+//! everything on a page comes from the conversions here, it has no scripts, fonts or images
+
+pub mod html;
+pub mod svg;
+
+use calendar::{Calendar, Date, DateError, WEEKDAY_NAMES};
+use common::{divide, floor, RD};
+use common::cycles_of_days::day_of_week_from_fixed;
+use arithmetical::gregorian::gregorian_from_fixed;
+use arithmetical::hebrew::{self, hebrew_from_fixed, last_month_of_hebrew_year, moment_from_molad};
+use holidays::{holidays_in_gregorian_year, Holiday, Tradition};
+use alloc::vec::Vec;
+use alloc::string::{String, ToString};
+
+/// a month of a calendar with months
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Month {
+    pub first: Date,
+    pub start: RD,
+    pub length: i32
+}
+
+impl Month {
+    pub fn new(first: Date) -> Month {
+        let start = first.to_fixed().expect("Calendars with months have R.D. dates");
+        let month = |fixed: RD| first.calendar().from_fixed(fixed).field("month");
+        // the hindu months follow the sun so their lengths are found by looking for the next one
+        let length = first.days_in_month().unwrap_or_else(|| (28..).find(|&day| month(start + day) != month(start)).expect("Months end"));
+        Month { first, start, length }
+    }
+
+    /// the month name and year, or year-month for calendars without month names
+    pub fn title(&self) -> String {
+        let month = self.first.field("month").expect("Months are of calendars with months");
+        let year = self.first.field("year").expect("Months are of calendars with years");
+        match self.first.value_name("month") {
+            Some(name) => format!("{} {}", name, year),
+            None => format!("{}-{}", year, month)
+        }
+    }
+
+    pub fn contains(&self, date: RD) -> bool {
+        self.start <= date && date < self.start + self.length
+    }
+
+    /// the first day of each row of a grid of the month whose weeks start on `week_start` (sunday
+    /// is 0), always six rows so months side by side line up
+    pub fn rows(&self, week_start: i32) -> [RD; 6] {
+        let first = self.start - divide(day_of_week_from_fixed(self.start) - week_start, 7).1;
+        [first, first + 7, first + 14, first + 21, first + 28, first + 35]
+    }
+}
+
+/// whether a calendar has years, months and days to lay out in a grid
+pub fn has_months(calendar: Calendar) -> bool {
+    calendar.fields() == ["year", "month", "day"]
+}
+
+pub fn month(calendar: Calendar, year: i32, month: i32) -> Result<Month, DateError> {
+    let first = calendar.date_from_fields(&[year, month, 1]).ok_or(DateError::InvalidField("year"))?;
+    first.validate()?;
+    Ok(Month::new(first))
+}
+
+/// the months of a year in order, the hebrew year starting with tishri
+pub fn months_of_year(calendar: Calendar, year: i32) -> Result<Vec<Month>, DateError> {
+    let first = if calendar == Calendar::Hebrew { 7 } else { 1 };
+    let mut months = vec![month(calendar, year, first)?];
+    loop {
+        let last = months.last().expect("There is a first month");
+        let next = calendar.from_fixed(last.start + last.length);
+        if next.field("year") != Some(year) {
+            return Ok(months);
+        }
+        months.push(Month::new(next));
+    }
+}
+
+/// the number shown for a day of a calendar, the kin of the long count and the number of the tzolkin
+pub fn day_number(calendar: Calendar, date: RD) -> Option<i32> {
+    let field = match calendar {
+        Calendar::Tzolkin => "number",
+        Calendar::LongCount => "kin",
+        _ => "day"
+    };
+    calendar.from_fixed(date).field(field)
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Moon {
+    New,
+    Full
+}
+
+/// the days of the mean new and full moons in [start, end), the new moons being the molads of
+/// the hebrew calendar and the full moons halfway between them
+pub fn moons_between(start: RD, end: RD) -> Vec<(RD, Moon)> {
+    // the months of a hebrew year start at tishri and go on from nisan after the last one
+    let next = |(month, year): (i32, i32)| if month == 6 {
+        (7, year + 1)
+    } else if month == last_month_of_hebrew_year(year) {
+        (1, year)
+    } else {
+        (month + 1, year)
+    };
+    // the molad of tishri of the year before is always before the start
+    let mut month = (7, hebrew_from_fixed(start).year - 1);
+    let mut new = moment_from_molad(hebrew::molad(month.0, month.1));
+    let mut moons = Vec::new();
+    while (floor(new) as RD) < end {
+        month = next(month);
+        let following = moment_from_molad(hebrew::molad(month.0, month.1));
+        for &(moment, moon) in &[(new, Moon::New), ((new + following) / 2.0, Moon::Full)] {
+            let date = floor(moment) as RD;
+            if (start..end).contains(&date) {
+                moons.push((date, moon));
+            }
+        }
+        new = following;
+    }
+    moons
+}
+
+/// what is shown in the cell of a day
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Day {
+    pub date: RD,
+    pub number: i32,
+    /// the day in each secondary calendar, with the month name on the first of a month
+    pub secondary: Vec<String>,
+    pub holidays: Vec<&'static str>,
+    pub moon: Option<Moon>
+}
+
+/// a month or a year of a calendar laid out for printing
+#[derive(Clone, Debug, PartialEq)]
+pub struct Page {
+    pub title: String,
+    pub calendar: Calendar,
+    pub months: Vec<Month>,
+    pub secondary: Vec<Calendar>,
+    pub holidays: Vec<Holiday>,
+    pub moons: Vec<(RD, Moon)>,
+    pub week_start: i32
+}
+
+impl Page {
+    fn new(title: String, calendar: Calendar, months: Vec<Month>) -> Page {
+        Page { title, calendar, months, secondary: Vec::new(), holidays: Vec::new(), moons: Vec::new(), week_start: 0 }
+    }
+
+    pub fn month(calendar: Calendar, year: i32, number: i32) -> Result<Page, DateError> {
+        let month = month(calendar, year, number)?;
+        Ok(Page::new(month.title(), calendar, vec![month]))
+    }
+
+    pub fn year(calendar: Calendar, year: i32) -> Result<Page, DateError> {
+        Ok(Page::new(year.to_string(), calendar, months_of_year(calendar, year)?))
+    }
+
+    /// the first and the day after the last day of the page
+    pub fn days(&self) -> (RD, RD) {
+        let first = self.months.first().expect("Pages have months");
+        let last = self.months.last().expect("Pages have months");
+        (first.start, last.start + last.length)
+    }
+
+    /// calendars whose day of the month is shown under each day
+    pub fn secondary(mut self, calendars: &[Calendar]) -> Page {
+        self.secondary.extend_from_slice(calendars);
+        self
+    }
+
+    /// labels the holidays of the traditions
+    pub fn holidays(mut self, traditions: &[Tradition]) -> Page {
+        let (start, end) = self.days();
+        for year in gregorian_from_fixed(start).year..=gregorian_from_fixed(end - 1).year {
+            self.holidays.extend(holidays_in_gregorian_year(year, traditions).into_iter().filter(|holiday| start <= holiday.date && holiday.date < end));
+        }
+        self
+    }
+
+    /// marks the mean new and full moons
+    pub fn moons(mut self) -> Page {
+        let (start, end) = self.days();
+        self.moons = moons_between(start, end);
+        self
+    }
+
+    /// the first day of the week, sunday is 0
+    pub fn week_start(mut self, weekday: i32) -> Page {
+        self.week_start = divide(weekday, 7).1;
+        self
+    }
+
+    /// the short names of the weekdays in the order of the columns
+    pub fn weekday_names(&self) -> Vec<&'static str> {
+        (0..7).map(|i| &WEEKDAY_NAMES[divide(self.week_start + i, 7).1 as usize].0[..3]).collect()
+    }
+
+    /// what the lines under the days and the moon markers are
+    pub fn legend(&self) -> Vec<String> {
+        let mut legend = Vec::new();
+        if !self.secondary.is_empty() {
+            let names: Vec<&str> = self.secondary.iter().map(|calendar| calendar.name()).collect();
+            legend.push(format!("under each day: {}", names.join(", ")));
+        }
+        if !self.moons.is_empty() {
+            legend.push("\u{25cf} new moon (molad), \u{25cb} full moon".to_string());
+        }
+        legend
+    }
+
+    pub fn day(&self, date: RD) -> Day {
+        let number = self.calendar.from_fixed(date).field("day").expect("Pages are of calendars with days");
+        let secondary = self.secondary.iter().filter_map(|&calendar| {
+            let number = day_number(calendar, date)?;
+            let converted = calendar.from_fixed(date);
+            Some(match converted.value_name("month") {
+                Some(month) if number == 1 && converted.field("day").is_some() => format!("1 {}", month),
+                _ => number.to_string()
+            })
+        }).collect();
+        let holidays = self.holidays.iter().filter(|holiday| holiday.date == date).map(|holiday| holiday.name).collect();
+        let moon = self.moons.iter().find(|moon| moon.0 == date).map(|moon| moon.1);
+        Day { date, number, secondary, holidays, moon }
+    }
+}
+
+/// escapes text for html and svg
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c)
+        }
+    }
+    escaped
+}
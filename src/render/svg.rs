@@ -0,0 +1,100 @@
+//! a page as a single svg image, months three across for a year, that scales to any paper size
+
+use render::{escape, Moon, Month, Page};
+use core::fmt::{self, Write};
+use alloc::string::String;
+
+const CELL_WIDTH: i32 = 100;
+const CELL_HEIGHT: i32 = 72;
+const MONTH_TITLE: i32 = 30;
+const WEEKDAY_ROW: i32 = 20;
+const MONTH_WIDTH: i32 = 7 * CELL_WIDTH;
+const MONTH_HEIGHT: i32 = MONTH_TITLE + WEEKDAY_ROW + 6 * CELL_HEIGHT;
+const GAP: i32 = 30;
+const PAGE_TITLE: i32 = 50;
+const LINE_HEIGHT: i32 = 11;
+/// about how many characters of a label fit in a cell
+const LABEL_LENGTH: usize = 19;
+
+const STYLE: &str = "\
+text { font-family: sans-serif; }
+.title { font-size: 32px; font-weight: bold; text-anchor: middle; }
+.month { font-size: 20px; font-weight: bold; text-anchor: middle; }
+.weekday { font-size: 12px; text-anchor: middle; }
+.cell { fill: none; stroke: #999; }
+.day { font-size: 15px; font-weight: bold; }
+.secondary { font-size: 10px; fill: #555; }
+.holiday { font-size: 10px; fill: #a00; }
+.new-moon { fill: #000; }
+.full-moon { fill: #fff; stroke: #000; }
+.legend { font-size: 12px; fill: #555; text-anchor: middle; }
+";
+
+fn label(text: &str) -> String {
+    if text.chars().count() <= LABEL_LENGTH {
+        return escape(text);
+    }
+    let mut short: String = text.chars().take(LABEL_LENGTH - 1).collect();
+    short.push('\u{2026}');
+    escape(&short)
+}
+
+fn write_month(svg: &mut String, page: &Page, month: &Month, x: i32, y: i32) -> fmt::Result {
+    writeln!(svg, "<g transform=\"translate({},{})\">", x, y)?;
+    writeln!(svg, "<text class=\"month\" x=\"{}\" y=\"22\">{}</text>", MONTH_WIDTH / 2, escape(&month.title()))?;
+    for (i, name) in page.weekday_names().iter().enumerate() {
+        writeln!(svg, "<text class=\"weekday\" x=\"{}\" y=\"{}\">{}</text>", i as i32 * CELL_WIDTH + CELL_WIDTH / 2, MONTH_TITLE + 14, name)?;
+    }
+    for (row, &row_start) in month.rows(page.week_start).iter().enumerate() {
+        let top = MONTH_TITLE + WEEKDAY_ROW + row as i32 * CELL_HEIGHT;
+        for column in 0..7 {
+            let date = row_start + column;
+            if !month.contains(date) {
+                continue;
+            }
+            let left = column * CELL_WIDTH;
+            let day = page.day(date);
+            writeln!(svg, "<rect class=\"cell\" x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"/>", left, top, CELL_WIDTH, CELL_HEIGHT)?;
+            writeln!(svg, "<text class=\"day\" x=\"{}\" y=\"{}\">{}</text>", left + 5, top + 17, day.number)?;
+            match day.moon {
+                Some(Moon::New) => writeln!(svg, "<circle class=\"new-moon\" cx=\"{}\" cy=\"{}\" r=\"5\"/>", left + CELL_WIDTH - 11, top + 11)?,
+                Some(Moon::Full) => writeln!(svg, "<circle class=\"full-moon\" cx=\"{}\" cy=\"{}\" r=\"5\"/>", left + CELL_WIDTH - 11, top + 11)?,
+                None => {}
+            }
+            // as many labels as fit under the day number, the secondary dates first
+            let labels = day.secondary.iter().map(|text| ("secondary", text.as_str()))
+                .chain(day.holidays.iter().map(|&name| ("holiday", name)));
+            for (line, (class, text)) in labels.take(((CELL_HEIGHT - 22) / LINE_HEIGHT) as usize).enumerate() {
+                writeln!(svg, "<text class=\"{}\" x=\"{}\" y=\"{}\">{}</text>", class, left + 5, top + 32 + line as i32 * LINE_HEIGHT, label(text))?;
+            }
+        }
+    }
+    writeln!(svg, "</g>")
+}
+
+fn write_page(svg: &mut String, page: &Page) -> fmt::Result {
+    let across = if page.months.len() == 1 { 1 } else { 3 };
+    let rows = (page.months.len() as i32 + across - 1) / across;
+    let width = across * MONTH_WIDTH + (across + 1) * GAP;
+    let legend = page.legend();
+    let height = PAGE_TITLE + rows * (MONTH_HEIGHT + GAP) + if legend.is_empty() { 0 } else { 30 };
+    writeln!(svg, "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">", width, height, width, height)?;
+    writeln!(svg, "<style>\n{}</style>", STYLE)?;
+    writeln!(svg, "<rect width=\"{}\" height=\"{}\" fill=\"#fff\"/>", width, height)?;
+    writeln!(svg, "<text class=\"title\" x=\"{}\" y=\"38\">{}</text>", width / 2, escape(&page.title))?;
+    for (i, month) in page.months.iter().enumerate() {
+        let (row, column) = (i as i32 / across, i as i32 % across);
+        write_month(svg, page, month, GAP + column * (MONTH_WIDTH + GAP), PAGE_TITLE + row * (MONTH_HEIGHT + GAP))?;
+    }
+    if !legend.is_empty() {
+        writeln!(svg, "<text class=\"legend\" x=\"{}\" y=\"{}\">{}</text>", width / 2, height - 12, legend.join(" \u{b7} "))?;
+    }
+    writeln!(svg, "</svg>")
+}
+
+/// the page as an svg document
+pub fn render(page: &Page) -> String {
+    let mut svg = String::new();
+    write_page(&mut svg, page).expect("Writing to a string cant fail");
+    svg
+}
@@ -0,0 +1,95 @@
+use common::RD;
+use core::ops::RangeInclusive;
+use alloc::vec::Vec;
+use alloc::boxed::Box;
+
+/// a yearly observance given as the dates it falls on in a gregorian year
+/// most of the holiday functions give exactly one date a year but some like
+/// `hebrew::tzom_tevet` or `julian::julian_in_gregorian` can give none or two
+pub struct Rule {
+    dates: Box<dyn Fn(i32) -> Vec<RD>>
+}
+
+impl Rule {
+    pub fn new<F>(dates: F) -> Rule where F: Fn(i32) -> Vec<RD> + 'static {
+        Rule { dates: Box::new(dates) }
+    }
+
+    /// for rules like `ecclesiastical::easter` that always fall once a year
+    pub fn fixed<F>(date: F) -> Rule where F: Fn(i32) -> RD + 'static {
+        Rule::new(move |year| vec![date(year)])
+    }
+
+    /// for rules like `hebrew::birkath_hachama` that only fall in some years
+    pub fn optional<F>(date: F) -> Rule where F: Fn(i32) -> Option<RD> + 'static {
+        Rule::new(move |year| date(year).into_iter().collect())
+    }
+
+    pub fn dates_in_gregorian_year(&self, gregorian_year: i32) -> Vec<RD> {
+        (self.dates)(gregorian_year)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Coincidence {
+    pub year: i32,
+    pub date: RD
+}
+
+/// the dates in a gregorian year that every rule falls on
+/// rules are evaluated in order and evaluation stops as soon as nothing is left
+/// so putting the cheapest or rarest rule first makes scanning faster
+pub fn coincidences_in_gregorian_year(rules: &[Rule], gregorian_year: i32) -> Vec<Coincidence> {
+    let mut dates = match rules.split_first() {
+        Some((first, _)) => first.dates_in_gregorian_year(gregorian_year),
+        None => return Vec::new()
+    };
+
+    for rule in &rules[1..] {
+        if dates.is_empty() {
+            break;
+        }
+        let other = rule.dates_in_gregorian_year(gregorian_year);
+        dates.retain(|date| other.contains(date));
+    }
+
+    dates.sort();
+    dates.dedup();
+    dates.into_iter().map(|date| Coincidence { year: gregorian_year, date }).collect()
+}
+
+/// gives every date in the gregorian years [start, end] that all rules fall on
+pub fn coincidences(rules: &[Rule], start: i32, end: i32) -> Vec<Coincidence> {
+    Coincidences::new(rules, start..=end).collect()
+}
+
+/// the first coincidence in the gregorian years [start, end] if there is one
+pub fn next_coincidence(rules: &[Rule], start: i32, end: i32) -> Option<Coincidence> {
+    Coincidences::new(rules, start..=end).next()
+}
+
+/// lazily scans a range of gregorian years for coincidences
+pub struct Coincidences<'a> {
+    rules: &'a [Rule],
+    years: RangeInclusive<i32>,
+    pending: Vec<Coincidence>
+}
+
+impl<'a> Coincidences<'a> {
+    pub fn new(rules: &'a [Rule], years: RangeInclusive<i32>) -> Coincidences<'a> {
+        Coincidences { rules, years, pending: Vec::new() }
+    }
+}
+
+impl<'a> Iterator for Coincidences<'a> {
+    type Item = Coincidence;
+
+    fn next(&mut self) -> Option<Coincidence> {
+        while self.pending.is_empty() {
+            let year = self.years.next()?;
+            self.pending = coincidences_in_gregorian_year(self.rules, year);
+            self.pending.reverse();
+        }
+        self.pending.pop()
+    }
+}
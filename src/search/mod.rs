@@ -0,0 +1,2 @@
+pub mod coincidence;
+pub mod query;
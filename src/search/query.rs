@@ -0,0 +1,578 @@
+//! a small query language over the fields of every calendar
+//!
+//! `weekday = friday and gregorian.day = 13 and hebrew.day = 13`
+//! `islamic.month = muharram and islamic.day = 1 and gregorian.month = december`
+//! `tzolkin.number = 8 and tzolkin.name = batz or not (bali.luang = true)`
+//!
+//! a comparison is `field op value` with op one of `= != < <= > >=`, or `field in low..high`
+//! fields are `weekday` (sunday is 0), `rd` or `calendar.field` using the names from
+//! `calendar::Calendar` and values are numbers or names such as months, weekdays and tzolkin names
+
+use calendar::{Calendar, Date, WEEKDAY_NAMES, value_from_name};
+use common::{divide, RD, cycles_of_days::day_of_week_from_fixed};
+use arithmetical::gregorian::{fixed_from_gregorian, Gregorian};
+use core::borrow::Borrow;
+use core::error::Error;
+use core::fmt;
+use core::str::FromStr;
+use alloc::vec::Vec;
+use alloc::string::{String, ToString};
+use alloc::boxed::Box;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub position: usize,
+    pub message: String
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} at position {}", self.message, self.position)
+    }
+}
+
+impl Error for ParseError {}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Field {
+    Weekday,
+    Fixed,
+    Calendar(Calendar, &'static str)
+}
+
+impl Field {
+    fn value(self, dates: &mut Dates) -> i32 {
+        match self {
+            Field::Weekday => day_of_week_from_fixed(dates.date),
+            Field::Fixed => dates.date,
+            Field::Calendar(calendar, name) => dates.get(calendar).field(name).expect("Fields are checked when parsing")
+        }
+    }
+
+    /// fields that only depend on the position of a date in a cycle of days
+    fn cycle_length(self) -> Option<i32> {
+        match self {
+            Field::Weekday | Field::Calendar(Calendar::Iso, "day") => Some(7),
+            Field::Calendar(calendar, _) => calendar.cycle_length(),
+            Field::Fixed => None
+        }
+    }
+
+    fn value_names(self) -> Option<&'static [(&'static str, i32)]> {
+        match self {
+            Field::Weekday => Some(WEEKDAY_NAMES),
+            Field::Calendar(calendar, name) => calendar.value_names(name),
+            Field::Fixed => None
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Operand {
+    Field(Field),
+    Value(i32)
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Op {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge
+}
+
+impl Op {
+    fn apply(self, left: i32, right: i32) -> bool {
+        match self {
+            Op::Eq => left == right,
+            Op::Ne => left != right,
+            Op::Lt => left < right,
+            Op::Le => left <= right,
+            Op::Gt => left > right,
+            Op::Ge => left >= right
+        }
+    }
+}
+
+/// which positions of a cycle a comparison holds for, position i being R.D. i mod length
+#[derive(Clone, Debug, PartialEq)]
+pub struct Cycle {
+    length: i32,
+    positions: Vec<bool>
+}
+
+impl Cycle {
+    fn next(&self, date: RD) -> Option<RD> {
+        let position = divide(date, self.length).1;
+        (0..self.length)
+            .find(|&offset| self.positions[divide(position + offset, self.length).1 as usize])
+            .map(|offset| date + offset)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Expr {
+    Compare(Operand, Op, Operand),
+    And(Vec<Expr>),
+    Or(Vec<Expr>),
+    Not(Box<Expr>),
+    /// a comparison on a field like weekday or the tzolkin name that repeats in a cycle of days
+    Cyclic(Box<Expr>, Cycle)
+}
+
+impl Expr {
+    fn matches(&self, dates: &mut Dates) -> bool {
+        match *self {
+            Expr::Compare(left, op, right) => {
+                let left = operand_value(left, dates);
+                let right = operand_value(right, dates);
+                op.apply(left, right)
+            },
+            Expr::And(ref exprs) => exprs.iter().all(|expr| expr.matches(dates)),
+            Expr::Or(ref exprs) => exprs.iter().any(|expr| expr.matches(dates)),
+            Expr::Not(ref expr) => !expr.matches(dates),
+            Expr::Cyclic(_, ref cycle) => cycle.positions[divide(dates.date, cycle.length).1 as usize]
+        }
+    }
+
+    /// the earliest date in [date, end] that could match, skipping whole stretches of a cycle
+    /// or month that cant, or `None` if nothing before `end` can match
+    fn next_candidate(&self, date: RD, end: RD, dates: &mut Dates) -> Option<RD> {
+        let candidate = match *self {
+            Expr::Cyclic(_, ref cycle) => cycle.next(date)?,
+            Expr::Compare(Operand::Field(field), Op::Eq, Operand::Value(day))
+                | Expr::Compare(Operand::Value(day), Op::Eq, Operand::Field(field)) => match field {
+                    // days only ever go up by one within a month so if the day hasnt
+                    // been reached yet none of the days before it can match
+                    Field::Calendar(calendar, "day") if has_consecutive_days(calendar) => {
+                        dates.set(date);
+                        let current = field.value(dates);
+                        if current < day { date.checked_add(day - current)? } else { date }
+                    },
+                    _ => date
+                },
+            Expr::And(ref exprs) => {
+                let mut candidate = date;
+                loop {
+                    let start = candidate;
+                    for expr in exprs {
+                        candidate = expr.next_candidate(candidate, end, dates)?;
+                    }
+                    if candidate == start {
+                        break candidate;
+                    }
+                }
+            },
+            Expr::Or(ref exprs) => exprs.iter().filter_map(|expr| expr.next_candidate(date, end, dates)).min()?,
+            _ => date
+        };
+        Some(candidate).filter(|&candidate| candidate <= end)
+    }
+
+    /// replaces comparisons that only depend on a cycle of days with a lookup table of the cycle
+    fn with_cycles(self) -> Expr {
+        match self {
+            Expr::Compare(left, op, right) => {
+                let length = match (left, right) {
+                    (Operand::Field(field), Operand::Value(_)) | (Operand::Value(_), Operand::Field(field)) => field.cycle_length(),
+                    _ => None
+                };
+                match length {
+                    Some(length) => {
+                        let expr = Expr::Compare(left, op, right);
+                        let mut dates = Dates::new();
+                        let positions = (0..length).map(|position| {
+                            dates.set(position);
+                            expr.matches(&mut dates)
+                        }).collect();
+                        Expr::Cyclic(Box::new(expr), Cycle { length, positions })
+                    },
+                    None => Expr::Compare(left, op, right)
+                }
+            },
+            Expr::And(exprs) => Expr::And(exprs.into_iter().map(Expr::with_cycles).collect()),
+            Expr::Or(exprs) => Expr::Or(exprs.into_iter().map(Expr::with_cycles).collect()),
+            Expr::Not(expr) => match expr.with_cycles() {
+                Expr::Cyclic(expr, cycle) => Expr::Cyclic(Box::new(Expr::Not(expr)), Cycle {
+                    length: cycle.length,
+                    positions: cycle.positions.into_iter().map(|position| !position).collect()
+                }),
+                expr => Expr::Not(Box::new(expr))
+            },
+            expr => expr
+        }
+    }
+}
+
+/// calendars whose day of the month goes up by exactly one from day to day, unlike the hindu
+/// lunisolar calendar where a lunar day can be skipped or repeated
+fn has_consecutive_days(calendar: Calendar) -> bool {
+    matches!(calendar, Calendar::Gregorian | Calendar::Julian | Calendar::Coptic | Calendar::Ethiopic
+        | Calendar::Islamic | Calendar::Hebrew | Calendar::HinduSolar)
+}
+
+fn operand_value(operand: Operand, dates: &mut Dates) -> i32 {
+    match operand {
+        Operand::Field(field) => field.value(dates),
+        Operand::Value(value) => value
+    }
+}
+
+/// each calendar date of the date being looked at, converted at most once
+struct Dates {
+    date: RD,
+    dates: Vec<Date>
+}
+
+impl Dates {
+    fn new() -> Dates {
+        Dates { date: 0, dates: Vec::new() }
+    }
+
+    fn set(&mut self, date: RD) {
+        if date != self.date {
+            self.date = date;
+            self.dates.clear();
+        }
+    }
+
+    fn get(&mut self, calendar: Calendar) -> Date {
+        match self.dates.iter().find(|date| date.calendar() == calendar) {
+            Some(&date) => date,
+            None => {
+                let date = calendar.from_fixed(self.date);
+                self.dates.push(date);
+                date
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Query {
+    expr: Expr
+}
+
+impl Query {
+    pub fn parse(text: &str) -> Result<Query, ParseError> {
+        let tokens = tokenize(text)?;
+        let mut parser = Parser { tokens, position: 0, end: text.len() };
+        let expr = parser.or()?;
+        match parser.peek() {
+            Some(&(position, ref token)) => Err(ParseError { position, message: format!("unexpected {}", token) }),
+            None => Ok(Query { expr: expr.with_cycles() })
+        }
+    }
+
+    /// builds a query from an expression put together in code rather than parsed
+    pub fn from_expr(expr: Expr) -> Query {
+        Query { expr: expr.with_cycles() }
+    }
+
+    pub fn expr(&self) -> &Expr {
+        &self.expr
+    }
+
+    pub fn matches(&self, date: RD) -> bool {
+        let mut dates = Dates::new();
+        dates.set(date);
+        self.expr.matches(&mut dates)
+    }
+
+    /// every matching date in [start, end]
+    pub fn search(&self, start: RD, end: RD) -> Matches<&Query> {
+        Matches { query: self, date: start, end, dates: Dates::new() }
+    }
+
+    pub fn into_search(self, start: RD, end: RD) -> Matches<Query> {
+        Matches { query: self, date: start, end, dates: Dates::new() }
+    }
+
+    /// every matching date in the gregorian years [start, end]
+    pub fn search_gregorian_years(&self, start: i32, end: i32) -> Matches<&Query> {
+        self.search(
+            fixed_from_gregorian(Gregorian { year: start, month: 1, day: 1 }),
+            fixed_from_gregorian(Gregorian { year: end, month: 12, day: 31 })
+        )
+    }
+}
+
+impl FromStr for Query {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Query, ParseError> {
+        Query::parse(text)
+    }
+}
+
+/// matching dates of a query that is either borrowed or owned
+pub struct Matches<Q> {
+    query: Q,
+    date: RD,
+    end: RD,
+    dates: Dates
+}
+
+impl<Q> Iterator for Matches<Q> where Q: Borrow<Query> {
+    type Item = RD;
+
+    fn next(&mut self) -> Option<RD> {
+        let expr = &self.query.borrow().expr;
+        while self.date <= self.end {
+            let date = match expr.next_candidate(self.date, self.end, &mut self.dates) {
+                Some(date) => date,
+                None => break
+            };
+            self.date = date + 1;
+            self.dates.set(date);
+            if expr.matches(&mut self.dates) {
+                return Some(date);
+            }
+        }
+        self.date = self.end + 1;
+        None
+    }
+}
+
+/// parses and runs a query over [start, end]
+pub fn query(text: &str, start: RD, end: RD) -> Result<Vec<RD>, ParseError> {
+    Ok(Query::parse(text)?.search(start, end).collect())
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+enum Token {
+    Word(String),
+    Number(i32),
+    Text(String),
+    Symbol(&'static str)
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Token::Word(ref word) => write!(f, "'{}'", word),
+            Token::Number(number) => write!(f, "{}", number),
+            Token::Text(ref text) => write!(f, "\"{}\"", text),
+            Token::Symbol(symbol) => write!(f, "'{}'", symbol)
+        }
+    }
+}
+
+const SYMBOLS: [&str; 12] = ["==", "!=", "<=", ">=", "..", "=", "<", ">", "(", ")", ".", "!"];
+
+fn tokenize(text: &str) -> Result<Vec<(usize, Token)>, ParseError> {
+    let mut tokens = Vec::new();
+    let mut chars = text.char_indices().peekable();
+
+    while let Some(&(position, c)) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c.is_ascii_digit() || (c == '-' && text[position + 1..].starts_with(|c: char| c.is_ascii_digit())) {
+            chars.next();
+            let mut end = position + 1;
+            while let Some(&(i, c)) = chars.peek() {
+                if !c.is_ascii_digit() {
+                    break;
+                }
+                end = i + 1;
+                chars.next();
+            }
+            let number = text[position..end].parse().map_err(|_| ParseError { position, message: "number is too large".to_string() })?;
+            tokens.push((position, Token::Number(number)));
+        } else if c.is_alphabetic() || c == '_' {
+            let mut end = position;
+            while let Some(&(i, c)) = chars.peek() {
+                if !(c.is_alphanumeric() || c == '_' || c == '\'') {
+                    break;
+                }
+                end = i + c.len_utf8();
+                chars.next();
+            }
+            tokens.push((position, Token::Word(text[position..end].to_lowercase())));
+        } else if c == '"' {
+            chars.next();
+            let mut end = None;
+            for (i, c) in chars.by_ref() {
+                if c == '"' {
+                    end = Some(i);
+                    break;
+                }
+            }
+            let end = end.ok_or(ParseError { position, message: "unterminated string".to_string() })?;
+            tokens.push((position, Token::Text(text[position + 1..end].to_string())));
+        } else {
+            let symbol = SYMBOLS.iter().find(|&&symbol| text[position..].starts_with(symbol))
+                .ok_or(ParseError { position, message: format!("unexpected '{}'", c) })?;
+            for _ in 0..symbol.len() {
+                chars.next();
+            }
+            tokens.push((position, Token::Symbol(symbol)));
+        }
+    }
+
+    Ok(tokens)
+}
+
+/// an operand before names have been resolved against the field they are compared to
+#[derive(Clone)]
+enum RawOperand {
+    Field(Field),
+    Value(i32),
+    Name(usize, String)
+}
+
+struct Parser {
+    tokens: Vec<(usize, Token)>,
+    position: usize,
+    end: usize
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&(usize, Token)> {
+        self.tokens.get(self.position)
+    }
+
+    fn next(&mut self) -> Result<(usize, Token), ParseError> {
+        let token = self.tokens.get(self.position).cloned()
+            .ok_or(ParseError { position: self.end, message: "unexpected end of query".to_string() })?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn at_word(&self, word: &str) -> bool {
+        match self.peek() {
+            Some(&(_, Token::Word(ref candidate))) => candidate == word,
+            _ => false
+        }
+    }
+
+    fn at_symbol(&self, symbol: &str) -> bool {
+        match self.peek() {
+            Some(&(_, Token::Symbol(candidate))) => candidate == symbol,
+            _ => false
+        }
+    }
+
+    fn expect_symbol(&mut self, symbol: &str) -> Result<(), ParseError> {
+        let (position, token) = self.next()?;
+        match token {
+            Token::Symbol(candidate) if candidate == symbol => Ok(()),
+            token => Err(ParseError { position, message: format!("expected '{}' but found {}", symbol, token) })
+        }
+    }
+
+    fn or(&mut self) -> Result<Expr, ParseError> {
+        let mut exprs = vec![self.and()?];
+        while self.at_word("or") {
+            self.position += 1;
+            exprs.push(self.and()?);
+        }
+        Ok(if exprs.len() == 1 { exprs.remove(0) } else { Expr::Or(exprs) })
+    }
+
+    fn and(&mut self) -> Result<Expr, ParseError> {
+        let mut exprs = vec![self.unary()?];
+        while self.at_word("and") {
+            self.position += 1;
+            exprs.push(self.unary()?);
+        }
+        Ok(if exprs.len() == 1 { exprs.remove(0) } else { Expr::And(exprs) })
+    }
+
+    fn unary(&mut self) -> Result<Expr, ParseError> {
+        if self.at_word("not") || self.at_symbol("!") {
+            self.position += 1;
+            Ok(Expr::Not(Box::new(self.unary()?)))
+        } else if self.at_symbol("(") {
+            self.position += 1;
+            let expr = self.or()?;
+            self.expect_symbol(")")?;
+            Ok(expr)
+        } else {
+            self.comparison()
+        }
+    }
+
+    fn comparison(&mut self) -> Result<Expr, ParseError> {
+        let left = self.operand()?;
+
+        if self.at_word("in") {
+            self.position += 1;
+            let low = self.operand()?;
+            self.expect_symbol("..")?;
+            let high = self.operand()?;
+            return Ok(Expr::And(vec![
+                resolve(left.clone(), Op::Ge, low)?,
+                resolve(left, Op::Le, high)?
+            ]));
+        }
+
+        let (position, token) = self.next()?;
+        let op = match token {
+            Token::Symbol("=") | Token::Symbol("==") => Op::Eq,
+            Token::Symbol("!=") => Op::Ne,
+            Token::Symbol("<") => Op::Lt,
+            Token::Symbol("<=") => Op::Le,
+            Token::Symbol(">") => Op::Gt,
+            Token::Symbol(">=") => Op::Ge,
+            token => return Err(ParseError { position, message: format!("expected a comparison but found {}", token) })
+        };
+        let right = self.operand()?;
+        resolve(left, op, right)
+    }
+
+    fn operand(&mut self) -> Result<RawOperand, ParseError> {
+        let (position, token) = self.next()?;
+        match token {
+            Token::Number(number) => Ok(RawOperand::Value(number)),
+            Token::Text(text) => Ok(RawOperand::Name(position, text)),
+            Token::Word(word) => {
+                if self.at_symbol(".") {
+                    self.position += 1;
+                    let calendar = Calendar::from_name(&word)
+                        .ok_or(ParseError { position, message: format!("unknown calendar '{}'", word) })?;
+                    let (field_position, field) = self.next()?;
+                    let field = match field {
+                        Token::Word(ref field) => calendar.fields().iter().find(|&&candidate| candidate == field),
+                        _ => None
+                    }.ok_or(ParseError {
+                        position: field_position,
+                        message: format!("{} has no field {}, expected one of {}", calendar.name(), field, calendar.fields().join(", "))
+                    })?;
+                    Ok(RawOperand::Field(Field::Calendar(calendar, field)))
+                } else {
+                    match word.as_str() {
+                        "weekday" => Ok(RawOperand::Field(Field::Weekday)),
+                        "rd" => Ok(RawOperand::Field(Field::Fixed)),
+                        _ => Ok(RawOperand::Name(position, word))
+                    }
+                }
+            },
+            token => Err(ParseError { position, message: format!("expected a field or value but found {}", token) })
+        }
+    }
+}
+
+/// turns names into values using the names of the field on the other side of the comparison
+fn resolve(left: RawOperand, op: Op, right: RawOperand) -> Result<Expr, ParseError> {
+    let names = |operand: &RawOperand| match *operand {
+        RawOperand::Field(field) => field.value_names(),
+        _ => None
+    };
+    let left_names = names(&left);
+    let right_names = names(&right);
+    Ok(Expr::Compare(resolve_operand(left, right_names)?, op, resolve_operand(right, left_names)?))
+}
+
+fn resolve_operand(operand: RawOperand, names: Option<&[(&str, i32)]>) -> Result<Operand, ParseError> {
+    match operand {
+        RawOperand::Field(field) => Ok(Operand::Field(field)),
+        RawOperand::Value(value) => Ok(Operand::Value(value)),
+        RawOperand::Name(position, name) => names
+            .and_then(|names| value_from_name(names, &name))
+            .map(Operand::Value)
+            .ok_or(ParseError { position, message: format!("unknown field or name '{}'", name) })
+    }
+}
@@ -0,0 +1,87 @@
+//! the adar and missing day policies of anniversaries
+
+extern crate calecalc;
+
+use calecalc::anniversary::{anniversary, hebrew_anniversary, observances_in_gregorian, Adar, HebrewRule, MissingDay, Rule, ASHKENAZI_YAHRZEIT, BIRTHDAY, SEPHARDI_YAHRZEIT};
+use calecalc::arithmetical::gregorian::{fixed_from_gregorian, Gregorian};
+use calecalc::arithmetical::hebrew::{fixed_from_hebrew, hebrew_birthday, yahrtzeit, Hebrew};
+use calecalc::calendar::Date;
+
+// 5785 and 5786 are common years, 5784 and 5787 are leap years where adar i is 12 and adar ii is 13
+fn hebrew(year: i32, month: i32, day: i32) -> i32 {
+    fixed_from_hebrew(Hebrew { year, month, day })
+}
+
+#[test]
+fn adar_of_a_common_year_in_a_leap_year() {
+    let death = Hebrew { year: 5785, month: 12, day: 10 };
+    assert_eq!(hebrew_anniversary(death, 5787, ASHKENAZI_YAHRZEIT), vec![hebrew(5787, 12, 10)]);
+    assert_eq!(hebrew_anniversary(death, 5787, SEPHARDI_YAHRZEIT), vec![hebrew(5787, 13, 10)]);
+    let both = HebrewRule { adar: Adar::Both, missing_day: MissingDay::LastDay, shevat: true };
+    assert_eq!(hebrew_anniversary(death, 5787, both), vec![hebrew(5787, 12, 10), hebrew(5787, 13, 10)]);
+    // in a common year there is only one adar whatever the rule
+    assert_eq!(hebrew_anniversary(death, 5786, ASHKENAZI_YAHRZEIT), vec![hebrew(5786, 12, 10)]);
+    assert_eq!(hebrew_anniversary(death, 5786, both), vec![hebrew(5786, 12, 10)]);
+}
+
+#[test]
+fn adars_of_a_leap_year() {
+    // adar ii is adar in a common year and adar i stays adar i in a leap year
+    let adar_ii = Hebrew { year: 5784, month: 13, day: 14 };
+    assert_eq!(hebrew_anniversary(adar_ii, 5785, ASHKENAZI_YAHRZEIT), vec![hebrew(5785, 12, 14)]);
+    assert_eq!(hebrew_anniversary(adar_ii, 5787, ASHKENAZI_YAHRZEIT), vec![hebrew(5787, 13, 14)]);
+    let adar_i = Hebrew { year: 5784, month: 12, day: 14 };
+    assert_eq!(hebrew_anniversary(adar_i, 5787, SEPHARDI_YAHRZEIT), vec![hebrew(5787, 12, 14)]);
+
+    // 30 adar i is missing in common years, its yahrzeit is 30 shevat
+    let last_of_adar_i = Hebrew { year: 5784, month: 12, day: 30 };
+    assert_eq!(hebrew_anniversary(last_of_adar_i, 5785, ASHKENAZI_YAHRZEIT), vec![hebrew(5785, 11, 30)]);
+    assert_eq!(hebrew_anniversary(last_of_adar_i, 5785, SEPHARDI_YAHRZEIT), vec![hebrew(5785, 11, 30)]);
+    assert_eq!(hebrew_anniversary(last_of_adar_i, 5787, ASHKENAZI_YAHRZEIT), vec![hebrew(5787, 12, 30)]);
+    assert_eq!(hebrew_anniversary(last_of_adar_i, 5785, BIRTHDAY), vec![hebrew(5785, 1, 1)]);
+}
+
+#[test]
+fn birthdays_agree_with_hebrew_birthday() {
+    for &(month, day) in &[(12, 10), (12, 30), (13, 5), (8, 30), (9, 30), (7, 1)] {
+        for &born in &[5784, 5785] {
+            let birth = Hebrew { year: born, month, day };
+            if born == 5785 && month == 13 {
+                continue;
+            }
+            for year in 5786..5790 {
+                assert_eq!(hebrew_anniversary(birth, year, BIRTHDAY), vec![hebrew_birthday(birth, year)], "{:?} in {}", birth, year);
+            }
+        }
+    }
+}
+
+#[test]
+fn ashkenazi_yahrzeits_agree_with_yahrtzeit() {
+    // yahrtzeit keeps 30 marheshvan and 30 kislev by the length of the month in the year after the death
+    let days = [(7, 1), (8, 29), (9, 29), (11, 30), (12, 10), (12, 29), (12, 30), (13, 5), (13, 29), (1, 15), (6, 29)];
+    for &(month, day) in &days {
+        for &died in &[5783, 5784, 5785] {
+            let death = Hebrew { year: died, month, day };
+            if Date::Hebrew(death).validate().is_err() {
+                continue;
+            }
+            for year in died + 1..died + 20 {
+                assert_eq!(hebrew_anniversary(death, year, ASHKENAZI_YAHRZEIT), vec![yahrtzeit(death, year)], "{:?} in {}", death, year);
+            }
+        }
+    }
+}
+
+#[test]
+fn missing_days_of_other_calendars() {
+    let leap_day = Date::Gregorian(Gregorian { year: 2024, month: 2, day: 29 });
+    let gregorian = |month, day| fixed_from_gregorian(Gregorian { year: 2025, month, day });
+    assert_eq!(anniversary(leap_day, 2025, MissingDay::LastDay), Some(gregorian(2, 28)));
+    assert_eq!(anniversary(leap_day, 2025, MissingDay::NextDay), Some(gregorian(3, 1)));
+    assert_eq!(anniversary(leap_day, 2028, MissingDay::LastDay), Some(fixed_from_gregorian(Gregorian { year: 2028, month: 2, day: 29 })));
+
+    let observances = observances_in_gregorian(leap_day, 2025, Rule::MissingDay(MissingDay::NextDay));
+    assert_eq!(observances.iter().map(|observance| observance.fixed).collect::<Vec<_>>(), vec![gregorian(3, 1)]);
+    assert_eq!(observances[0].date, Date::Gregorian(Gregorian { year: 2025, month: 3, day: 1 }));
+}
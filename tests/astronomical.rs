@@ -0,0 +1,69 @@
+//! time scales and the corrections to the position of the sun against published values
+
+extern crate calecalc;
+
+use calecalc::arithmetical::gregorian::{fixed_from_gregorian, Gregorian};
+use calecalc::astronomical::{JERUSALEM, Location};
+use calecalc::astronomical::solar::{aberration, nutation, obliquity};
+use calecalc::astronomical::time::*;
+use calecalc::common::Moment;
+
+fn gregorian(year: i32, month: i32, day: i32) -> Moment {
+    fixed_from_gregorian(Gregorian { year, month, day }) as Moment
+}
+
+fn assert_close(found: f64, expected: f64, tolerance: f64) {
+    assert!((found - expected).abs() < tolerance, "found {} instead of {}", found, expected);
+}
+
+#[test]
+fn ephemeris_correction_in_seconds() {
+    // espenak and meeus, five millennium canon of solar eclipses
+    for &(year, seconds, tolerance) in &[(1620, 95.4, 1.0), (1850, 7.1, 0.5), (1950, 29.1, 0.5), (2000, 63.8, 0.5), (2100, 202.7, 1.0)] {
+        assert_close(ephemeris_correction(gregorian(year, 1, 1)) * 86400.0, seconds, tolerance);
+    }
+    // the fits for before and after 2050 meet
+    assert_close(ephemeris_correction(gregorian(2051, 1, 1)) * 86400.0, ephemeris_correction(gregorian(2050, 1, 1)) * 86400.0, 3.0);
+
+    let tee = gregorian(2000, 1, 1);
+    assert_close(universal_from_dynamical(dynamical_from_universal(tee)), tee, 1e-9);
+}
+
+#[test]
+fn equation_of_time_of_meeus() {
+    // meeus, astronomical algorithms, example 28.a, 13m 42.6s on 13 october 1992 at 0h dynamical time
+    let tee = universal_from_dynamical(gregorian(1992, 10, 13));
+    assert_close(equation_of_time(tee) * 1440.0, 13.0 + 42.6 / 60.0, 0.01);
+}
+
+#[test]
+fn local_standard_and_apparent_time() {
+    let tee = gregorian(2026, 10, 19) + 0.25;
+    assert_close(local_from_universal(tee, JERUSALEM) - tee, 35.24 / 360.0, 1e-9);
+    assert_close(standard_from_universal(tee, JERUSALEM) - tee, 2.0 / 24.0, 1e-9);
+    // greenwich mean time is local mean time at greenwich
+    let greenwich = Location { latitude: 51.4777, longitude: 0.0, elevation: 46.0, zone: 0.0 };
+    assert_close(standard_from_local(tee, greenwich), tee, 1e-9);
+    // the sundial is about a quarter of an hour ahead in late october
+    assert_close((apparent_from_universal(tee, JERUSALEM) - local_from_universal(tee, JERUSALEM)) * 1440.0, 15.0, 1.0);
+
+    for &convert in &[
+        |tee| universal_from_local(local_from_universal(tee, JERUSALEM), JERUSALEM),
+        |tee| universal_from_standard(standard_from_universal(tee, JERUSALEM), JERUSALEM),
+        |tee| local_from_standard(standard_from_local(tee, JERUSALEM), JERUSALEM),
+        |tee| universal_from_apparent(apparent_from_universal(tee, JERUSALEM), JERUSALEM)
+    ] {
+        assert_close(convert(tee), tee, 1e-5);
+    }
+}
+
+#[test]
+fn corrections_to_the_longitude_of_the_sun() {
+    // meeus, examples 22.a and 25.a, 10 april 1987 and 13 october 1992 at 0h dynamical time
+    let tee = universal_from_dynamical(gregorian(1987, 4, 10));
+    assert_close(obliquity(tee), 23.0 + 26.0 / 60.0 + 27.407 / 3600.0, 1e-5);
+    // the series of calendrical calculations are within an arcsecond of the full ones
+    assert_close(nutation(tee) * 3600.0, -3.788, 1.0);
+    let tee = universal_from_dynamical(gregorian(1992, 10, 13));
+    assert_close(aberration(tee) * 3600.0, -20.539, 1.0);
+}
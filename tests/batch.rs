@@ -0,0 +1,49 @@
+//! batch conversions that reuse the last month against converting every date on its own
+
+extern crate calecalc;
+
+use calecalc::batch::{from_fixed_batch, to_fixed_batch, Cursor};
+use calecalc::calendar::ALL;
+
+/// a run of days, dates jumping around in no order and dates going back and forth across years
+fn dates() -> Vec<i32> {
+    let mut dates: Vec<i32> = (730000..731200).collect();
+    let mut state: u32 = 12345;
+    dates.extend((0..2000).map(|_| {
+        state = state.wrapping_mul(1103515245).wrapping_add(12345);
+        -100000 + (state >> 8) as i32 % 900000
+    }));
+    dates.extend((0..400).map(|i| 738000 + if i % 2 == 0 { i * 3 } else { -i * 5 }));
+    dates
+}
+
+#[test]
+fn from_fixed_batch_is_from_fixed() {
+    let dates = dates();
+    for &calendar in &ALL {
+        let direct: Vec<_> = dates.iter().map(|&date| calendar.from_fixed(date)).collect();
+        assert_eq!(from_fixed_batch(calendar, &dates), direct, "{:?}", calendar);
+    }
+}
+
+#[test]
+fn to_fixed_batch_is_to_fixed() {
+    let dates = dates();
+    for &calendar in &ALL {
+        let converted: Vec<_> = dates.iter().map(|&date| calendar.from_fixed(date)).collect();
+        let direct: Vec<_> = converted.iter().map(|date| date.to_fixed()).collect();
+        assert_eq!(to_fixed_batch(calendar, &converted), direct, "{:?}", calendar);
+    }
+}
+
+#[test]
+fn cursor_steps() {
+    for &calendar in &ALL {
+        let mut cursor = Cursor::new(calendar, 738000);
+        for &days in &[1, 1, 30, -45, 400, -1, 29, -800, 7] {
+            let date = cursor.step(days);
+            assert_eq!(date, calendar.from_fixed(cursor.fixed()), "{:?}", calendar);
+        }
+        assert!(Cursor::new(calendar, 738000).take(800).all(|(fixed, date)| date == calendar.from_fixed(fixed)), "{:?}", calendar);
+    }
+}
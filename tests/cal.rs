@@ -0,0 +1,30 @@
+//! text calendars of the cal command against ones checked by hand in tests/golden
+
+use std::process::Command;
+
+/// what calecalc cal prints with the arguments
+fn cal(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_calecalc")).arg("cal").args(args).output().expect("The binary runs");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    String::from_utf8(output.stdout).expect("The calendar is utf-8")
+}
+
+#[test]
+fn month_grid() {
+    assert_eq!(cal(&["2026-02"]), include_str!("golden/cal_month.txt"));
+    // the same month named, and given as month and year
+    assert_eq!(cal(&["february", "2026"]), include_str!("golden/cal_month.txt"));
+    assert_eq!(cal(&["2", "2026"]), include_str!("golden/cal_month.txt"));
+}
+
+#[test]
+fn overlays_week_start_and_week_numbers() {
+    // 1 february 2026 is a sunday so it is alone in the first row when weeks start on monday
+    assert_eq!(cal(&["2026-02", "--overlay", "hebrew,islamic", "--week-start", "monday", "--week-numbers"]), include_str!("golden/cal_overlay.txt"));
+}
+
+#[test]
+fn hebrew_year_grid() {
+    // 5787 is a leap year with adar i and adar ii
+    assert_eq!(cal(&["--calendar", "hebrew", "5787"]), include_str!("golden/cal_hebrew_year.txt"));
+}
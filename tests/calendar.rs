@@ -0,0 +1,27 @@
+//! checking dates of every calendar
+
+extern crate calecalc;
+
+use calecalc::calendar::{Date, DateError, ALL, FIXED_RANGE};
+use calecalc::arithmetical::gregorian::Gregorian;
+use calecalc::arithmetical::hebrew::Hebrew;
+use calecalc::arithmetical::mayan::long_count::LongCount;
+
+#[test]
+fn dates_of_the_fixed_range_are_valid() {
+    for &calendar in &ALL {
+        for &fixed in &[*FIXED_RANGE.start(), -1, 0, 738000, *FIXED_RANGE.end()] {
+            let date = calendar.from_fixed(fixed);
+            assert_eq!(date.validate(), Ok(()), "{:?}", date);
+        }
+    }
+}
+
+#[test]
+fn fields_too_far_out_to_convert() {
+    assert_eq!(Date::Gregorian(Gregorian { year: i32::MAX, month: 1, day: 1 }).validate(), Err(DateError::InvalidField("year")));
+    assert_eq!(Date::Hebrew(Hebrew { year: i32::MIN, month: 7, day: 1 }).validate(), Err(DateError::InvalidField("year")));
+    assert_eq!(Date::Hebrew(Hebrew { year: 5785, month: i32::MAX, day: 1 }).validate(), Err(DateError::InvalidMonth));
+    assert_eq!(Date::Gregorian(Gregorian { year: 2026, month: 1, day: i32::MIN }).validate(), Err(DateError::InvalidDay));
+    assert_eq!(Date::LongCount(LongCount { baktun: 20000, katun: 0, tun: 0, uinal: 0, kin: 0 }).validate(), Err(DateError::InvalidField("baktun")));
+}
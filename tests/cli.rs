@@ -0,0 +1,94 @@
+//! exit codes and output of the calecalc binary
+
+use std::io::Write;
+use std::process::{Command, Output, Stdio};
+
+/// the exit code of calecalc run with the arguments
+fn exit_code(args: &[&str]) -> i32 {
+    Command::new(env!("CARGO_BIN_EXE_calecalc")).args(args).output().expect("The binary runs").status.code().expect("The binary exits")
+}
+
+#[test]
+fn unexpected_arguments() {
+    assert_eq!(exit_code(&["verify", "extra-arg"]), 2);
+    assert_eq!(exit_code(&["verify", "--help"]), 0);
+    assert_eq!(exit_code(&["no-such-command"]), 2);
+}
+
+#[test]
+fn dates_that_arent_in_the_calendar() {
+    assert_eq!(exit_code(&["convert", "--from", "hebrew", "5785-0-1"]), 3);
+    assert_eq!(exit_code(&["convert", "--from", "hebrew", "5785-14-1"]), 3);
+    assert_eq!(exit_code(&["convert", "--from", "hebrew", "5785-13-1"]), 3);
+    assert_eq!(exit_code(&["convert", "--from", "hebrew", "5784-13-1"]), 0);
+}
+
+#[test]
+fn dates_too_far_out_to_convert() {
+    assert_eq!(exit_code(&["convert", "--from", "gregorian", "2147483647-1-1", "--to", "hebrew"]), 3);
+    assert_eq!(exit_code(&["convert", "--from", "gregorian", "110000-1-1"]), 3);
+    assert_eq!(exit_code(&["convert", "--from", "rd", "2147483647"]), 3);
+    assert_eq!(exit_code(&["convert", "--from", "mjd", "-2147483648"]), 3);
+    assert_eq!(exit_code(&["convert", "--from", "mjd", "-36700000"]), 3);
+    assert_eq!(exit_code(&["convert", "--from", "long_count", "300.0.0.0.0"]), 3);
+    assert_eq!(exit_code(&["convert", "--from", "tzolkin", "8", "batz", "--on-or-before", "2147483647"]), 3);
+    assert_eq!(exit_code(&["convert", "--from", "rd", "36000000"]), 0);
+    assert_eq!(exit_code(&["convert", "--from", "rd", "-36000000"]), 0);
+}
+
+/// calecalc run with the arguments and the input on standard input
+fn run_with_input(args: &[&str], input: &[u8]) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_calecalc")).args(args)
+        .stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::piped())
+        .spawn().expect("The binary runs");
+    child.stdin.take().expect("Standard input is piped").write_all(input).expect("The input is written");
+    child.wait_with_output().expect("The binary exits")
+}
+
+#[test]
+fn batch_rows_that_arent_dates() {
+    let input = b"name,date\na,5785-7-1\nb,5785-0-1\nc,\nd,5784-13-1\n";
+    let output = run_with_input(&["batch", "--column", "date", "--header", "--from", "hebrew", "--to", "gregorian,julian"], input);
+    assert_eq!(output.status.code(), Some(1));
+    // the rows after a bad one are still converted and an empty date isnt an error
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "\
+name,date,gregorian,julian
+a,5785-7-1,3 October 2024,20 September 2024
+b,5785-0-1,,
+c,,,
+d,5784-13-1,11 March 2024,27 February 2024
+");
+    let errors = String::from_utf8_lossy(&output.stderr);
+    assert!(errors.starts_with("line 3: 5785-0-1 isnt a hebrew date"), "{}", errors);
+    assert_eq!(errors.lines().count(), 2, "{}", errors);
+}
+
+#[test]
+fn batch_rows_that_arent_utf8() {
+    // a latin-1 row in the middle of the input
+    let input = b"name,date\ncaf\xe9,2026-01-01\nb,2026-01-02\n";
+    let output = run_with_input(&["batch", "--column", "date", "--header", "--to", "julian"], input);
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(String::from_utf8_lossy(&output.stdout), "\
+name,date,julian
+caf\u{fffd},2026-01-01,
+b,2026-01-02,20 December 2025
+");
+    let errors = String::from_utf8_lossy(&output.stderr);
+    assert!(errors.starts_with("line 2: not utf-8\n"), "{}", errors);
+}
+
+#[test]
+fn repl_session() {
+    let input = b"julian 1752-09-02\n+ 1\nas gregorian hebrew\nnext easter\nnth 3 monday\n+ 2147483647\n+ 35000000 weeks\n- 1\n";
+    let output = run_with_input(&["repl"], input);
+    assert_eq!(output.status.code(), Some(0));
+    let printed = String::from_utf8_lossy(&output.stdout);
+    for shown in ["13 September 1752", "$2\n  rd         639797", "  gregorian  22 April 1753\n  hebrew     18 Nisan 5513", "$4\n  gregorian  16 April 1753"] {
+        assert!(printed.contains(shown), "{}", printed);
+    }
+    // steps too far out are refused and the session goes on from the last date
+    let errors = String::from_utf8_lossy(&output.stderr);
+    assert_eq!(errors.matches("outside R.D. -36000000 to 36000000").count(), 2, "{}", errors);
+    assert!(printed.ends_with("$5\n  gregorian  15 April 1753\n  hebrew     11 Nisan 5513\n"), "{}", printed);
+}
@@ -0,0 +1,51 @@
+//! the yearly rules of the coincidence search and the search itself
+
+extern crate calecalc;
+
+use calecalc::arithmetical::gregorian::{fixed_from_gregorian, thanksgiving, Gregorian};
+use calecalc::arithmetical::hebrew::hanukkah;
+use calecalc::search::coincidence::{coincidences, coincidences_in_gregorian_year, next_coincidence, Coincidence, Rule};
+
+fn gregorian(year: i32, month: i32, day: i32) -> i32 {
+    fixed_from_gregorian(Gregorian { year, month, day })
+}
+
+#[test]
+fn thanksgiving_is_the_fourth_thursday_of_november() {
+    assert_eq!(thanksgiving(2013), gregorian(2013, 11, 28));
+    assert_eq!(thanksgiving(2024), gregorian(2024, 11, 28));
+    assert_eq!(thanksgiving(2025), gregorian(2025, 11, 27));
+    // november starting on a friday puts it as late as it can be
+    assert_eq!(thanksgiving(2019), gregorian(2019, 11, 28));
+    assert_eq!(thanksgiving(2030), gregorian(2030, 11, 28));
+    // and on a thursday as early
+    assert_eq!(thanksgiving(2018), gregorian(2018, 11, 22));
+}
+
+#[test]
+fn hanukkah_is_25_kislev() {
+    assert_eq!(hanukkah(2013), gregorian(2013, 11, 28));
+    assert_eq!(hanukkah(2023), gregorian(2023, 12, 8));
+    assert_eq!(hanukkah(2024), gregorian(2024, 12, 26));
+    assert_eq!(hanukkah(2025), gregorian(2025, 12, 15));
+}
+
+#[test]
+fn thanksgivukkah() {
+    let rules = [Rule::fixed(thanksgiving), Rule::fixed(hanukkah)];
+    assert_eq!(coincidences(&rules, 1800, 2200), vec![
+        Coincidence { year: 1861, date: gregorian(1861, 11, 28) },
+        Coincidence { year: 2013, date: gregorian(2013, 11, 28) }
+    ]);
+    assert_eq!(next_coincidence(&rules, 1900, 2200), Some(Coincidence { year: 2013, date: gregorian(2013, 11, 28) }));
+    assert_eq!(next_coincidence(&rules, 2014, 2200), None);
+}
+
+#[test]
+fn rules_with_several_or_no_dates() {
+    let thursdays_of_november = Rule::new(|year| (1..=30).map(|day| gregorian(year, 11, day)).filter(|&date| date % 7 == 4).collect());
+    let never = Rule::optional(|_| None);
+    assert_eq!(coincidences_in_gregorian_year(&[thursdays_of_november, Rule::fixed(thanksgiving)], 2025), vec![Coincidence { year: 2025, date: gregorian(2025, 11, 27) }]);
+    assert_eq!(coincidences_in_gregorian_year(&[Rule::fixed(thanksgiving), never], 2025), vec![]);
+    assert_eq!(coincidences_in_gregorian_year(&[], 2025), vec![]);
+}
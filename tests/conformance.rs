@@ -0,0 +1,23 @@
+//! every calendar against the published sample dates
+
+extern crate calecalc;
+
+use calecalc::calendar::ALL;
+use calecalc::conformance::{verify, verify_calendar, SAMPLE_DATES};
+
+#[test]
+fn sample_dates_all_convert() {
+    let report = verify();
+    assert_eq!(report.checked, 2 * ALL.len() * SAMPLE_DATES.len());
+    for mismatch in &report.mismatches {
+        eprintln!("{}", mismatch);
+    }
+    assert!(report.is_ok(), "{} mismatches", report.mismatches.len());
+}
+
+#[test]
+fn sample_dates_of_each_calendar() {
+    for &calendar in &ALL {
+        assert_eq!(verify_calendar(calendar), vec![], "{:?}", calendar);
+    }
+}
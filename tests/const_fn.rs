@@ -0,0 +1,44 @@
+//! the conversions that are const fn, worked out when these consts are compiled
+
+extern crate calecalc;
+
+use calecalc::arithmetical::coptic_ethiopic::coptic::{coptic_from_fixed, fixed_from_coptic};
+use calecalc::arithmetical::coptic_ethiopic::CopticEthiopic;
+use calecalc::arithmetical::gregorian::{fixed_from_gregorian, gregorian_from_fixed, thanksgiving, Gregorian};
+use calecalc::arithmetical::islamic::{fixed_from_islamic, islamic_from_fixed, Islamic};
+use calecalc::arithmetical::julian::{fixed_from_julian, julian_from_fixed, Julian};
+use calecalc::arithmetical::julian_day::{fixed_from_mjd, mjd_from_fixed};
+use calecalc::arithmetical::mayan::long_count::{long_count_from_fixed, LongCount};
+use calecalc::arithmetical::mayan::tzolkin::{mayan_tzolkin_from_fixed, Tzolkin};
+use calecalc::common::cycles_of_days::day_of_week_from_fixed;
+use calecalc::common::divide;
+
+const DIVIDED: (i32, i32) = divide(-7, 3);
+const MOON_LANDING: i32 = fixed_from_gregorian(Gregorian { year: 1969, month: 7, day: 20 });
+const GREGORIAN: Gregorian = gregorian_from_fixed(MOON_LANDING);
+const WEEKDAY: i32 = day_of_week_from_fixed(MOON_LANDING);
+const JULIAN: Julian = julian_from_fixed(MOON_LANDING);
+const ISLAMIC: Islamic = islamic_from_fixed(MOON_LANDING);
+const COPTIC: CopticEthiopic = coptic_from_fixed(MOON_LANDING);
+const LONG_COUNT: LongCount = long_count_from_fixed(MOON_LANDING);
+const TZOLKIN: Tzolkin = mayan_tzolkin_from_fixed(MOON_LANDING);
+const MJD: i32 = mjd_from_fixed(MOON_LANDING);
+const THANKSGIVING: i32 = thanksgiving(2026);
+
+#[test]
+fn consts_match_the_conversions_at_run_time() {
+    assert_eq!(DIVIDED, (-3, 2));
+    assert_eq!(MOON_LANDING, 718998);
+    assert_eq!(GREGORIAN, Gregorian { year: 1969, month: 7, day: 20 });
+    // a sunday
+    assert_eq!(WEEKDAY, 0);
+    assert_eq!(fixed_from_julian(JULIAN), MOON_LANDING);
+    assert_eq!(JULIAN, Julian { year: 1969, month: 7, day: 7 });
+    assert_eq!(fixed_from_islamic(ISLAMIC), MOON_LANDING);
+    assert_eq!(fixed_from_coptic(COPTIC), MOON_LANDING);
+    assert_eq!(LONG_COUNT, long_count_from_fixed(MOON_LANDING));
+    assert_eq!(TZOLKIN, mayan_tzolkin_from_fixed(MOON_LANDING));
+    assert_eq!(fixed_from_mjd(MJD), MOON_LANDING);
+    assert_eq!(MJD, 40422);
+    assert_eq!(THANKSGIVING, fixed_from_gregorian(Gregorian { year: 2026, month: 11, day: 26 }));
+}
@@ -0,0 +1,42 @@
+//! calendars described in text against the built in ones
+
+extern crate calecalc;
+
+use calecalc::arithmetical::coptic_ethiopic::coptic::{coptic_from_fixed, fixed_from_coptic, is_coptic_leap_year};
+use calecalc::arithmetical::coptic_ethiopic::CopticEthiopic;
+use calecalc::arithmetical::custom::{CustomCalendar, CustomDate};
+
+const COPTIC: &str = "
+# the coptic calendar
+name: coptic
+epoch: 103605
+months: 30 30 30 30 30 30 30 30 30 30 30 30 5
+month_names: Thoout Paope Athor Koiak Tobe Meshir Paremotep Parmoute Pashons Paone Epep Mesore Epagomene
+leap_years: 4 1 1
+leap_day: 13
+";
+
+#[test]
+fn coptic_round_trip() {
+    let custom = CustomCalendar::parse(COPTIC).unwrap();
+    for date in (-200000..800000).step_by(7).chain(103600..103700) {
+        let coptic = coptic_from_fixed(date);
+        let found = custom.date_from_fixed(date);
+        assert_eq!((found.year, found.month, found.day), (coptic.year, coptic.month, coptic.day), "R.D. {}", date);
+        assert_eq!(custom.fixed_from_date(found), date);
+        assert_eq!(custom.is_leap_year(coptic.year), is_coptic_leap_year(coptic.year));
+    }
+}
+
+#[test]
+fn coptic_epagomenal_days() {
+    let custom = CustomCalendar::parse(COPTIC).unwrap();
+    // 1739 is a leap year with a 6th epagomenal day
+    assert_eq!(custom.days_in_month(1739, 13), Some(6));
+    assert_eq!(custom.days_in_month(1740, 13), Some(5));
+    let sixth = CustomDate { year: 1739, month: 13, day: 6 };
+    assert_eq!(custom.fixed_from_date(sixth), fixed_from_coptic(CopticEthiopic { year: 1739, month: 13, day: 6 }));
+    assert_eq!(custom.format(sixth), "6 Epagomene 1739");
+    assert_eq!(custom.parse_date("6 Epagomene 1739"), Ok(sixth));
+    assert!(custom.parse_date("6 Epagomene 1740").is_err());
+}
@@ -0,0 +1,59 @@
+//! dates packed into codes and unpacked again, and codes that arent dates
+
+extern crate calecalc;
+
+use calecalc::arithmetical::gregorian::Gregorian;
+use calecalc::arithmetical::mayan::long_count::LongCount;
+use calecalc::calendar::{Date, ALL};
+use calecalc::encoding::{decode, encode, tag, EncodingError};
+
+#[test]
+fn round_trip_in_date_order() {
+    for &calendar in &ALL {
+        let mut last = None;
+        for fixed in (-800000..800000).step_by(997).chain(738000..738800) {
+            let date = calendar.from_fixed(fixed);
+            let code = encode(&date).unwrap();
+            assert_eq!(decode(code), Ok(date), "{:?}", date);
+            // the cyclic calendars have no years so only the others sort like their dates
+            if calendar.fields().contains(&"year") || calendar.fields().contains(&"baktun") {
+                if let Some((last_fixed, last_code)) = last {
+                    assert_eq!(fixed > last_fixed, code > last_code, "{:?}", date);
+                }
+            }
+            last = Some((fixed, code));
+        }
+    }
+}
+
+#[test]
+fn years_too_far_out_to_convert() {
+    let year = |year| Date::Gregorian(Gregorian { year, month: 1, day: 1 });
+    assert!(encode(&year(110_000)).is_ok());
+    assert_eq!(encode(&year(i32::MAX)), Err(EncodingError::FieldOutOfRange("year")));
+    let code = encode(&year(0)).unwrap();
+    // the year is the 32 bits after the tag, all of them set is i32::MAX
+    assert_eq!(decode(code | 0x7fff_ffff << 24), Err(EncodingError::FieldOutOfRange("year")));
+    let baktun = Date::LongCount(LongCount { baktun: 20000, katun: 0, tun: 0, uinal: 0, kin: 0 });
+    assert_eq!(encode(&baktun), Err(EncodingError::FieldOutOfRange("baktun")));
+}
+
+#[test]
+fn corrupted_codes() {
+    let mut state: u64 = 12345;
+    for &calendar in &ALL {
+        let tag = (tag(calendar) as u64) << 56;
+        for i in 0..100000 {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            // every other code has a year of 0 to 65535 so its other fields get checked too
+            let fields = if i % 2 == 0 { state >> 8 } else { state >> 24 | 0x8000_0000 << 24 };
+            // the fields either decode to a date that encodes to the same code or are an error
+            let code = tag | fields;
+            if let Ok(date) = decode(code) {
+                assert_eq!(date.calendar(), calendar);
+                assert_eq!(encode(&date), Ok(code), "{:?}", date);
+                date.to_fixed();
+            }
+        }
+    }
+}
@@ -0,0 +1,41 @@
+//! lengths of hebrew months and years
+
+extern crate calecalc;
+
+use calecalc::arithmetical::gregorian::{fixed_from_gregorian, Gregorian};
+use calecalc::arithmetical::hebrew::{fixed_from_hebrew, hebrew_from_fixed, last_day_of_hebrew_month, Hebrew};
+use calecalc::calendar::{Date, DateError};
+
+fn gregorian(year: i32, month: i32, day: i32) -> i32 {
+    fixed_from_gregorian(Gregorian { year, month, day })
+}
+
+#[test]
+fn adar_has_29_days_in_common_years() {
+    // 5785 is a common year and 5784 a leap year
+    assert_eq!(last_day_of_hebrew_month(12, 5785), 29);
+    assert_eq!(last_day_of_hebrew_month(12, 5784), 30);
+    assert_eq!(last_day_of_hebrew_month(13, 5784), 29);
+
+    // 1 adar 5785 is march 1 2025 and 1 nisan 5785 is march 30 2025
+    assert_eq!(fixed_from_hebrew(Hebrew { year: 5785, month: 12, day: 1 }), gregorian(2025, 3, 1));
+    assert_eq!(fixed_from_hebrew(Hebrew { year: 5785, month: 1, day: 1 }), gregorian(2025, 3, 30));
+    assert_eq!(hebrew_from_fixed(gregorian(2025, 3, 29)), Hebrew { year: 5785, month: 12, day: 29 });
+    assert_eq!(hebrew_from_fixed(gregorian(2025, 3, 30)), Hebrew { year: 5785, month: 1, day: 1 });
+}
+
+#[test]
+fn months_outside_the_year() {
+    // months past the year are counted as if there were more 30 day months after adar ii
+    assert_eq!(fixed_from_hebrew(Hebrew { year: 5785, month: 0, day: 1 }), fixed_from_hebrew(Hebrew { year: 5785, month: 1, day: 1 }));
+    assert_eq!(fixed_from_hebrew(Hebrew { year: 5784, month: 14, day: 1 }), fixed_from_hebrew(Hebrew { year: 5784, month: 1, day: 1 }));
+    assert_eq!(fixed_from_hebrew(Hebrew { year: 5784, month: 15, day: 1 }), fixed_from_hebrew(Hebrew { year: 5784, month: 1, day: 31 }));
+
+    for &month in &[0, 14, -1, 20] {
+        assert!(Date::Hebrew(Hebrew { year: 5785, month, day: 1 }).validate().is_err(), "month {}", month);
+    }
+    assert_eq!(Date::Hebrew(Hebrew { year: 5785, month: 0, day: 1 }).validate(), Err(DateError::InvalidMonth));
+    assert_eq!(Date::Hebrew(Hebrew { year: 5785, month: 14, day: 1 }).validate(), Err(DateError::InvalidMonth));
+    assert_eq!(Date::Hebrew(Hebrew { year: 5785, month: 13, day: 1 }).validate(), Err(DateError::InvalidMonth));
+    assert_eq!(Date::Hebrew(Hebrew { year: 5784, month: 13, day: 1 }).validate(), Ok(()));
+}
@@ -0,0 +1,20 @@
+//! hindu solar dates where the start of the year falls on a whole number of days
+
+extern crate calecalc;
+
+use calecalc::arithmetical::hindu::solar::{fixed_from_hindu_solar, hindu_solar_from_fixed, HinduSolar};
+
+#[test]
+fn years_starting_on_a_whole_day() {
+    // every 576 years the sum for the first of the year lands exactly on a day
+    assert_eq!(hindu_solar_from_fixed(813139), HinduSolar { year: 5328, month: 1, day: 1 });
+    assert_eq!(fixed_from_hindu_solar(HinduSolar { year: 5328, month: 1, day: 1 }), 813139);
+    assert_eq!(fixed_from_hindu_solar(HinduSolar { year: 4752, month: 1, day: 1 }), 602750);
+    assert_eq!(fixed_from_hindu_solar(HinduSolar { year: 5327, month: 12, day: 30 }), 813138);
+
+    for &start in &[-238806, -28417, 181972, 392361, 602750, 813139] {
+        for date in start - 40..start + 40 {
+            assert_eq!(fixed_from_hindu_solar(hindu_solar_from_fixed(date)), date, "{:?}", hindu_solar_from_fixed(date));
+        }
+    }
+}
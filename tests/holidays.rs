@@ -0,0 +1,57 @@
+//! holidays of a gregorian year, and the filters of the holidays command
+
+extern crate calecalc;
+
+use calecalc::arithmetical::gregorian::{fixed_from_gregorian, Gregorian};
+use calecalc::holidays::{holidays_in_gregorian_year, Tradition, TRADITIONS};
+use std::process::Command;
+
+fn gregorian(year: i32, month: i32, day: i32) -> i32 {
+    fixed_from_gregorian(Gregorian { year, month, day })
+}
+
+#[test]
+fn holidays_of_2026() {
+    let holidays = holidays_in_gregorian_year(2026, &TRADITIONS);
+    let date = |name: &str| holidays.iter().find(|holiday| holiday.name == name).map(|holiday| holiday.date);
+    assert_eq!(date("Passover"), Some(gregorian(2026, 4, 2)));
+    assert_eq!(date("Yom Kippur"), Some(gregorian(2026, 9, 21)));
+    assert_eq!(date("Easter"), Some(gregorian(2026, 4, 5)));
+    assert_eq!(date("Orthodox Easter"), Some(gregorian(2026, 4, 12)));
+    assert_eq!(date("Labor Day"), Some(gregorian(2026, 9, 7)));
+    assert!(holidays.windows(2).all(|pair| pair[0].date <= pair[1].date));
+
+    let us = holidays_in_gregorian_year(2026, &[Tradition::Us]);
+    assert!(!us.is_empty() && us.iter().all(|holiday| holiday.tradition == Tradition::Us));
+}
+
+/// what calecalc holidays prints with the arguments, and its exit code
+fn holidays(args: &[&str]) -> (String, i32) {
+    let output = Command::new(env!("CARGO_BIN_EXE_calecalc")).arg("holidays").args(args).output().expect("The binary runs");
+    (String::from_utf8_lossy(&output.stdout).into_owned(), output.status.code().expect("The binary exits"))
+}
+
+#[test]
+fn month_and_name_filters() {
+    assert_eq!(holidays(&["2026", "--month", "april", "--name", "easter"]), ("\
+2026-04-05  Sun  Easter           christian
+2026-04-12  Sun  Orthodox Easter  orthodox
+".to_string(), 0));
+    assert_eq!(holidays(&["2026", "--month", "9", "--traditions", "us,jewish"]), ("\
+2026-09-07  Mon  Labor Day      us
+2026-09-12  Sat  Rosh Hashanah  jewish
+2026-09-21  Mon  Yom Kippur     jewish
+2026-09-26  Sat  Sukkot         jewish
+".to_string(), 0));
+    assert_eq!(holidays(&["2026", "--name", "no such holiday"]), (String::new(), 0));
+    assert_eq!(holidays(&["2026", "--month", "13"]).1, 2);
+}
+
+#[test]
+fn years_too_far_out() {
+    assert_eq!(holidays(&["2147483647"]).1, 3);
+    assert_eq!(holidays(&["1000000", "--traditions", "jewish"]).1, 3);
+    assert_eq!(holidays(&["1000000", "--traditions", "islamic"]).1, 3);
+    assert_eq!(holidays(&["-110001"]).1, 3);
+    assert_eq!(holidays(&["110000", "--traditions", "islamic"]).1, 0);
+}
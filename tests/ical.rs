@@ -0,0 +1,117 @@
+//! icalendar feeds written and read back
+
+extern crate calecalc;
+
+use calecalc::arithmetical::gregorian::{fixed_from_gregorian, Gregorian};
+use calecalc::ical::{escape, fold, read, unescape, Event, Feed};
+
+fn gregorian(year: i32, month: i32, day: i32) -> i32 {
+    fixed_from_gregorian(Gregorian { year, month, day })
+}
+
+#[test]
+fn folding_keeps_lines_to_75_octets() {
+    assert_eq!(fold("SUMMARY:short"), "SUMMARY:short\r\n");
+    let line = format!("SUMMARY:{}", "abcdefghij".repeat(20));
+    let folded = fold(&line);
+    assert_eq!(&folded[..77], &format!("{}\r\n", &line[..75])[..]);
+    // a folded line starts with a space which counts towards its 75 octets
+    assert!(folded[77..].starts_with(' '));
+
+    // characters of several octets arent split across lines
+    let line = format!("SUMMARY:{}", "חנוכה ".repeat(30));
+    let folded = fold(&line);
+    for part in folded.split("\r\n") {
+        assert!(part.len() <= 75, "{:?}", part);
+    }
+    assert_eq!(folded.trim_end_matches("\r\n").replace("\r\n ", ""), line);
+}
+
+#[test]
+fn escaped_text_reads_back() {
+    assert_eq!(escape("a, b; c\\d\ne"), "a\\, b\\; c\\\\d\\ne");
+    assert_eq!(escape("a\r\nb"), "a\\nb");
+    for &text in &["a, b; c\\d\ne", "plain", "\\n", "ends with \\"] {
+        assert_eq!(unescape(&escape(text)), text);
+    }
+}
+
+#[test]
+fn uids_stay_the_same_when_written_again() {
+    let date = gregorian(2026, 12, 5);
+    let event = Event::new(date, "Hanukkah, first candle");
+    assert_eq!(event.uid("example.org"), "hanukkahfirstcandle-20261205@example.org");
+    assert_eq!(event.clone().description("other text").uid("example.org"), event.uid("example.org"));
+    assert_eq!(event.clone().key("Jewish Hanukkah").uid("example.org"), "jewishhanukkah-20261205@example.org");
+
+    let feed = || {
+        let mut feed = Feed::new("holidays", gregorian(2026, 10, 19)).domain("example.org");
+        assert!(feed.add(event.clone()));
+        // the same event again isnt added twice
+        assert!(!feed.add(event.clone().description("again")));
+        assert!(feed.add(Event::new(date + 1, "Hanukkah, first candle")));
+        // icalendar dates only have years 0 to 9999
+        assert!(!feed.add(Event::new(gregorian(10000, 1, 1), "too late")));
+        // the last day of 9999 ends on a day that cant be written either
+        assert!(!feed.add(Event::new(gregorian(9999, 12, 31), "too late")));
+        assert!(feed.add(Event::new(gregorian(9999, 12, 30), "just in time")));
+        assert!(feed.add(Event::new(gregorian(0, 1, 1), "first day")));
+        assert!(!feed.add(Event::new(gregorian(0, 1, 1) - 1, "too early")));
+        feed
+    };
+    assert_eq!(feed().events().len(), 4);
+    assert_eq!(feed().to_string(), feed().to_string());
+}
+
+#[test]
+fn written_feeds_read_back() {
+    let mut feed = Feed::new("feed; with, escapes", gregorian(2026, 10, 19));
+    let summary = format!("a long summary, with escapes; {}", "and words ".repeat(12));
+    feed.add(Event::new(gregorian(2026, 3, 3), summary.clone()).description("line one\nline two").category("test"));
+    let text = feed.to_string();
+    assert!(text.ends_with("END:VCALENDAR\r\n"));
+    assert!(text.split("\r\n").all(|line| line.len() <= 75));
+    assert!(text.contains("DTSTART;VALUE=DATE:20260303\r\n"));
+    assert!(text.contains("DTEND;VALUE=DATE:20260304\r\n"));
+    assert!(text.contains("TRANSP:TRANSPARENT\r\n"));
+
+    let events = read(&text).unwrap();
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].uid, Some(feed.events()[0].uid("calecalc")));
+    assert_eq!(events[0].summary.as_deref(), Some(&summary[..]));
+    assert_eq!(events[0].description.as_deref(), Some("line one\nline two"));
+    assert_eq!(events[0].start, gregorian(2026, 3, 3));
+}
+
+fn event(start: &str, rule: &str) -> String {
+    format!("BEGIN:VCALENDAR\r\nBEGIN:VEVENT\r\nUID:test\r\nDTSTART;VALUE=DATE:{}\r\nRRULE:{}\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n", start, rule)
+}
+
+#[test]
+fn nth_weekdays_only_of_months_and_years() {
+    assert!(read(&event("20260105", "FREQ=WEEKLY;BYDAY=99MO")).is_err());
+    assert!(read(&event("20260105", "FREQ=WEEKLY;BYDAY=1MO")).is_err());
+    assert!(read(&event("20260105", "FREQ=DAILY;BYDAY=-1MO")).is_err());
+    // the nth weekday is of the month even in a yearly rule
+    assert!(read(&event("20260105", "FREQ=YEARLY;BYDAY=6MO")).is_err());
+    assert!(read(&event("20260105", "FREQ=WEEKLY;BYDAY=MO,WE")).is_ok());
+
+    // the 5th monday and the last monday of every month
+    let events = read(&event("20260105", "FREQ=MONTHLY;BYDAY=5MO;COUNT=3")).unwrap();
+    assert_eq!(events[0].occurrences_between(gregorian(2026, 1, 1), gregorian(2027, 1, 1)), vec![gregorian(2026, 1, 5), gregorian(2026, 3, 30), gregorian(2026, 6, 29)]);
+    let events = read(&event("20260126", "FREQ=MONTHLY;BYDAY=-1MO;COUNT=3")).unwrap();
+    assert_eq!(events[0].occurrences_between(gregorian(2026, 1, 1), gregorian(2027, 1, 1)), vec![gregorian(2026, 1, 26), gregorian(2026, 2, 23), gregorian(2026, 3, 30)]);
+}
+
+#[test]
+fn days_past_the_end_of_hebrew_months() {
+    // no hebrew month has a 31st day so only the start is an occurrence
+    let events = read(&event("20261011", "RSCALE=HEBREW;FREQ=MONTHLY;BYMONTHDAY=31;COUNT=3")).unwrap();
+    assert_eq!(events[0].occurrences_between(gregorian(2026, 1, 1), gregorian(2030, 1, 1)), vec![gregorian(2026, 10, 11)]);
+
+    // 30 heshvan only in some years
+    let events = read(&event("20261111", "RSCALE=HEBREW;FREQ=MONTHLY;BYMONTHDAY=30;COUNT=4")).unwrap();
+    let dates = events[0].occurrences_between(gregorian(2026, 1, 1), gregorian(2030, 1, 1));
+    assert_eq!(dates.len(), 4);
+    assert!(dates.windows(2).all(|pair| (29..=60).contains(&(pair[1] - pair[0]))), "{:?}", dates);
+}
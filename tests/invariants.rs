@@ -0,0 +1,37 @@
+//! the conversions of every calendar against each other and the lengths of their months and years
+
+extern crate calecalc;
+
+use calecalc::calendar::Calendar;
+use calecalc::conformance::invariants::{check, check_all, check_with, Violation};
+
+#[test]
+fn every_calendar_holds_together() {
+    for report in check_all(700000, 740000) {
+        for violation in report.violations.iter().take(5) {
+            eprintln!("{}", violation);
+        }
+        assert!(report.is_ok(), "{:?} has {} violations", report.calendar, report.violations.len());
+    }
+    // years far from the present, and the turn of the era
+    for &calendar in &[Calendar::Hebrew, Calendar::HinduLunisolar, Calendar::Roman, Calendar::Islamic] {
+        assert!(check(calendar, -2000, 2000).is_ok(), "{:?}", calendar);
+        assert!(check(calendar, -1_000_000, -990_000).is_ok(), "{:?}", calendar);
+    }
+}
+
+#[test]
+fn broken_round_trip_is_reported() {
+    // a conversion that skips a day, so R.D. 738000 converts to the date of the day after it
+    let skipping = |fixed| Calendar::Gregorian.from_fixed(if fixed == 738000 { fixed + 1 } else { fixed });
+    let report = check_with(Calendar::Gregorian, 737990, 738010, skipping);
+    assert!(!report.is_ok());
+    let date = Calendar::Gregorian.from_fixed(738001);
+    assert!(report.violations.contains(&Violation::RoundTrip { fixed: 738000, date, back: 738001 }), "{:?}", report.violations);
+    assert!(report.violations.iter().all(|violation| match *violation {
+        Violation::RoundTrip { fixed, .. } | Violation::Succession { fixed, .. } => fixed == 737999 || fixed == 738000,
+        _ => false
+    }), "{:?}", report.violations);
+
+    assert!(check_with(Calendar::Gregorian, 737990, 738010, |fixed| Calendar::Gregorian.from_fixed(fixed)).is_ok());
+}
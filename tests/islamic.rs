@@ -0,0 +1,35 @@
+//! birashks variant of the arithmetic islamic calendar, which isnt one of the calendars `check_all` covers
+
+extern crate calecalc;
+
+use calecalc::arithmetical::islamic::{fixed_from_islamic_birashk, is_islamic_birashk_leap_year, islamic_birashk_from_fixed, Islamic};
+
+#[test]
+fn birashk_round_trip_and_succession() {
+    let mut previous = islamic_birashk_from_fixed(-200000);
+    for date in -199999..1000000 {
+        let islamic = islamic_birashk_from_fixed(date);
+        assert_eq!(fixed_from_islamic_birashk(islamic), date, "{:?}", islamic);
+        let follows = islamic == Islamic { day: previous.day + 1, ..previous }
+            || islamic == Islamic { month: previous.month + 1, day: 1, ..previous }
+            || islamic == Islamic { year: previous.year + 1, month: 1, day: 1 };
+        assert!(follows, "{:?} after {:?}", islamic, previous);
+        previous = islamic;
+    }
+}
+
+#[test]
+fn birashk_year_and_month_lengths() {
+    for year in -500..3000 {
+        let start = fixed_from_islamic_birashk(Islamic { year, month: 1, day: 1 });
+        let length = fixed_from_islamic_birashk(Islamic { year: year + 1, month: 1, day: 1 }) - start;
+        assert_eq!(length, if is_islamic_birashk_leap_year(year) { 355 } else { 354 }, "{}", year);
+        for month in 1..=12 {
+            let last = islamic_birashk_from_fixed(fixed_from_islamic_birashk(Islamic { year, month, day: 1 }) + 28);
+            assert_eq!((last.year, last.month, last.day), (year, month, 29));
+        }
+    }
+    // the 15th year of a cycle is leap instead of the 16th
+    assert!(is_islamic_birashk_leap_year(15) && !is_islamic_birashk_leap_year(16));
+    assert_eq!(islamic_birashk_from_fixed(fixed_from_islamic_birashk(Islamic { year: 15, month: 12, day: 30 })), Islamic { year: 15, month: 12, day: 30 });
+}
@@ -0,0 +1,64 @@
+//! new and full moons against the times the us naval observatory publishes, in universal time,
+//! and the molad against the times published in hebrew calendars
+
+extern crate calecalc;
+
+use calecalc::arithmetical::gregorian::{fixed_from_gregorian, Gregorian};
+use calecalc::arithmetical::hebrew::{molad, Molad};
+use calecalc::astronomical::lunar::{lunar_phase_at_or_after, molad_and_conjunction, new_moon_at_or_after, FIRST_QUARTER, FULL, LAST_QUARTER, NEW};
+use calecalc::common::Moment;
+
+/// the published times are rounded to the minute
+const TOLERANCE: f64 = 2.0 / 1440.0;
+
+fn gregorian(year: i32, month: i32, day: i32) -> i32 {
+    fixed_from_gregorian(Gregorian { year, month, day })
+}
+
+fn moment(year: i32, month: i32, day: i32, hour: i32, minute: i32) -> Moment {
+    gregorian(year, month, day) as Moment + (hour * 60 + minute) as Moment / 1440.0
+}
+
+fn assert_close(found: Moment, expected: Moment) {
+    assert!((found - expected).abs() < TOLERANCE, "found R.D. {} instead of R.D. {}, {} minutes off", found, expected, (found - expected) * 1440.0);
+}
+
+#[test]
+fn phases_of_january_2026() {
+    let start = moment(2026, 1, 1, 0, 0);
+    assert_close(lunar_phase_at_or_after(FULL, start), moment(2026, 1, 3, 10, 3));
+    assert_close(lunar_phase_at_or_after(LAST_QUARTER, start), moment(2026, 1, 10, 15, 48));
+    assert_close(lunar_phase_at_or_after(NEW, start), moment(2026, 1, 18, 19, 52));
+    assert_close(new_moon_at_or_after(start), moment(2026, 1, 18, 19, 52));
+    assert_close(lunar_phase_at_or_after(FIRST_QUARTER, start), moment(2026, 1, 26, 4, 47));
+}
+
+#[test]
+fn moons_of_eclipses() {
+    // the total solar eclipse of april 8 2024 and the eclipses of march 2025
+    assert_close(new_moon_at_or_after(moment(2024, 4, 1, 0, 0)), moment(2024, 4, 8, 18, 21));
+    assert_close(lunar_phase_at_or_after(FULL, moment(2025, 3, 1, 0, 0)), moment(2025, 3, 14, 6, 55));
+    assert_close(new_moon_at_or_after(moment(2025, 3, 15, 0, 0)), moment(2025, 3, 29, 10, 58));
+}
+
+#[test]
+fn new_moon_of_february_1977() {
+    // meeus works this one out in astronomical algorithms as 3:37:42 dynamical time, 48 seconds ahead
+    assert_close(new_moon_at_or_after(moment(1977, 2, 1, 0, 0)), moment(1977, 2, 18, 3, 37));
+}
+
+#[test]
+fn molads_of_recent_months() {
+    let at = |year, month, day, hour, minute, chalakim| Molad { date: gregorian(year, month, day), hour, minute, chalakim };
+    // tishri starts the year and nisan, month 1, is in the middle of it
+    assert_eq!(molad(7, 5785), at(2024, 10, 3, 3, 21, 13));
+    assert_eq!(molad(1, 5785), at(2025, 3, 29, 7, 46, 1));
+    assert_eq!(molad(7, 5786), at(2025, 9, 22, 12, 10, 7));
+    assert_eq!(molad(10, 5786), at(2025, 12, 20, 2, 22, 10));
+    assert_eq!(molad(1, 5786), at(2026, 3, 18, 16, 34, 13));
+
+    // the molad of nisan 5785 is less than a day before the new moon of the partial solar eclipse
+    let (mean, conjunction) = molad_and_conjunction(1, 5785);
+    assert_close(conjunction, moment(2025, 3, 29, 10, 58));
+    assert!(mean < conjunction && conjunction - mean < 1.0, "{} {}", mean, conjunction);
+}
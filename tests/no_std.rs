@@ -0,0 +1,25 @@
+//! the library without std, and with only alloc, still builds
+
+use std::process::Command;
+
+/// builds the library with the features into its own target directory so it doesnt wait on
+/// the build running the tests
+fn builds(features: &[&str]) -> bool {
+    let manifest = env!("CARGO_MANIFEST_DIR");
+    Command::new(env!("CARGO"))
+        .args(["build", "--quiet", "--lib", "--no-default-features"])
+        .args(features)
+        .arg("--manifest-path").arg(format!("{}/Cargo.toml", manifest))
+        .arg("--target-dir").arg(format!("{}/target/no_std", manifest))
+        .status().expect("Cargo runs").success()
+}
+
+#[test]
+fn core_only() {
+    assert!(builds(&[]));
+}
+
+#[test]
+fn alloc_without_std() {
+    assert!(builds(&["--features", "alloc"]));
+}
@@ -0,0 +1,46 @@
+//! monthly intervals of recurrences against counting the months day by day
+
+extern crate calecalc;
+
+use calecalc::calendar::Calendar;
+use calecalc::recurrence::{Frequency, Recurrence};
+
+const START: i32 = 738000;
+const END: i32 = 742000;
+
+/// the days in [START, END] that are on `day` of every `interval`th month from the month of START
+fn every_day(calendar: Calendar, day: i32, interval: i32) -> Vec<i32> {
+    let month = |date: i32| {
+        let fields = calendar.from_fixed(date);
+        (fields.field("year"), fields.field("month"), fields.field("leap_month"))
+    };
+    let mut months = 0;
+    (START..=END).filter(|&date| {
+        if date > START && month(date) != month(date - 1) {
+            months += 1;
+        }
+        months % interval == 0 && calendar.from_fixed(date).field("day") == Some(day)
+    }).collect()
+}
+
+#[test]
+fn monthly_intervals() {
+    for &calendar in &[
+        Calendar::Gregorian, Calendar::Julian, Calendar::Coptic, Calendar::Ethiopic, Calendar::Islamic,
+        Calendar::Hebrew, Calendar::HinduSolar, Calendar::HinduLunisolar
+    ] {
+        for &(day, interval) in &[(1, 1), (3, 2), (29, 3), (30, 5)] {
+            let recurrence = Recurrence::new(calendar, Frequency::Monthly, START).by_month_day(&[day]).interval(interval).until(END);
+            assert_eq!(recurrence.occurrences().collect::<Vec<_>>(), every_day(calendar, day, interval), "{:?} day {} every {} months", calendar, day, interval);
+        }
+    }
+}
+
+#[test]
+fn monthly_without_months() {
+    // a long count month is a winal of 20 days and other calendars without months count years
+    let winals: Vec<i32> = Recurrence::new(Calendar::LongCount, Frequency::Monthly, START).interval(4).count(3).occurrences().collect();
+    assert_eq!(winals, vec![START, START + 80, START + 160]);
+    let rounds: Vec<i32> = Recurrence::new(Calendar::Tzolkin, Frequency::Monthly, START).interval(2).count(3).occurrences().collect();
+    assert_eq!(rounds, vec![START, START + 520, START + 1040]);
+}
@@ -0,0 +1,27 @@
+//! rendered pages against pages checked by hand in tests/golden
+//!
+//! a change to the layout changes these pages, write them again and look at them before
+//! committing them
+
+extern crate calecalc;
+
+use calecalc::calendar::Calendar;
+use calecalc::holidays::Tradition;
+use calecalc::render::{html, svg, Page};
+
+fn december_2026() -> Page {
+    Page::month(Calendar::Gregorian, 2026, 12).unwrap().secondary(&[Calendar::Hebrew]).holidays(&[Tradition::Jewish, Tradition::Us]).moons()
+}
+
+#[test]
+fn month_page() {
+    assert_eq!(html::render(&december_2026()), include_str!("golden/month.html"));
+    assert_eq!(svg::render(&december_2026()), include_str!("golden/month.svg"));
+}
+
+#[test]
+fn year_page() {
+    // a year of a calendar with a leap month, the weeks starting on monday
+    let year = Page::year(Calendar::Hebrew, 5787).unwrap().week_start(1);
+    assert_eq!(svg::render(&year), include_str!("golden/year.svg"));
+}
@@ -0,0 +1,44 @@
+//! roman dates at the leap day and the turn of the era
+
+extern crate calecalc;
+
+use calecalc::arithmetical::julian::{fixed_from_julian, Julian};
+use calecalc::arithmetical::julian::roman::{fixed_from_roman, roman_from_fixed, Event, Roman};
+
+fn julian(year: i32, month: i32, day: i32) -> i32 {
+    fixed_from_julian(Julian { year, month, day })
+}
+
+#[test]
+fn counts_before_the_kalends_of_march() {
+    let kalends = |count, leap| Roman { year: 2024, month: 3, event: Event::Kalends, count, leap };
+    // the leap day is repeated 6th day before the kalends so only the counts from it back to the ides move
+    assert_eq!(roman_from_fixed(julian(2024, 2, 14)), kalends(16, false));
+    assert_eq!(roman_from_fixed(julian(2024, 2, 24)), kalends(6, false));
+    assert_eq!(roman_from_fixed(julian(2024, 2, 25)), kalends(6, true));
+    assert_eq!(roman_from_fixed(julian(2024, 2, 26)), kalends(5, false));
+    assert_eq!(roman_from_fixed(julian(2024, 2, 29)), kalends(2, false));
+    assert_eq!(fixed_from_roman(kalends(5, false)), julian(2024, 2, 26));
+    assert_eq!(fixed_from_roman(kalends(2, false)), julian(2024, 2, 29));
+
+    for &year in &[2023, 2024, -5, 4] {
+        for date in julian(year, 1, 1)..julian(year, 4, 1) {
+            assert_eq!(fixed_from_roman(roman_from_fixed(date)), date, "{:?}", roman_from_fixed(date));
+        }
+    }
+}
+
+#[test]
+fn kalends_of_january_after_1_bce() {
+    // 1 b.c.e. is julian year -1 and is followed by 1 c.e. without a year 0
+    let kalends = |year, count| Roman { year, month: 1, event: Event::Kalends, count, leap: false };
+    assert_eq!(roman_from_fixed(julian(-1, 12, 14)), kalends(1, 19));
+    assert_eq!(roman_from_fixed(julian(-1, 12, 31)), kalends(1, 2));
+    assert_eq!(roman_from_fixed(julian(1, 1, 1)), kalends(1, 1));
+    assert_eq!(roman_from_fixed(julian(-2, 12, 31)), kalends(-1, 2));
+    assert_eq!(fixed_from_roman(kalends(1, 19)), julian(-1, 12, 14));
+
+    for date in julian(-3, 11, 1)..julian(2, 3, 1) {
+        assert_eq!(fixed_from_roman(roman_from_fixed(date)), date, "{:?}", roman_from_fixed(date));
+    }
+}
@@ -0,0 +1,51 @@
+//! the skipping search of queries against checking every day
+
+extern crate calecalc;
+
+use calecalc::search::query::{query, Query};
+
+const START: i32 = 700000;
+const END: i32 = 740000;
+
+fn assert_same_as_every_day(text: &str, start: i32, end: i32) {
+    let parsed = Query::parse(text).unwrap();
+    let every_day: Vec<i32> = (start..=end).filter(|&date| parsed.matches(date)).collect();
+    assert_eq!(parsed.search(start, end).collect::<Vec<_>>(), every_day, "{}", text);
+}
+
+#[test]
+fn days_of_the_month() {
+    for text in &[
+        "gregorian.day = 13",
+        "julian.day = 31",
+        "coptic.day = 5",
+        "ethiopic.day = 30",
+        "islamic.day = 30",
+        "hebrew.day = 30",
+        "hindu_solar.day = 31",
+        "hindu_lunisolar.day = 13",
+        "hindu_lunisolar.day = 30"
+    ] {
+        assert_same_as_every_day(text, START, END);
+    }
+}
+
+#[test]
+fn days_with_other_fields() {
+    for text in &[
+        "weekday = friday and gregorian.day = 13",
+        "hindu_lunisolar.day = 13 and weekday = friday",
+        "hebrew.day = 1 and hebrew.month = nisan or islamic.day = 1",
+        "gregorian.day = 29 and gregorian.month = february and weekday = monday",
+        "tzolkin.number = 8 and hebrew.day = 13 and not (weekday = saturday)"
+    ] {
+        assert_same_as_every_day(text, START, END);
+    }
+}
+
+#[test]
+fn days_no_month_has() {
+    assert_eq!(query("hebrew.day = 31 and weekday = 5", START, END).unwrap(), vec![]);
+    assert_eq!(query("gregorian.day = 32 or hebrew.day = 31", START, END).unwrap(), vec![]);
+    assert_eq!(query("gregorian.day = 2147483647", START, END).unwrap(), vec![]);
+}
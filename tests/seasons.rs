@@ -0,0 +1,56 @@
+//! equinoxes and solstices against the times the us naval observatory publishes, in universal time
+
+extern crate calecalc;
+
+use calecalc::arithmetical::gregorian::{fixed_from_gregorian, Gregorian};
+use calecalc::astronomical::solar::{season_in_gregorian, solar_longitude, solar_longitude_after, SPRING, SUMMER, AUTUMN, WINTER};
+use calecalc::common::Moment;
+
+/// the published times are rounded to the minute
+const TOLERANCE: f64 = 2.0 / 1440.0;
+
+fn moment(year: i32, month: i32, day: i32, hour: i32, minute: i32) -> Moment {
+    fixed_from_gregorian(Gregorian { year, month, day }) as Moment + (hour * 60 + minute) as Moment / 1440.0
+}
+
+fn assert_close(found: Moment, expected: Moment) {
+    assert!((found - expected).abs() < TOLERANCE, "found R.D. {} instead of R.D. {}, {} minutes off", found, expected, (found - expected) * 1440.0);
+}
+
+#[test]
+fn seasons_of_recent_years() {
+    let table = [
+        (2000, [(3, 20, 7, 35), (6, 21, 1, 48), (9, 22, 17, 28), (12, 21, 13, 37)]),
+        (2010, [(3, 20, 17, 32), (6, 21, 11, 28), (9, 23, 3, 9), (12, 21, 23, 38)]),
+        (2020, [(3, 20, 3, 50), (6, 20, 21, 44), (9, 22, 13, 31), (12, 21, 10, 2)]),
+        (2021, [(3, 20, 9, 37), (6, 21, 3, 32), (9, 22, 19, 21), (12, 21, 15, 59)]),
+        (2024, [(3, 20, 3, 6), (6, 20, 20, 51), (9, 22, 12, 44), (12, 21, 9, 20)]),
+        (2025, [(3, 20, 9, 1), (6, 21, 2, 42), (9, 22, 18, 19), (12, 21, 15, 3)]),
+        (2026, [(3, 20, 14, 46), (6, 21, 8, 24), (9, 23, 0, 5), (12, 21, 20, 50)])
+    ];
+    for &(year, seasons) in &table {
+        for (&season, &(month, day, hour, minute)) in [SPRING, SUMMER, AUTUMN, WINTER].iter().zip(&seasons) {
+            assert_close(season_in_gregorian(season, year), moment(year, month, day, hour, minute));
+        }
+    }
+}
+
+#[test]
+fn june_solstice_of_1962() {
+    // meeus works this one out in astronomical algorithms as 21:25:08 dynamical time, 34 seconds ahead
+    assert_close(season_in_gregorian(SUMMER, 1962), moment(1962, 6, 21, 21, 24));
+}
+
+#[test]
+fn any_longitude_after_a_moment() {
+    let start = moment(2025, 1, 1, 0, 0);
+    for &lambda in &[0.0, 15.0, 45.0, 123.4, 315.0, 359.9] {
+        let found = solar_longitude_after(lambda, start);
+        assert!(found > start && found - start < 366.0, "{} is found at R.D. {}", lambda, found);
+        let difference = solar_longitude(found) - lambda;
+        assert!(difference.abs() < 0.001 || (difference.abs() - 360.0).abs() < 0.001, "{} is found as {}", lambda, solar_longitude(found));
+    }
+    // the next spring equinox after one is a year later
+    let spring = season_in_gregorian(SPRING, 2025);
+    assert_close(solar_longitude_after(SPRING, spring + 1.0), season_in_gregorian(SPRING, 2026));
+}
@@ -0,0 +1,117 @@
+//! starts `calecalc serve` on a free port of localhost and sends it requests
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::{Child, Command, Stdio};
+
+struct Server {
+    child: Child,
+    address: String
+}
+
+impl Server {
+    fn start() -> Server {
+        let mut child = Command::new(env!("CARGO_BIN_EXE_calecalc"))
+            .args(["serve", "--port", "0"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("The binary runs");
+        let mut line = String::new();
+        BufReader::new(child.stdout.as_mut().expect("Stdout is piped")).read_line(&mut line).expect("The server prints its address");
+        let address = line.trim().strip_prefix("listening on http://").expect("The first line is the address").to_string();
+        Server { child, address }
+    }
+
+    /// the status and body of a request
+    fn request(&self, method: &str, target: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(&self.address).expect("The server accepts connections");
+        write!(stream, "{} {} HTTP/1.1\r\nHost: {}\r\n\r\n", method, target, self.address).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").expect("Responses have a head");
+        let status = head.split_whitespace().nth(1).and_then(|status| status.parse().ok()).expect("Responses have a status");
+        (status, body.to_string())
+    }
+
+    fn get(&self, target: &str) -> (u16, String) {
+        self.request("GET", target)
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+#[test]
+fn converts_a_hebrew_date() {
+    let server = Server::start();
+    let (status, body) = server.get("/convert?from=hebrew&date=7+Kislev+5706&to=rd,gregorian");
+    assert_eq!(status, 200);
+    assert!(body.starts_with("{\"rd\":710347,\"gregorian\":{"), "{}", body);
+    assert!(body.contains("\"year\":1945,\"month\":11,\"day\":12"), "{}", body);
+}
+
+#[test]
+fn decodes_the_query() {
+    let server = Server::start();
+    let (status, body) = server.get("/convert?date=1945%2D11%2D12&to=hebrew");
+    assert_eq!(status, 200);
+    assert!(body.contains("\"text\":\"7 Kislev 5706\""), "{}", body);
+}
+
+#[test]
+fn dates_not_in_the_calendar_are_unprocessable() {
+    let server = Server::start();
+    let (status, body) = server.get("/convert?from=gregorian&date=1945-02-30");
+    assert_eq!(status, 422);
+    assert!(body.contains("\"code\":\"invalid_date\""), "{}", body);
+    assert!(body.contains("\"reason\":\"invalid_day\""), "{}", body);
+
+    let (status, body) = server.get("/convert?from=hebrew&date=5785-13-1");
+    assert_eq!(status, 422);
+    assert!(body.contains("\"reason\":\"invalid_month\""), "{}", body);
+
+    // a year whose holidays cant be worked out
+    let (status, body) = server.get("/holidays?year=200000");
+    assert_eq!(status, 422);
+    assert!(body.contains("\"reason\":\"invalid_field\",\"field\":\"year\""), "{}", body);
+}
+
+#[test]
+fn bad_requests() {
+    let server = Server::start();
+    for target in ["/convert?from=klingon&date=1", "/convert?from=hebrew", "/convert?date=2000-1-1&to=hebrew,nope", "/holidays?year=soon", "/holidays?traditions=druid"] {
+        let (status, body) = server.get(target);
+        assert_eq!(status, 400, "{}", target);
+        assert!(body.contains("\"code\":\"bad_request\""), "{}", body);
+    }
+}
+
+#[test]
+fn lists_holidays() {
+    let server = Server::start();
+    let (status, body) = server.get("/holidays?year=2026&traditions=jewish");
+    assert_eq!(status, 200);
+    assert!(body.contains("{\"name\":\"Passover\",\"tradition\":\"jewish\",\"date\":\"2026-04-02\""), "{}", body);
+    assert!(!body.contains("Thanksgiving"), "{}", body);
+}
+
+#[test]
+fn converts_today() {
+    let server = Server::start();
+    let (status, body) = server.get("/today?to=rd,weekday");
+    assert_eq!(status, 200);
+    assert!(body.starts_with("{\"rd\":") && body.contains("\"weekday\":"), "{}", body);
+}
+
+#[test]
+fn unknown_paths_and_methods() {
+    let server = Server::start();
+    assert_eq!(server.get("/nowhere").0, 404);
+    assert_eq!(server.request("POST", "/convert?date=2000-1-1").0, 405);
+    let (status, body) = server.request("HEAD", "/today");
+    assert_eq!((status, body.as_str()), (200, ""));
+}